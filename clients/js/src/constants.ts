@@ -35,6 +35,16 @@ export const CANDY_MACHINE_HIDDEN_SECTION =
   MAX_URI_LENGTH + // u32 + max uri length
   32; // hash
 
+export const CANDY_MACHINE_HEADER_V3 =
+  8 + // discriminator
+  1 + // version
+  1 + // token standard
+  6 + // features
+  32 + // authority
+  32 + // mint authority
+  32 + // collection mint
+  8; // items redeemed
+
 export const CANDY_GUARD_LABEL_SIZE = 6;
 export const CANDY_GUARD_DATA =
   8 + // discriminator
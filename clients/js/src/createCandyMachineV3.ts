@@ -0,0 +1,53 @@
+import { createAccount } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  none,
+  Signer,
+  transactionBuilder,
+  TransactionBuilder,
+} from '@metaplex-foundation/umi';
+import { initializeCandyMachineV3 } from './generated';
+import { getCandyMachineSizeV3 } from './hooked';
+
+export type CreateCandyMachineV3Input = Omit<
+  Parameters<typeof initializeCandyMachineV3>[1],
+  'candyMachine'
+> & {
+  candyMachine: Signer;
+};
+
+export const createCandyMachineV3 = async (
+  context: Parameters<typeof initializeCandyMachineV3>[0] &
+    Pick<Context, 'rpc'>,
+  input: CreateCandyMachineV3Input
+): Promise<TransactionBuilder> => {
+  const space = getCandyMachineSizeV3(
+    {
+      itemsAvailable: input.itemsAvailable,
+      symbol: input.symbol ?? '',
+      sellerFeeBasisPoints: input.sellerFeeBasisPoints,
+      maxEditionSupply: input.maxEditionSupply ?? 0,
+      isMutable: input.isMutable ?? true,
+      creators: input.creators,
+      configLineSettings: input.configLineSettings ?? none(),
+      hiddenSettings: input.hiddenSettings ?? none(),
+    },
+    input.tokenStandard
+  );
+  const lamports = await context.rpc.getRent(space);
+  return transactionBuilder()
+    .add(
+      createAccount(context, {
+        newAccount: input.candyMachine,
+        lamports,
+        space,
+        programId: context.programs.get('mplCandyMachineCore').publicKey,
+      })
+    )
+    .add(
+      initializeCandyMachineV3(context, {
+        ...input,
+        candyMachine: input.candyMachine.publicKey,
+      })
+    );
+};
@@ -16,6 +16,8 @@ import {
   FreezeTokenPaymentArgs,
   Gatekeeper,
   GatekeeperArgs,
+  ItemRange,
+  ItemRangeArgs,
   MintLimit,
   MintLimitArgs,
   NftBurn,
@@ -96,6 +98,7 @@ export type DefaultGuardSetArgs = GuardSetArgs & {
   programGate: OptionOrNullable<ProgramGateArgs>;
   allocation: OptionOrNullable<AllocationArgs>;
   token2022Payment: OptionOrNullable<Token2022PaymentArgs>;
+  itemRange: OptionOrNullable<ItemRangeArgs>;
 };
 
 /**
@@ -123,6 +126,7 @@ export type DefaultGuardSet = GuardSet & {
   programGate: Option<ProgramGate>;
   allocation: Option<Allocation>;
   token2022Payment: Option<Token2022Payment>;
+  itemRange: Option<ItemRange>;
 };
 
 /**
@@ -150,6 +154,7 @@ export type DefaultGuardSetMintArgs = GuardSetMintArgs & {
   // programGate: no mint settings
   allocation: OptionOrNullable<AllocationMintArgs>;
   token2022Payment: OptionOrNullable<Token2022PaymentMintArgs>;
+  // itemRange: no mint settings
 };
 
 /**
@@ -177,6 +182,7 @@ export type DefaultGuardSetRouteArgs = GuardSetRouteArgs & {
   // programGate: no route settings
   allocation: AllocationRouteArgs;
   // token2022Payment: no route settings
+  // itemRange: no route settings
 };
 
 /** @internal */
@@ -202,6 +208,7 @@ export const defaultCandyGuardNames: string[] = [
   'programGate',
   'allocation',
   'token2022Payment',
+  'itemRange',
 ];
 
 /** @internal */
@@ -1,10 +1,8 @@
 import {
-  TokenStandard,
   findMasterEditionPda,
   findMetadataPda,
   findTokenRecordPda,
   getMplTokenMetadataProgramId,
-  isProgrammable,
 } from '@metaplex-foundation/mpl-token-metadata';
 import {
   findAssociatedTokenPda,
@@ -25,6 +23,10 @@ import {
   getFreezeTokenPaymentSerializer,
 } from '../generated';
 import { GuardManifest, GuardRemainingAccount, RouteParser } from '../guards';
+import {
+  CandyMachineTokenStandard,
+  isProgrammableTokenStandard,
+} from '../hooked';
 import { getMplTokenAuthRulesProgramId } from '../programs';
 
 /**
@@ -185,7 +187,7 @@ export type FreezeTokenPaymentRouteArgsThaw = Omit<
   nftOwner: PublicKey;
 
   /** The token standard of the minted NFT. */
-  nftTokenStandard: TokenStandard;
+  nftTokenStandard: CandyMachineTokenStandard;
 
   /** The ruleSet of the minted NFT, if any. */
   nftRuleSet?: PublicKey;
@@ -288,7 +290,7 @@ const thawRouteInstruction: RouteParser<FreezeTokenPaymentRouteArgsThaw> = (
     { publicKey: getMplTokenMetadataProgramId(context), isWritable: false },
   ];
 
-  if (!isProgrammable(args.nftTokenStandard)) {
+  if (!isProgrammableTokenStandard(args.nftTokenStandard)) {
     return { data, remainingAccounts };
   }
 
@@ -7,6 +7,7 @@ export * from './endDate';
 export * from './freezeSolPayment';
 export * from './freezeTokenPayment';
 export * from './gatekeeper';
+export * from './itemRange';
 export * from './mintLimit';
 export * from './nftBurn';
 export * from './nftGate';
@@ -0,0 +1,20 @@
+import { getItemRangeSerializer, ItemRange, ItemRangeArgs } from '../generated';
+import { GuardManifest, noopParser } from '../guards';
+
+/**
+ * The itemRange guard restricts the mint to items requested
+ * within a range of indices. The mint must request a specific
+ * item, which requires the `mintV3` instruction.
+ *
+ * Combined with groups, this guard allows setting a different
+ * price or access rules for each range of items.
+ */
+export const itemRangeGuardManifest: GuardManifest<
+  ItemRangeArgs,
+  ItemRange
+> = {
+  name: 'itemRange',
+  serializer: getItemRangeSerializer,
+  mintParser: noopParser,
+  routeParser: noopParser,
+};
@@ -6,10 +6,6 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
-import {
-  TokenStandardArgs,
-  getTokenStandardSerializer,
-} from '@metaplex-foundation/mpl-token-metadata';
 import {
   Account,
   Context,
@@ -31,7 +27,9 @@ import {
 } from '@metaplex-foundation/umi/serializers';
 import {
   CandyMachineAccountData,
+  CandyMachineTokenStandardArgs,
   getCandyMachineAccountDataSerializer,
+  getCandyMachineTokenStandardSerializer,
 } from '../../hooked';
 import {
   AccountVersionArgs,
@@ -112,7 +110,7 @@ export function getCandyMachineGpaBuilder(
     .registerFields<{
       discriminator: Array<number>;
       version: AccountVersionArgs;
-      tokenStandard: TokenStandardArgs;
+      tokenStandard: CandyMachineTokenStandardArgs;
       features: Array<number>;
       authority: PublicKey;
       mintAuthority: PublicKey;
@@ -122,7 +120,7 @@ export function getCandyMachineGpaBuilder(
     }>({
       discriminator: [0, array(u8(), { size: 8 })],
       version: [8, getAccountVersionSerializer()],
-      tokenStandard: [9, getTokenStandardSerializer()],
+      tokenStandard: [9, getCandyMachineTokenStandardSerializer()],
       features: [null, array(u8(), { size: 6 })],
       authority: [null, publicKeySerializer()],
       mintAuthority: [null, publicKeySerializer()],
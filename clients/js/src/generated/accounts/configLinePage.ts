@@ -0,0 +1,157 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Page account storing a contiguous range of config lines of a candy machine.
+ *
+ * Pages allow a candy machine to hold more config lines than fit in a single account:
+ * the first page stores the first `lines_per_page` config lines, the second page the
+ * following `lines_per_page` config lines and so on.
+ */
+
+export type ConfigLinePage = Account<ConfigLinePageAccountData>;
+
+export type ConfigLinePageAccountData = {
+  discriminator: Array<number>;
+  /** Candy machine of the page. */
+  candyMachine: PublicKey;
+};
+
+export type ConfigLinePageAccountDataArgs = {
+  /** Candy machine of the page. */
+  candyMachine: PublicKey;
+};
+
+export function getConfigLinePageAccountDataSerializer(): Serializer<
+  ConfigLinePageAccountDataArgs,
+  ConfigLinePageAccountData
+> {
+  return mapSerializer<
+    ConfigLinePageAccountDataArgs,
+    any,
+    ConfigLinePageAccountData
+  >(
+    struct<ConfigLinePageAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['candyMachine', publicKeySerializer()],
+      ],
+      { description: 'ConfigLinePageAccountData' }
+    ),
+    (value) => ({ ...value, discriminator: [52, 99, 33, 39, 22, 118, 121, 92] })
+  ) as Serializer<ConfigLinePageAccountDataArgs, ConfigLinePageAccountData>;
+}
+
+export function deserializeConfigLinePage(
+  rawAccount: RpcAccount
+): ConfigLinePage {
+  return deserializeAccount(
+    rawAccount,
+    getConfigLinePageAccountDataSerializer()
+  );
+}
+
+export async function fetchConfigLinePage(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ConfigLinePage> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'ConfigLinePage');
+  return deserializeConfigLinePage(maybeAccount);
+}
+
+export async function safeFetchConfigLinePage(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ConfigLinePage | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeConfigLinePage(maybeAccount) : null;
+}
+
+export async function fetchAllConfigLinePage(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ConfigLinePage[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'ConfigLinePage');
+    return deserializeConfigLinePage(maybeAccount);
+  });
+}
+
+export async function safeFetchAllConfigLinePage(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ConfigLinePage[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeConfigLinePage(maybeAccount as RpcAccount)
+    );
+}
+
+export function getConfigLinePageGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{ discriminator: Array<number>; candyMachine: PublicKey }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      candyMachine: [8, publicKeySerializer()],
+    })
+    .deserializeUsing<ConfigLinePage>((account) =>
+      deserializeConfigLinePage(account)
+    )
+    .whereField('discriminator', [52, 99, 33, 39, 22, 118, 121, 92]);
+}
+
+export function getConfigLinePageSize(): number {
+  return 40;
+}
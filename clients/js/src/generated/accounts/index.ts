@@ -9,5 +9,8 @@
 export * from './allocationTracker';
 export * from './allowListProof';
 export * from './candyMachine';
+export * from './configLinePage';
 export * from './freezeEscrow';
 export * from './mintCounter';
+export * from './mintReceipt';
+export * from './mintRequest';
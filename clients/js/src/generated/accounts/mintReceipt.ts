@@ -0,0 +1,242 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  i64,
+  mapSerializer,
+  option,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/** Receipt of a mint, linking the minted item to the candy machine. */
+export type MintReceipt = Account<MintReceiptAccountData>;
+
+export type MintReceiptAccountData = {
+  discriminator: Array<number>;
+  /** Candy machine of the mint. */
+  candyMachine: PublicKey;
+  /** Mint (or asset) account of the item. */
+  mint: PublicKey;
+  /** Payer of the mint, which also paid the receipt account rent. */
+  payer: PublicKey;
+  /** Index of the config line minted. */
+  index: number;
+  /** Number of items redeemed after the mint. */
+  itemsRedeemed: bigint;
+  /** Slot of the mint. */
+  slot: bigint;
+  /** Timestamp of the mint. */
+  timestamp: bigint;
+  /** Label of the candy guard group used to mint (if any). */
+  group: Option<string>;
+  /** Bump of the PDA. */
+  bump: number;
+};
+
+export type MintReceiptAccountDataArgs = {
+  /** Candy machine of the mint. */
+  candyMachine: PublicKey;
+  /** Mint (or asset) account of the item. */
+  mint: PublicKey;
+  /** Payer of the mint, which also paid the receipt account rent. */
+  payer: PublicKey;
+  /** Index of the config line minted. */
+  index: number;
+  /** Number of items redeemed after the mint. */
+  itemsRedeemed: number | bigint;
+  /** Slot of the mint. */
+  slot: number | bigint;
+  /** Timestamp of the mint. */
+  timestamp: number | bigint;
+  /** Label of the candy guard group used to mint (if any). */
+  group: OptionOrNullable<string>;
+  /** Bump of the PDA. */
+  bump: number;
+};
+
+export function getMintReceiptAccountDataSerializer(): Serializer<
+  MintReceiptAccountDataArgs,
+  MintReceiptAccountData
+> {
+  return mapSerializer<MintReceiptAccountDataArgs, any, MintReceiptAccountData>(
+    struct<MintReceiptAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['candyMachine', publicKeySerializer()],
+        ['mint', publicKeySerializer()],
+        ['payer', publicKeySerializer()],
+        ['index', u32()],
+        ['itemsRedeemed', u64()],
+        ['slot', u64()],
+        ['timestamp', i64()],
+        ['group', option(string())],
+        ['bump', u8()],
+      ],
+      { description: 'MintReceiptAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [140, 16, 143, 24, 20, 95, 250, 15],
+    })
+  ) as Serializer<MintReceiptAccountDataArgs, MintReceiptAccountData>;
+}
+
+export function deserializeMintReceipt(rawAccount: RpcAccount): MintReceipt {
+  return deserializeAccount(rawAccount, getMintReceiptAccountDataSerializer());
+}
+
+export async function fetchMintReceipt(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<MintReceipt> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'MintReceipt');
+  return deserializeMintReceipt(maybeAccount);
+}
+
+export async function safeFetchMintReceipt(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<MintReceipt | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeMintReceipt(maybeAccount) : null;
+}
+
+export async function fetchAllMintReceipt(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<MintReceipt[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'MintReceipt');
+    return deserializeMintReceipt(maybeAccount);
+  });
+}
+
+export async function safeFetchAllMintReceipt(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<MintReceipt[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeMintReceipt(maybeAccount as RpcAccount));
+}
+
+export function getMintReceiptGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      candyMachine: PublicKey;
+      mint: PublicKey;
+      payer: PublicKey;
+      index: number;
+      itemsRedeemed: number | bigint;
+      slot: number | bigint;
+      timestamp: number | bigint;
+      group: OptionOrNullable<string>;
+      bump: number;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      candyMachine: [8, publicKeySerializer()],
+      mint: [40, publicKeySerializer()],
+      payer: [72, publicKeySerializer()],
+      index: [104, u32()],
+      itemsRedeemed: [108, u64()],
+      slot: [116, u64()],
+      timestamp: [124, i64()],
+      group: [132, option(string())],
+      bump: [null, u8()],
+    })
+    .deserializeUsing<MintReceipt>((account) => deserializeMintReceipt(account))
+    .whereField('discriminator', [140, 16, 143, 24, 20, 95, 250, 15]);
+}
+
+export function findMintReceiptPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the Candy Machine account */
+    candyMachine: PublicKey;
+    /** The address of the minted NFT (or asset) */
+    mint: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('mint_receipt'),
+    publicKeySerializer().serialize(seeds.candyMachine),
+    publicKeySerializer().serialize(seeds.mint),
+  ]);
+}
+
+export async function fetchMintReceiptFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findMintReceiptPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<MintReceipt> {
+  return fetchMintReceipt(context, findMintReceiptPda(context, seeds), options);
+}
+
+export async function safeFetchMintReceiptFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findMintReceiptPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<MintReceipt | null> {
+  return safeFetchMintReceipt(
+    context,
+    findMintReceiptPda(context, seeds),
+    options
+  );
+}
@@ -0,0 +1,230 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u16,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Mint request of a candy machine using commit-reveal.
+ *
+ * The request reserves a mint for the owner; the item is selected when the request is
+ * revealed using the hash of the first slot after the request slot. Requests are
+ * revealed in the order they were created, so the items available when a request is
+ * revealed only depend on the requests created before it. Anyone can reveal a request
+ * and, once expired, anyone can close it so it does not block the following requests.
+ */
+
+export type MintRequest = Account<MintRequestAccountData>;
+
+export type MintRequestAccountData = {
+  discriminator: Array<number>;
+  /** Candy machine of the request. */
+  candyMachine: PublicKey;
+  /** Owner of the NFT to be minted. */
+  owner: PublicKey;
+  /** Payer of the request account rent. */
+  payer: PublicKey;
+  /** Slot of the request. */
+  slot: bigint;
+  /** Sequence of the request, which determines the order of the reveals. */
+  sequence: number;
+  /** Bump of the PDA. */
+  bump: number;
+};
+
+export type MintRequestAccountDataArgs = {
+  /** Candy machine of the request. */
+  candyMachine: PublicKey;
+  /** Owner of the NFT to be minted. */
+  owner: PublicKey;
+  /** Payer of the request account rent. */
+  payer: PublicKey;
+  /** Slot of the request. */
+  slot: number | bigint;
+  /** Sequence of the request, which determines the order of the reveals. */
+  sequence: number;
+  /** Bump of the PDA. */
+  bump: number;
+};
+
+export function getMintRequestAccountDataSerializer(): Serializer<
+  MintRequestAccountDataArgs,
+  MintRequestAccountData
+> {
+  return mapSerializer<MintRequestAccountDataArgs, any, MintRequestAccountData>(
+    struct<MintRequestAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['candyMachine', publicKeySerializer()],
+        ['owner', publicKeySerializer()],
+        ['payer', publicKeySerializer()],
+        ['slot', u64()],
+        ['sequence', u16()],
+        ['bump', u8()],
+      ],
+      { description: 'MintRequestAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [60, 88, 16, 213, 180, 138, 14, 225],
+    })
+  ) as Serializer<MintRequestAccountDataArgs, MintRequestAccountData>;
+}
+
+export function deserializeMintRequest(rawAccount: RpcAccount): MintRequest {
+  return deserializeAccount(rawAccount, getMintRequestAccountDataSerializer());
+}
+
+export async function fetchMintRequest(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<MintRequest> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'MintRequest');
+  return deserializeMintRequest(maybeAccount);
+}
+
+export async function safeFetchMintRequest(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<MintRequest | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeMintRequest(maybeAccount) : null;
+}
+
+export async function fetchAllMintRequest(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<MintRequest[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'MintRequest');
+    return deserializeMintRequest(maybeAccount);
+  });
+}
+
+export async function safeFetchAllMintRequest(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<MintRequest[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeMintRequest(maybeAccount as RpcAccount));
+}
+
+export function getMintRequestGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      candyMachine: PublicKey;
+      owner: PublicKey;
+      payer: PublicKey;
+      slot: number | bigint;
+      sequence: number;
+      bump: number;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      candyMachine: [8, publicKeySerializer()],
+      owner: [40, publicKeySerializer()],
+      payer: [72, publicKeySerializer()],
+      slot: [104, u64()],
+      sequence: [112, u16()],
+      bump: [114, u8()],
+    })
+    .deserializeUsing<MintRequest>((account) => deserializeMintRequest(account))
+    .whereField('discriminator', [60, 88, 16, 213, 180, 138, 14, 225]);
+}
+
+export function getMintRequestSize(): number {
+  return 115;
+}
+
+export function findMintRequestPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the Candy Machine account */
+    candyMachine: PublicKey;
+    /** The owner of the NFT to be minted */
+    owner: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('mint_request'),
+    publicKeySerializer().serialize(seeds.candyMachine),
+    publicKeySerializer().serialize(seeds.owner),
+  ]);
+}
+
+export async function fetchMintRequestFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findMintRequestPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<MintRequest> {
+  return fetchMintRequest(context, findMintRequestPda(context, seeds), options);
+}
+
+export async function safeFetchMintRequestFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findMintRequestPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<MintRequest | null> {
+  return safeFetchMintRequest(
+    context,
+    findMintRequestPda(context, seeds),
+    options
+  );
+}
@@ -731,6 +731,49 @@ export class CgInvalidAccountVersionError extends ProgramError {
 codeToErrorMap.set(0x17a3, CgInvalidAccountVersionError);
 nameToErrorMap.set('InvalidAccountVersion', CgInvalidAccountVersionError);
 
+/** MissingRequestedItem: Missing requested item index */
+export class CgMissingRequestedItemError extends ProgramError {
+  readonly name: string = 'MissingRequestedItem';
+
+  readonly code: number = 0x17a4; // 6052
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing requested item index', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a4, CgMissingRequestedItemError);
+nameToErrorMap.set('MissingRequestedItem', CgMissingRequestedItemError);
+
+/** RequestedItemOutOfRange: Requested item is outside of the allowed range */
+export class CgRequestedItemOutOfRangeError extends ProgramError {
+  readonly name: string = 'RequestedItemOutOfRange';
+
+  readonly code: number = 0x17a5; // 6053
+
+  constructor(program: Program, cause?: Error) {
+    super('Requested item is outside of the allowed range', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a5, CgRequestedItemOutOfRangeError);
+nameToErrorMap.set('RequestedItemOutOfRange', CgRequestedItemOutOfRangeError);
+
+/** ItemRangeRequired: Requesting a specific item requires the item range guard */
+export class CgItemRangeRequiredError extends ProgramError {
+  readonly name: string = 'ItemRangeRequired';
+
+  readonly code: number = 0x17a6; // 6054
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Requesting a specific item requires the item range guard',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17a6, CgItemRangeRequiredError);
+nameToErrorMap.set('ItemRangeRequired', CgItemRangeRequiredError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
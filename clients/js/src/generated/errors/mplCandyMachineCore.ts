@@ -480,6 +480,1069 @@ export class CmInvalidAccountVersionError extends ProgramError {
 codeToErrorMap.set(0x178d, CmInvalidAccountVersionError);
 nameToErrorMap.set('InvalidAccountVersion', CmInvalidAccountVersionError);
 
+/** MissingMplCoreProgram: Missing MPL Core program */
+export class CmMissingMplCoreProgramError extends ProgramError {
+  readonly name: string = 'MissingMplCoreProgram';
+
+  readonly code: number = 0x178e; // 6030
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing MPL Core program', program, cause);
+  }
+}
+codeToErrorMap.set(0x178e, CmMissingMplCoreProgramError);
+nameToErrorMap.set('MissingMplCoreProgram', CmMissingMplCoreProgramError);
+
+/** MissingBubblegumAccounts: Missing Bubblegum accounts */
+export class CmMissingBubblegumAccountsError extends ProgramError {
+  readonly name: string = 'MissingBubblegumAccounts';
+
+  readonly code: number = 0x178f; // 6031
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing Bubblegum accounts', program, cause);
+  }
+}
+codeToErrorMap.set(0x178f, CmMissingBubblegumAccountsError);
+nameToErrorMap.set('MissingBubblegumAccounts', CmMissingBubblegumAccountsError);
+
+/** MissingSplToken2022Program: Missing SPL Token-2022 program */
+export class CmMissingSplToken2022ProgramError extends ProgramError {
+  readonly name: string = 'MissingSplToken2022Program';
+
+  readonly code: number = 0x1790; // 6032
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing SPL Token-2022 program', program, cause);
+  }
+}
+codeToErrorMap.set(0x1790, CmMissingSplToken2022ProgramError);
+nameToErrorMap.set(
+  'MissingSplToken2022Program',
+  CmMissingSplToken2022ProgramError
+);
+
+/** MissingMintRequest: Missing mint request account */
+export class CmMissingMintRequestError extends ProgramError {
+  readonly name: string = 'MissingMintRequest';
+
+  readonly code: number = 0x1791; // 6033
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing mint request account', program, cause);
+  }
+}
+codeToErrorMap.set(0x1791, CmMissingMintRequestError);
+nameToErrorMap.set('MissingMintRequest', CmMissingMintRequestError);
+
+/** InvalidMintRequest: Invalid mint request account */
+export class CmInvalidMintRequestError extends ProgramError {
+  readonly name: string = 'InvalidMintRequest';
+
+  readonly code: number = 0x1792; // 6034
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid mint request account', program, cause);
+  }
+}
+codeToErrorMap.set(0x1792, CmInvalidMintRequestError);
+nameToErrorMap.set('InvalidMintRequest', CmInvalidMintRequestError);
+
+/** MintRequestNotReady: Mint request cannot be revealed in the same slot */
+export class CmMintRequestNotReadyError extends ProgramError {
+  readonly name: string = 'MintRequestNotReady';
+
+  readonly code: number = 0x1793; // 6035
+
+  constructor(program: Program, cause?: Error) {
+    super('Mint request cannot be revealed in the same slot', program, cause);
+  }
+}
+codeToErrorMap.set(0x1793, CmMintRequestNotReadyError);
+nameToErrorMap.set('MintRequestNotReady', CmMintRequestNotReadyError);
+
+/** MintRequestExpired: Mint request slot hash is no longer available */
+export class CmMintRequestExpiredError extends ProgramError {
+  readonly name: string = 'MintRequestExpired';
+
+  readonly code: number = 0x1794; // 6036
+
+  constructor(program: Program, cause?: Error) {
+    super('Mint request slot hash is no longer available', program, cause);
+  }
+}
+codeToErrorMap.set(0x1794, CmMintRequestExpiredError);
+nameToErrorMap.set('MintRequestExpired', CmMintRequestExpiredError);
+
+/** MintRequestNotExpired: Mint request can only be closed after it expires */
+export class CmMintRequestNotExpiredError extends ProgramError {
+  readonly name: string = 'MintRequestNotExpired';
+
+  readonly code: number = 0x1795; // 6037
+
+  constructor(program: Program, cause?: Error) {
+    super('Mint request can only be closed after it expires', program, cause);
+  }
+}
+codeToErrorMap.set(0x1795, CmMintRequestNotExpiredError);
+nameToErrorMap.set('MintRequestNotExpired', CmMintRequestNotExpiredError);
+
+/** InvalidRarityTiers: Invalid rarity tiers */
+export class CmInvalidRarityTiersError extends ProgramError {
+  readonly name: string = 'InvalidRarityTiers';
+
+  readonly code: number = 0x1796; // 6038
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid rarity tiers', program, cause);
+  }
+}
+codeToErrorMap.set(0x1796, CmInvalidRarityTiersError);
+nameToErrorMap.set('InvalidRarityTiers', CmInvalidRarityTiersError);
+
+/** NoChangingRarityTiersDuringMint: Can't change rarity tiers after items have begun to be minted */
+export class CmNoChangingRarityTiersDuringMintError extends ProgramError {
+  readonly name: string = 'NoChangingRarityTiersDuringMint';
+
+  readonly code: number = 0x1797; // 6039
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      "Can't change rarity tiers after items have begun to be minted",
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1797, CmNoChangingRarityTiersDuringMintError);
+nameToErrorMap.set(
+  'NoChangingRarityTiersDuringMint',
+  CmNoChangingRarityTiersDuringMintError
+);
+
+/** PickItemDisabled: Pick item mode is not enabled */
+export class CmPickItemDisabledError extends ProgramError {
+  readonly name: string = 'PickItemDisabled';
+
+  readonly code: number = 0x1798; // 6040
+
+  constructor(program: Program, cause?: Error) {
+    super('Pick item mode is not enabled', program, cause);
+  }
+}
+codeToErrorMap.set(0x1798, CmPickItemDisabledError);
+nameToErrorMap.set('PickItemDisabled', CmPickItemDisabledError);
+
+/** PickItemNotSupported: Pick item mode requires random config line selection */
+export class CmPickItemNotSupportedError extends ProgramError {
+  readonly name: string = 'PickItemNotSupported';
+
+  readonly code: number = 0x1799; // 6041
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Pick item mode requires random config line selection',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1799, CmPickItemNotSupportedError);
+nameToErrorMap.set('PickItemNotSupported', CmPickItemNotSupportedError);
+
+/** ItemNotAvailable: Requested item is not available */
+export class CmItemNotAvailableError extends ProgramError {
+  readonly name: string = 'ItemNotAvailable';
+
+  readonly code: number = 0x179a; // 6042
+
+  constructor(program: Program, cause?: Error) {
+    super('Requested item is not available', program, cause);
+  }
+}
+codeToErrorMap.set(0x179a, CmItemNotAvailableError);
+nameToErrorMap.set('ItemNotAvailable', CmItemNotAvailableError);
+
+/** MerkleConfigLinesEnabled: Config lines are verified using a merkle root */
+export class CmMerkleConfigLinesEnabledError extends ProgramError {
+  readonly name: string = 'MerkleConfigLinesEnabled';
+
+  readonly code: number = 0x179b; // 6043
+
+  constructor(program: Program, cause?: Error) {
+    super('Config lines are verified using a merkle root', program, cause);
+  }
+}
+codeToErrorMap.set(0x179b, CmMerkleConfigLinesEnabledError);
+nameToErrorMap.set('MerkleConfigLinesEnabled', CmMerkleConfigLinesEnabledError);
+
+/** NoChangingConfigLinesRootDuringMint: Can't change the config lines root after items have begun to be minted */
+export class CmNoChangingConfigLinesRootDuringMintError extends ProgramError {
+  readonly name: string = 'NoChangingConfigLinesRootDuringMint';
+
+  readonly code: number = 0x179c; // 6044
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      "Can't change the config lines root after items have begun to be minted",
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x179c, CmNoChangingConfigLinesRootDuringMintError);
+nameToErrorMap.set(
+  'NoChangingConfigLinesRootDuringMint',
+  CmNoChangingConfigLinesRootDuringMintError
+);
+
+/** ConfigLinesAlreadyLoaded: Config lines root cannot be used with loaded config lines */
+export class CmConfigLinesAlreadyLoadedError extends ProgramError {
+  readonly name: string = 'ConfigLinesAlreadyLoaded';
+
+  readonly code: number = 0x179d; // 6045
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Config lines root cannot be used with loaded config lines',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x179d, CmConfigLinesAlreadyLoadedError);
+nameToErrorMap.set('ConfigLinesAlreadyLoaded', CmConfigLinesAlreadyLoadedError);
+
+/** MissingConfigLineProof: Missing config line proof */
+export class CmMissingConfigLineProofError extends ProgramError {
+  readonly name: string = 'MissingConfigLineProof';
+
+  readonly code: number = 0x179e; // 6046
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing config line proof', program, cause);
+  }
+}
+codeToErrorMap.set(0x179e, CmMissingConfigLineProofError);
+nameToErrorMap.set('MissingConfigLineProof', CmMissingConfigLineProofError);
+
+/** InvalidConfigLineProof: Invalid config line proof */
+export class CmInvalidConfigLineProofError extends ProgramError {
+  readonly name: string = 'InvalidConfigLineProof';
+
+  readonly code: number = 0x179f; // 6047
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid config line proof', program, cause);
+  }
+}
+codeToErrorMap.set(0x179f, CmInvalidConfigLineProofError);
+nameToErrorMap.set('InvalidConfigLineProof', CmInvalidConfigLineProofError);
+
+/** MissingHiddenSettings: Missing hidden settings */
+export class CmMissingHiddenSettingsError extends ProgramError {
+  readonly name: string = 'MissingHiddenSettings';
+
+  readonly code: number = 0x17a0; // 6048
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing hidden settings', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a0, CmMissingHiddenSettingsError);
+nameToErrorMap.set('MissingHiddenSettings', CmMissingHiddenSettingsError);
+
+/** HiddenSettingsMissingIndex: Hidden settings name or uri must include an index replacement pattern */
+export class CmHiddenSettingsMissingIndexError extends ProgramError {
+  readonly name: string = 'HiddenSettingsMissingIndex';
+
+  readonly code: number = 0x17a1; // 6049
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Hidden settings name or uri must include an index replacement pattern',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17a1, CmHiddenSettingsMissingIndexError);
+nameToErrorMap.set(
+  'HiddenSettingsMissingIndex',
+  CmHiddenSettingsMissingIndexError
+);
+
+/** NoChangingOnchainRevealDuringMint: Can't change the on-chain reveal after items have begun to be minted */
+export class CmNoChangingOnchainRevealDuringMintError extends ProgramError {
+  readonly name: string = 'NoChangingOnchainRevealDuringMint';
+
+  readonly code: number = 0x17a2; // 6050
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      "Can't change the on-chain reveal after items have begun to be minted",
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17a2, CmNoChangingOnchainRevealDuringMintError);
+nameToErrorMap.set(
+  'NoChangingOnchainRevealDuringMint',
+  CmNoChangingOnchainRevealDuringMintError
+);
+
+/** CannotChangeHiddenSettings: Can't change hidden settings after items have begun to be minted */
+export class CmCannotChangeHiddenSettingsError extends ProgramError {
+  readonly name: string = 'CannotChangeHiddenSettings';
+
+  readonly code: number = 0x17a3; // 6051
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      "Can't change hidden settings after items have begun to be minted",
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17a3, CmCannotChangeHiddenSettingsError);
+nameToErrorMap.set(
+  'CannotChangeHiddenSettings',
+  CmCannotChangeHiddenSettingsError
+);
+
+/** InvalidRevealItem: Item is not a hidden item of the candy machine */
+export class CmInvalidRevealItemError extends ProgramError {
+  readonly name: string = 'InvalidRevealItem';
+
+  readonly code: number = 0x17a4; // 6052
+
+  constructor(program: Program, cause?: Error) {
+    super('Item is not a hidden item of the candy machine', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a4, CmInvalidRevealItemError);
+nameToErrorMap.set('InvalidRevealItem', CmInvalidRevealItemError);
+
+/** InvalidBatchAccounts: Invalid number of accounts for a batch mint */
+export class CmInvalidBatchAccountsError extends ProgramError {
+  readonly name: string = 'InvalidBatchAccounts';
+
+  readonly code: number = 0x17a5; // 6053
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid number of accounts for a batch mint', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a5, CmInvalidBatchAccountsError);
+nameToErrorMap.set('InvalidBatchAccounts', CmInvalidBatchAccountsError);
+
+/** BatchMintNotSupported: Batch mint is not supported with commit-reveal, merkle config lines, soulbound items or a mint delegate */
+export class CmBatchMintNotSupportedError extends ProgramError {
+  readonly name: string = 'BatchMintNotSupported';
+
+  readonly code: number = 0x17a6; // 6054
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Batch mint is not supported with commit-reveal, merkle config lines, soulbound items or a mint delegate',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17a6, CmBatchMintNotSupportedError);
+nameToErrorMap.set('BatchMintNotSupported', CmBatchMintNotSupportedError);
+
+/** CandyMachinePaused: Candy machine is paused */
+export class CmCandyMachinePausedError extends ProgramError {
+  readonly name: string = 'CandyMachinePaused';
+
+  readonly code: number = 0x17a7; // 6055
+
+  constructor(program: Program, cause?: Error) {
+    super('Candy machine is paused', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a7, CmCandyMachinePausedError);
+nameToErrorMap.set('CandyMachinePaused', CmCandyMachinePausedError);
+
+/** ConfigLineNotLoaded: Config line is not loaded */
+export class CmConfigLineNotLoadedError extends ProgramError {
+  readonly name: string = 'ConfigLineNotLoaded';
+
+  readonly code: number = 0x17a8; // 6056
+
+  constructor(program: Program, cause?: Error) {
+    super('Config line is not loaded', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a8, CmConfigLineNotLoadedError);
+nameToErrorMap.set('ConfigLineNotLoaded', CmConfigLineNotLoadedError);
+
+/** MissingMintReceipt: Missing mint receipt account */
+export class CmMissingMintReceiptError extends ProgramError {
+  readonly name: string = 'MissingMintReceipt';
+
+  readonly code: number = 0x17a9; // 6057
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing mint receipt account', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a9, CmMissingMintReceiptError);
+nameToErrorMap.set('MissingMintReceipt', CmMissingMintReceiptError);
+
+/** InvalidMintReceipt: Invalid mint receipt account */
+export class CmInvalidMintReceiptError extends ProgramError {
+  readonly name: string = 'InvalidMintReceipt';
+
+  readonly code: number = 0x17aa; // 6058
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid mint receipt account', program, cause);
+  }
+}
+codeToErrorMap.set(0x17aa, CmInvalidMintReceiptError);
+nameToErrorMap.set('InvalidMintReceipt', CmInvalidMintReceiptError);
+
+/** ReceiptGroupTooLong: Mint receipt group label exceeds the maximum length */
+export class CmReceiptGroupTooLongError extends ProgramError {
+  readonly name: string = 'ReceiptGroupTooLong';
+
+  readonly code: number = 0x17ab; // 6059
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Mint receipt group label exceeds the maximum length',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17ab, CmReceiptGroupTooLongError);
+nameToErrorMap.set('ReceiptGroupTooLong', CmReceiptGroupTooLongError);
+
+/** CandyMachineNotSoldOut: Mint receipts can only be closed after all items are minted */
+export class CmCandyMachineNotSoldOutError extends ProgramError {
+  readonly name: string = 'CandyMachineNotSoldOut';
+
+  readonly code: number = 0x17ac; // 6060
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Mint receipts can only be closed after all items are minted',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17ac, CmCandyMachineNotSoldOutError);
+nameToErrorMap.set('CandyMachineNotSoldOut', CmCandyMachineNotSoldOutError);
+
+/** NoChangingConfigLinesDuringMint: Can't change config lines settings after items have begun to be minted */
+export class CmNoChangingConfigLinesDuringMintError extends ProgramError {
+  readonly name: string = 'NoChangingConfigLinesDuringMint';
+
+  readonly code: number = 0x17ad; // 6061
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      "Can't change config lines settings after items have begun to be minted",
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17ad, CmNoChangingConfigLinesDuringMintError);
+nameToErrorMap.set(
+  'NoChangingConfigLinesDuringMint',
+  CmNoChangingConfigLinesDuringMintError
+);
+
+/** CannotDecreaseLength: Cannot decrease the length in config lines settings */
+export class CmCannotDecreaseLengthError extends ProgramError {
+  readonly name: string = 'CannotDecreaseLength';
+
+  readonly code: number = 0x17ae; // 6062
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Cannot decrease the length in config lines settings',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17ae, CmCannotDecreaseLengthError);
+nameToErrorMap.set('CannotDecreaseLength', CmCannotDecreaseLengthError);
+
+/** CannotRemoveLoadedConfigLines: Cannot remove config lines that were already added */
+export class CmCannotRemoveLoadedConfigLinesError extends ProgramError {
+  readonly name: string = 'CannotRemoveLoadedConfigLines';
+
+  readonly code: number = 0x17af; // 6063
+
+  constructor(program: Program, cause?: Error) {
+    super('Cannot remove config lines that were already added', program, cause);
+  }
+}
+codeToErrorMap.set(0x17af, CmCannotRemoveLoadedConfigLinesError);
+nameToErrorMap.set(
+  'CannotRemoveLoadedConfigLines',
+  CmCannotRemoveLoadedConfigLinesError
+);
+
+/** ConfigLineAlreadyMinted: Config line was already minted */
+export class CmConfigLineAlreadyMintedError extends ProgramError {
+  readonly name: string = 'ConfigLineAlreadyMinted';
+
+  readonly code: number = 0x17b0; // 6064
+
+  constructor(program: Program, cause?: Error) {
+    super('Config line was already minted', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b0, CmConfigLineAlreadyMintedError);
+nameToErrorMap.set('ConfigLineAlreadyMinted', CmConfigLineAlreadyMintedError);
+
+/** ConfigLinesFinalized: Config lines are finalized and can no longer change */
+export class CmConfigLinesFinalizedError extends ProgramError {
+  readonly name: string = 'ConfigLinesFinalized';
+
+  readonly code: number = 0x17b1; // 6065
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Config lines are finalized and can no longer change',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17b1, CmConfigLinesFinalizedError);
+nameToErrorMap.set('ConfigLinesFinalized', CmConfigLinesFinalizedError);
+
+/** InvalidUriEncoding: Config line URI does not match the URI encoding */
+export class CmInvalidUriEncodingError extends ProgramError {
+  readonly name: string = 'InvalidUriEncoding';
+
+  readonly code: number = 0x17b2; // 6066
+
+  constructor(program: Program, cause?: Error) {
+    super('Config line URI does not match the URI encoding', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b2, CmInvalidUriEncodingError);
+nameToErrorMap.set('InvalidUriEncoding', CmInvalidUriEncodingError);
+
+/** CannotResizeEncodedUris: Cannot change the URI length of config lines using a URI encoding */
+export class CmCannotResizeEncodedUrisError extends ProgramError {
+  readonly name: string = 'CannotResizeEncodedUris';
+
+  readonly code: number = 0x17b3; // 6067
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Cannot change the URI length of config lines using a URI encoding',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17b3, CmCannotResizeEncodedUrisError);
+nameToErrorMap.set('CannotResizeEncodedUris', CmCannotResizeEncodedUrisError);
+
+/** CannotChangeMintIndexSize: Cannot change the size of the mint indices */
+export class CmCannotChangeMintIndexSizeError extends ProgramError {
+  readonly name: string = 'CannotChangeMintIndexSize';
+
+  readonly code: number = 0x17b4; // 6068
+
+  constructor(program: Program, cause?: Error) {
+    super('Cannot change the size of the mint indices', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b4, CmCannotChangeMintIndexSizeError);
+nameToErrorMap.set(
+  'CannotChangeMintIndexSize',
+  CmCannotChangeMintIndexSizeError
+);
+
+/** CandyMachineDataTooLarge: Candy machine data exceeds the space of the account */
+export class CmCandyMachineDataTooLargeError extends ProgramError {
+  readonly name: string = 'CandyMachineDataTooLarge';
+
+  readonly code: number = 0x17b5; // 6069
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Candy machine data exceeds the space of the account',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17b5, CmCandyMachineDataTooLargeError);
+nameToErrorMap.set('CandyMachineDataTooLarge', CmCandyMachineDataTooLargeError);
+
+/** MissingConfigLinePage: Missing config line page account */
+export class CmMissingConfigLinePageError extends ProgramError {
+  readonly name: string = 'MissingConfigLinePage';
+
+  readonly code: number = 0x17b6; // 6070
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing config line page account', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b6, CmMissingConfigLinePageError);
+nameToErrorMap.set('MissingConfigLinePage', CmMissingConfigLinePageError);
+
+/** InvalidConfigLinePage: Invalid config line page account */
+export class CmInvalidConfigLinePageError extends ProgramError {
+  readonly name: string = 'InvalidConfigLinePage';
+
+  readonly code: number = 0x17b7; // 6071
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid config line page account', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b7, CmInvalidConfigLinePageError);
+nameToErrorMap.set('InvalidConfigLinePage', CmInvalidConfigLinePageError);
+
+/** ConfigLinesCrossPages: Config lines must be added to a single page */
+export class CmConfigLinesCrossPagesError extends ProgramError {
+  readonly name: string = 'ConfigLinesCrossPages';
+
+  readonly code: number = 0x17b8; // 6072
+
+  constructor(program: Program, cause?: Error) {
+    super('Config lines must be added to a single page', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b8, CmConfigLinesCrossPagesError);
+nameToErrorMap.set('ConfigLinesCrossPages', CmConfigLinesCrossPagesError);
+
+/** ConfigLinePagesNotSupported: Operation is not supported when config lines are stored on page accounts */
+export class CmConfigLinePagesNotSupportedError extends ProgramError {
+  readonly name: string = 'ConfigLinePagesNotSupported';
+
+  readonly code: number = 0x17b9; // 6073
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Operation is not supported when config lines are stored on page accounts',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17b9, CmConfigLinePagesNotSupportedError);
+nameToErrorMap.set(
+  'ConfigLinePagesNotSupported',
+  CmConfigLinePagesNotSupportedError
+);
+
+/** MissingMasterEditionAccounts: Missing master edition accounts */
+export class CmMissingMasterEditionAccountsError extends ProgramError {
+  readonly name: string = 'MissingMasterEditionAccounts';
+
+  readonly code: number = 0x17ba; // 6074
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing master edition accounts', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ba, CmMissingMasterEditionAccountsError);
+nameToErrorMap.set(
+  'MissingMasterEditionAccounts',
+  CmMissingMasterEditionAccountsError
+);
+
+/** FungibleMintMismatch: Mint account does not match the token type of the config line */
+export class CmFungibleMintMismatchError extends ProgramError {
+  readonly name: string = 'FungibleMintMismatch';
+
+  readonly code: number = 0x17bb; // 6075
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Mint account does not match the token type of the config line',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17bb, CmFungibleMintMismatchError);
+nameToErrorMap.set('FungibleMintMismatch', CmFungibleMintMismatchError);
+
+/** CollectionRequired: Token standard requires a collection */
+export class CmCollectionRequiredError extends ProgramError {
+  readonly name: string = 'CollectionRequired';
+
+  readonly code: number = 0x17bc; // 6076
+
+  constructor(program: Program, cause?: Error) {
+    super('Token standard requires a collection', program, cause);
+  }
+}
+codeToErrorMap.set(0x17bc, CmCollectionRequiredError);
+nameToErrorMap.set('CollectionRequired', CmCollectionRequiredError);
+
+/** SoulboundNotSupported: Soulbound items are only supported for NFTs and Programmable NFTs */
+export class CmSoulboundNotSupportedError extends ProgramError {
+  readonly name: string = 'SoulboundNotSupported';
+
+  readonly code: number = 0x17bd; // 6077
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Soulbound items are only supported for NFTs and Programmable NFTs',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17bd, CmSoulboundNotSupportedError);
+nameToErrorMap.set('SoulboundNotSupported', CmSoulboundNotSupportedError);
+
+/** NoChangingSoulboundDuringMint: Can't change the soulbound setting once mint has started */
+export class CmNoChangingSoulboundDuringMintError extends ProgramError {
+  readonly name: string = 'NoChangingSoulboundDuringMint';
+
+  readonly code: number = 0x17be; // 6078
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      "Can't change the soulbound setting once mint has started",
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17be, CmNoChangingSoulboundDuringMintError);
+nameToErrorMap.set(
+  'NoChangingSoulboundDuringMint',
+  CmNoChangingSoulboundDuringMintError
+);
+
+/** SoulboundOwnerNotSigner: NFT owner must sign to mint soulbound items */
+export class CmSoulboundOwnerNotSignerError extends ProgramError {
+  readonly name: string = 'SoulboundOwnerNotSigner';
+
+  readonly code: number = 0x17bf; // 6079
+
+  constructor(program: Program, cause?: Error) {
+    super('NFT owner must sign to mint soulbound items', program, cause);
+  }
+}
+codeToErrorMap.set(0x17bf, CmSoulboundOwnerNotSignerError);
+nameToErrorMap.set('SoulboundOwnerNotSigner', CmSoulboundOwnerNotSignerError);
+
+/** InvalidSoulboundItem: Item was not minted by the candy machine */
+export class CmInvalidSoulboundItemError extends ProgramError {
+  readonly name: string = 'InvalidSoulboundItem';
+
+  readonly code: number = 0x17c0; // 6080
+
+  constructor(program: Program, cause?: Error) {
+    super('Item was not minted by the candy machine', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c0, CmInvalidSoulboundItemError);
+nameToErrorMap.set('InvalidSoulboundItem', CmInvalidSoulboundItemError);
+
+/** MintDelegateNotSupported: Mint delegates are only supported for Programmable NFTs */
+export class CmMintDelegateNotSupportedError extends ProgramError {
+  readonly name: string = 'MintDelegateNotSupported';
+
+  readonly code: number = 0x17c1; // 6081
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Mint delegates are only supported for Programmable NFTs',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17c1, CmMintDelegateNotSupportedError);
+nameToErrorMap.set('MintDelegateNotSupported', CmMintDelegateNotSupportedError);
+
+/** SoulboundMintDelegateConflict: Soulbound items cannot have a mint delegate */
+export class CmSoulboundMintDelegateConflictError extends ProgramError {
+  readonly name: string = 'SoulboundMintDelegateConflict';
+
+  readonly code: number = 0x17c2; // 6082
+
+  constructor(program: Program, cause?: Error) {
+    super('Soulbound items cannot have a mint delegate', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c2, CmSoulboundMintDelegateConflictError);
+nameToErrorMap.set(
+  'SoulboundMintDelegateConflict',
+  CmSoulboundMintDelegateConflictError
+);
+
+/** MissingMintDelegate: Missing mint delegate account */
+export class CmMissingMintDelegateError extends ProgramError {
+  readonly name: string = 'MissingMintDelegate';
+
+  readonly code: number = 0x17c3; // 6083
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing mint delegate account', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c3, CmMissingMintDelegateError);
+nameToErrorMap.set('MissingMintDelegate', CmMissingMintDelegateError);
+
+/** MintDelegateOwnerNotSigner: NFT owner must sign to approve the mint delegate */
+export class CmMintDelegateOwnerNotSignerError extends ProgramError {
+  readonly name: string = 'MintDelegateOwnerNotSigner';
+
+  readonly code: number = 0x17c4; // 6084
+
+  constructor(program: Program, cause?: Error) {
+    super('NFT owner must sign to approve the mint delegate', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c4, CmMintDelegateOwnerNotSignerError);
+nameToErrorMap.set(
+  'MintDelegateOwnerNotSigner',
+  CmMintDelegateOwnerNotSignerError
+);
+
+/** MintRequestOutOfOrder: Mint requests must be revealed in the order they were created */
+export class CmMintRequestOutOfOrderError extends ProgramError {
+  readonly name: string = 'MintRequestOutOfOrder';
+
+  readonly code: number = 0x17c5; // 6085
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Mint requests must be revealed in the order they were created',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17c5, CmMintRequestOutOfOrderError);
+nameToErrorMap.set('MintRequestOutOfOrder', CmMintRequestOutOfOrderError);
+
+/** PendingMintRequests: Candy machine has pending mint requests */
+export class CmPendingMintRequestsError extends ProgramError {
+  readonly name: string = 'PendingMintRequests';
+
+  readonly code: number = 0x17c6; // 6086
+
+  constructor(program: Program, cause?: Error) {
+    super('Candy machine has pending mint requests', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c6, CmPendingMintRequestsError);
+nameToErrorMap.set('PendingMintRequests', CmPendingMintRequestsError);
+
+/** OnchainRevealNotEnabled: On-chain reveal is not enabled */
+export class CmOnchainRevealNotEnabledError extends ProgramError {
+  readonly name: string = 'OnchainRevealNotEnabled';
+
+  readonly code: number = 0x17c7; // 6087
+
+  constructor(program: Program, cause?: Error) {
+    super('On-chain reveal is not enabled', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c7, CmOnchainRevealNotEnabledError);
+nameToErrorMap.set('OnchainRevealNotEnabled', CmOnchainRevealNotEnabledError);
+
+/** MissingCollectionMetadata: Missing collection metadata account */
+export class CmMissingCollectionMetadataError extends ProgramError {
+  readonly name: string = 'MissingCollectionMetadata';
+
+  readonly code: number = 0x17c8; // 6088
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing collection metadata account', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c8, CmMissingCollectionMetadataError);
+nameToErrorMap.set(
+  'MissingCollectionMetadata',
+  CmMissingCollectionMetadataError
+);
+
+/** MasterEditionNotSet: Master edition mint is not set */
+export class CmMasterEditionNotSetError extends ProgramError {
+  readonly name: string = 'MasterEditionNotSet';
+
+  readonly code: number = 0x17c9; // 6089
+
+  constructor(program: Program, cause?: Error) {
+    super('Master edition mint is not set', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c9, CmMasterEditionNotSetError);
+nameToErrorMap.set('MasterEditionNotSet', CmMasterEditionNotSetError);
+
+/** MasterEditionMismatch: Master edition accounts do not match the master edition mint */
+export class CmMasterEditionMismatchError extends ProgramError {
+  readonly name: string = 'MasterEditionMismatch';
+
+  readonly code: number = 0x17ca; // 6090
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Master edition accounts do not match the master edition mint',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17ca, CmMasterEditionMismatchError);
+nameToErrorMap.set('MasterEditionMismatch', CmMasterEditionMismatchError);
+
+/** NoChangingMasterEditionDuringMint: Can't change the master edition after items have begun to be minted */
+export class CmNoChangingMasterEditionDuringMintError extends ProgramError {
+  readonly name: string = 'NoChangingMasterEditionDuringMint';
+
+  readonly code: number = 0x17cb; // 6091
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      "Can't change the master edition after items have begun to be minted",
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17cb, CmNoChangingMasterEditionDuringMintError);
+nameToErrorMap.set(
+  'NoChangingMasterEditionDuringMint',
+  CmNoChangingMasterEditionDuringMintError
+);
+
+/** InvalidFungibleAmount: Fungible amount must be greater than zero */
+export class CmInvalidFungibleAmountError extends ProgramError {
+  readonly name: string = 'InvalidFungibleAmount';
+
+  readonly code: number = 0x17cc; // 6092
+
+  constructor(program: Program, cause?: Error) {
+    super('Fungible amount must be greater than zero', program, cause);
+  }
+}
+codeToErrorMap.set(0x17cc, CmInvalidFungibleAmountError);
+nameToErrorMap.set('InvalidFungibleAmount', CmInvalidFungibleAmountError);
+
+/** FungibleRandomSelection: Fungible tokens require the item to be known when minting (sequential config lines, hidden settings or a requested index) */
+export class CmFungibleRandomSelectionError extends ProgramError {
+  readonly name: string = 'FungibleRandomSelection';
+
+  readonly code: number = 0x17cd; // 6093
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Fungible tokens require the item to be known when minting (sequential config lines, hidden settings or a requested index)',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17cd, CmFungibleRandomSelectionError);
+nameToErrorMap.set('FungibleRandomSelection', CmFungibleRandomSelectionError);
+
+/** CommitRevealConflict: Commit-reveal mints cannot be combined with soulbound items or a mint delegate */
+export class CmCommitRevealConflictError extends ProgramError {
+  readonly name: string = 'CommitRevealConflict';
+
+  readonly code: number = 0x17ce; // 6094
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Commit-reveal mints cannot be combined with soulbound items or a mint delegate',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17ce, CmCommitRevealConflictError);
+nameToErrorMap.set('CommitRevealConflict', CmCommitRevealConflictError);
+
+/** PickItemRequiresCandyGuard: Requesting a specific item requires a candy guard as the mint authority */
+export class CmPickItemRequiresCandyGuardError extends ProgramError {
+  readonly name: string = 'PickItemRequiresCandyGuard';
+
+  readonly code: number = 0x17cf; // 6095
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Requesting a specific item requires a candy guard as the mint authority',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17cf, CmPickItemRequiresCandyGuardError);
+nameToErrorMap.set(
+  'PickItemRequiresCandyGuard',
+  CmPickItemRequiresCandyGuardError
+);
+
+/** PickItemRarityTiersConflict: Pick item mode cannot be combined with rarity tiers */
+export class CmPickItemRarityTiersConflictError extends ProgramError {
+  readonly name: string = 'PickItemRarityTiersConflict';
+
+  readonly code: number = 0x17d0; // 6096
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Pick item mode cannot be combined with rarity tiers',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17d0, CmPickItemRarityTiersConflictError);
+nameToErrorMap.set(
+  'PickItemRarityTiersConflict',
+  CmPickItemRarityTiersConflictError
+);
+
+/** AccountGrowthTooLarge: Account size can only increase by 10240 bytes per instruction */
+export class CmAccountGrowthTooLargeError extends ProgramError {
+  readonly name: string = 'AccountGrowthTooLarge';
+
+  readonly code: number = 0x17d1; // 6097
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Account size can only increase by 10240 bytes per instruction',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17d1, CmAccountGrowthTooLargeError);
+nameToErrorMap.set('AccountGrowthTooLarge', CmAccountGrowthTooLargeError);
+
+/** ItemsNotTracked: Minted items are not tracked by the candy machine */
+export class CmItemsNotTrackedError extends ProgramError {
+  readonly name: string = 'ItemsNotTracked';
+
+  readonly code: number = 0x17d2; // 6098
+
+  constructor(program: Program, cause?: Error) {
+    super('Minted items are not tracked by the candy machine', program, cause);
+  }
+}
+codeToErrorMap.set(0x17d2, CmItemsNotTrackedError);
+nameToErrorMap.set('ItemsNotTracked', CmItemsNotTrackedError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
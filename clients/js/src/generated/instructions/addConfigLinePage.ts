@@ -0,0 +1,141 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type AddConfigLinePageInstructionAccounts = {
+  /** Candy Machine account. */
+  candyMachine: PublicKey | Pda;
+  /** Candy Machine authority. */
+  authority?: Signer;
+  /**
+   * Config line page account (must be pre-allocated but zero content).
+   *
+   */
+
+  configLinePage: PublicKey | Pda;
+  /** Payer of the transaction. */
+  payer?: Signer;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type AddConfigLinePageInstructionData = { discriminator: Array<number> };
+
+export type AddConfigLinePageInstructionDataArgs = {};
+
+export function getAddConfigLinePageInstructionDataSerializer(): Serializer<
+  AddConfigLinePageInstructionDataArgs,
+  AddConfigLinePageInstructionData
+> {
+  return mapSerializer<
+    AddConfigLinePageInstructionDataArgs,
+    any,
+    AddConfigLinePageInstructionData
+  >(
+    struct<AddConfigLinePageInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'AddConfigLinePageInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: [206, 251, 40, 44, 11, 184, 4, 76] })
+  ) as Serializer<
+    AddConfigLinePageInstructionDataArgs,
+    AddConfigLinePageInstructionData
+  >;
+}
+
+// Instruction.
+export function addConfigLinePage(
+  context: Pick<Context, 'identity' | 'payer' | 'programs'>,
+  input: AddConfigLinePageInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.candyMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+    configLinePage: {
+      index: 2,
+      isWritable: true,
+      value: input.configLinePage ?? null,
+    },
+    payer: { index: 3, isWritable: true, value: input.payer ?? null },
+    systemProgram: {
+      index: 4,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAddConfigLinePageInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
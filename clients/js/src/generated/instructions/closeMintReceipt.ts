@@ -0,0 +1,131 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CloseMintReceiptInstructionAccounts = {
+  /** Candy Machine account. */
+  candyMachine: PublicKey | Pda;
+  /** Candy Machine authority. */
+  authority?: Signer;
+  /** Mint receipt account. */
+  mintReceipt: PublicKey | Pda;
+  /**
+   * Payer of the mint receipt, which receives the rent of the receipt account.
+   *
+   */
+
+  mintReceiptPayer: PublicKey | Pda;
+};
+
+// Data.
+export type CloseMintReceiptInstructionData = { discriminator: Array<number> };
+
+export type CloseMintReceiptInstructionDataArgs = {};
+
+export function getCloseMintReceiptInstructionDataSerializer(): Serializer<
+  CloseMintReceiptInstructionDataArgs,
+  CloseMintReceiptInstructionData
+> {
+  return mapSerializer<
+    CloseMintReceiptInstructionDataArgs,
+    any,
+    CloseMintReceiptInstructionData
+  >(
+    struct<CloseMintReceiptInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'CloseMintReceiptInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [237, 71, 117, 45, 108, 208, 4, 227],
+    })
+  ) as Serializer<
+    CloseMintReceiptInstructionDataArgs,
+    CloseMintReceiptInstructionData
+  >;
+}
+
+// Instruction.
+export function closeMintReceipt(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: CloseMintReceiptInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: false,
+      value: input.candyMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+    mintReceipt: {
+      index: 2,
+      isWritable: true,
+      value: input.mintReceipt ?? null,
+    },
+    mintReceiptPayer: {
+      index: 3,
+      isWritable: true,
+      value: input.mintReceiptPayer ?? null,
+    },
+  };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCloseMintReceiptInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
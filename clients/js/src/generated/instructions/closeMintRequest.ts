@@ -0,0 +1,141 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CloseMintRequestInstructionAccounts = {
+  /** Candy Machine account. */
+  candyMachine: PublicKey | Pda;
+  /** Mint request account. */
+  mintRequest: PublicKey | Pda;
+  /**
+   * Payer of the mint request, which receives the rent of the request account.
+   *
+   */
+
+  mintRequestPayer: PublicKey | Pda;
+  /**
+   * SlotHashes sysvar cluster data.
+   *
+   */
+
+  recentSlothashes?: PublicKey | Pda;
+};
+
+// Data.
+export type CloseMintRequestInstructionData = { discriminator: Array<number> };
+
+export type CloseMintRequestInstructionDataArgs = {};
+
+export function getCloseMintRequestInstructionDataSerializer(): Serializer<
+  CloseMintRequestInstructionDataArgs,
+  CloseMintRequestInstructionData
+> {
+  return mapSerializer<
+    CloseMintRequestInstructionDataArgs,
+    any,
+    CloseMintRequestInstructionData
+  >(
+    struct<CloseMintRequestInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'CloseMintRequestInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [110, 107, 165, 251, 73, 53, 214, 194],
+    })
+  ) as Serializer<
+    CloseMintRequestInstructionDataArgs,
+    CloseMintRequestInstructionData
+  >;
+}
+
+// Instruction.
+export function closeMintRequest(
+  context: Pick<Context, 'programs'>,
+  input: CloseMintRequestInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.candyMachine ?? null,
+    },
+    mintRequest: {
+      index: 1,
+      isWritable: true,
+      value: input.mintRequest ?? null,
+    },
+    mintRequestPayer: {
+      index: 2,
+      isWritable: true,
+      value: input.mintRequestPayer ?? null,
+    },
+    recentSlothashes: {
+      index: 3,
+      isWritable: false,
+      value: input.recentSlothashes ?? null,
+    },
+  };
+
+  // Default values.
+  if (!resolvedAccounts.recentSlothashes.value) {
+    resolvedAccounts.recentSlothashes.value = publicKey(
+      'SysvarS1otHashes111111111111111111111111111'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCloseMintRequestInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
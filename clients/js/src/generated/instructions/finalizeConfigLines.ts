@@ -0,0 +1,135 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type FinalizeConfigLinesInstructionAccounts = {
+  /** Candy Machine account. */
+  candyMachine: PublicKey | Pda;
+  /** Candy Machine authority. */
+  authority?: Signer;
+  /** Payer of the transaction. */
+  payer?: Signer;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type FinalizeConfigLinesInstructionData = {
+  discriminator: Array<number>;
+};
+
+export type FinalizeConfigLinesInstructionDataArgs = {};
+
+export function getFinalizeConfigLinesInstructionDataSerializer(): Serializer<
+  FinalizeConfigLinesInstructionDataArgs,
+  FinalizeConfigLinesInstructionData
+> {
+  return mapSerializer<
+    FinalizeConfigLinesInstructionDataArgs,
+    any,
+    FinalizeConfigLinesInstructionData
+  >(
+    struct<FinalizeConfigLinesInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'FinalizeConfigLinesInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [171, 61, 218, 56, 127, 115, 12, 217],
+    })
+  ) as Serializer<
+    FinalizeConfigLinesInstructionDataArgs,
+    FinalizeConfigLinesInstructionData
+  >;
+}
+
+// Instruction.
+export function finalizeConfigLines(
+  context: Pick<Context, 'identity' | 'payer' | 'programs'>,
+  input: FinalizeConfigLinesInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.candyMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+    payer: { index: 2, isWritable: true, value: input.payer ?? null },
+    systemProgram: {
+      index: 3,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getFinalizeConfigLinesInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
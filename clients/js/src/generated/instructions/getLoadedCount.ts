@@ -0,0 +1,104 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type GetLoadedCountInstructionAccounts = {
+  /** Candy Machine account. */
+  candyMachine: PublicKey | Pda;
+};
+
+// Data.
+export type GetLoadedCountInstructionData = { discriminator: Array<number> };
+
+export type GetLoadedCountInstructionDataArgs = {};
+
+export function getGetLoadedCountInstructionDataSerializer(): Serializer<
+  GetLoadedCountInstructionDataArgs,
+  GetLoadedCountInstructionData
+> {
+  return mapSerializer<
+    GetLoadedCountInstructionDataArgs,
+    any,
+    GetLoadedCountInstructionData
+  >(
+    struct<GetLoadedCountInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'GetLoadedCountInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [87, 129, 34, 250, 29, 52, 146, 57],
+    })
+  ) as Serializer<
+    GetLoadedCountInstructionDataArgs,
+    GetLoadedCountInstructionData
+  >;
+}
+
+// Instruction.
+export function getLoadedCount(
+  context: Pick<Context, 'programs'>,
+  input: GetLoadedCountInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: false,
+      value: input.candyMachine ?? null,
+    },
+  };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getGetLoadedCountInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
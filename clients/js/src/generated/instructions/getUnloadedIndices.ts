@@ -0,0 +1,121 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type GetUnloadedIndicesInstructionAccounts = {
+  /** Candy Machine account. */
+  candyMachine: PublicKey | Pda;
+};
+
+// Data.
+export type GetUnloadedIndicesInstructionData = {
+  discriminator: Array<number>;
+  start: number;
+};
+
+export type GetUnloadedIndicesInstructionDataArgs = { start: number };
+
+export function getGetUnloadedIndicesInstructionDataSerializer(): Serializer<
+  GetUnloadedIndicesInstructionDataArgs,
+  GetUnloadedIndicesInstructionData
+> {
+  return mapSerializer<
+    GetUnloadedIndicesInstructionDataArgs,
+    any,
+    GetUnloadedIndicesInstructionData
+  >(
+    struct<GetUnloadedIndicesInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['start', u32()],
+      ],
+      { description: 'GetUnloadedIndicesInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [148, 238, 4, 65, 53, 210, 159, 213],
+    })
+  ) as Serializer<
+    GetUnloadedIndicesInstructionDataArgs,
+    GetUnloadedIndicesInstructionData
+  >;
+}
+
+// Args.
+export type GetUnloadedIndicesInstructionArgs =
+  GetUnloadedIndicesInstructionDataArgs;
+
+// Instruction.
+export function getUnloadedIndices(
+  context: Pick<Context, 'programs'>,
+  input: GetUnloadedIndicesInstructionAccounts &
+    GetUnloadedIndicesInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: false,
+      value: input.candyMachine ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: GetUnloadedIndicesInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getGetUnloadedIndicesInstructionDataSerializer().serialize(
+    resolvedArgs as GetUnloadedIndicesInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -6,19 +6,49 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './addConfigLinePage';
 export * from './addConfigLines';
+export * from './closeMintReceipt';
+export * from './closeMintRequest';
 export * from './deleteCandyGuard';
 export * from './deleteCandyMachine';
+export * from './finalizeConfigLines';
+export * from './getItemsRemaining';
+export * from './getLoadedCount';
+export * from './getUnloadedIndices';
 export * from './initializeCandyMachine';
 export * from './initializeCandyMachineV2';
+export * from './initializeCandyMachineV3';
+export * from './initializeCandyMachineWithoutCollection';
+export * from './mintBatchFromCandyMachine';
 export * from './mintFromCandyMachine';
 export * from './mintFromCandyMachineV2';
+export * from './mintFromCandyMachineV3';
+export * from './previewConfigLine';
+export * from './releaseSoulbound';
+export * from './removeConfigLine';
+export * from './resizeConfigLines';
+export * from './revealItem';
+export * from './revealMint';
 export * from './setCandyGuardAuthority';
 export * from './setCandyMachineAuthority';
 export * from './setCollection';
 export * from './setCollectionV2';
+export * from './setCommitReveal';
+export * from './setConfigLinesRoot';
+export * from './setFungibleAmount';
+export * from './setItemsAvailable';
+export * from './setMasterEdition';
 export * from './setMintAuthority';
+export * from './setMintDelegate';
+export * from './setMintReceipt';
+export * from './setOnchainReveal';
+export * from './setPaused';
+export * from './setPickItem';
+export * from './setRarityTiers';
+export * from './setSoulbound';
 export * from './setTokenStandard';
+export * from './setUriEncoding';
 export * from './unwrap';
 export * from './updateCandyMachine';
 export * from './wrap';
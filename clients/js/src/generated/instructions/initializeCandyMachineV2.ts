@@ -8,12 +8,9 @@
 
 import {
   MetadataDelegateRole,
-  TokenStandard,
-  TokenStandardArgs,
   findMasterEditionPda,
   findMetadataDelegateRecordPda,
   findMetadataPda,
-  getTokenStandardSerializer,
 } from '@metaplex-foundation/mpl-token-metadata';
 import {
   Amount,
@@ -41,7 +38,12 @@ import {
   u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
-import { findCandyMachineAuthorityPda } from '../../hooked';
+import {
+  CandyMachineTokenStandard,
+  CandyMachineTokenStandardArgs,
+  findCandyMachineAuthorityPda,
+  getCandyMachineTokenStandardSerializer,
+} from '../../hooked';
 import {
   ResolvedAccount,
   ResolvedAccountsWithIndices,
@@ -166,7 +168,7 @@ export type InitializeCandyMachineV2InstructionData = {
   configLineSettings: Option<ConfigLineSettings>;
   /** Hidden setttings */
   hiddenSettings: Option<HiddenSettings>;
-  tokenStandard: TokenStandard;
+  tokenStandard: CandyMachineTokenStandard;
 };
 
 export type InitializeCandyMachineV2InstructionDataArgs = {
@@ -186,7 +188,7 @@ export type InitializeCandyMachineV2InstructionDataArgs = {
   configLineSettings?: OptionOrNullable<ConfigLineSettingsArgs>;
   /** Hidden setttings */
   hiddenSettings?: OptionOrNullable<HiddenSettingsArgs>;
-  tokenStandard: TokenStandardArgs;
+  tokenStandard: CandyMachineTokenStandardArgs;
 };
 
 export function getInitializeCandyMachineV2InstructionDataSerializer(): Serializer<
@@ -209,7 +211,7 @@ export function getInitializeCandyMachineV2InstructionDataSerializer(): Serializ
         ['creators', array(getCreatorSerializer())],
         ['configLineSettings', option(getConfigLineSettingsSerializer())],
         ['hiddenSettings', option(getHiddenSettingsSerializer())],
-        ['tokenStandard', getTokenStandardSerializer()],
+        ['tokenStandard', getCandyMachineTokenStandardSerializer()],
       ],
       { description: 'InitializeCandyMachineV2InstructionData' }
     ),
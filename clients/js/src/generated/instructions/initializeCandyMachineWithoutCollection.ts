@@ -0,0 +1,241 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Amount,
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  mapAmountSerializer,
+  none,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  mapSerializer,
+  option,
+  string,
+  struct,
+  u16,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  CandyMachineTokenStandard,
+  CandyMachineTokenStandardArgs,
+  getCandyMachineTokenStandardSerializer,
+} from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  ConfigLineSettings,
+  ConfigLineSettingsArgs,
+  Creator,
+  CreatorArgs,
+  HiddenSettings,
+  HiddenSettingsArgs,
+  getConfigLineSettingsSerializer,
+  getCreatorSerializer,
+  getHiddenSettingsSerializer,
+} from '../types';
+
+// Accounts.
+export type InitializeCandyMachineWithoutCollectionInstructionAccounts = {
+  /**
+   * Candy Machine account. The account space must be allocated to allow accounts larger
+   * than 10kb.
+   *
+   */
+
+  candyMachine: PublicKey | Pda;
+  /**
+   * Candy Machine authority. This is the address that controls the upate of the candy machine.
+   *
+   */
+
+  authority?: PublicKey | Pda;
+  /** Payer of the transaction. */
+  payer?: Signer;
+  /**
+   * Authorization rule set to be used by minted NFTs.
+   *
+   */
+
+  ruleSet?: PublicKey | Pda;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type InitializeCandyMachineWithoutCollectionInstructionData = {
+  discriminator: Array<number>;
+  /** Number of assets available */
+  itemsAvailable: bigint;
+  /** Symbol for the asset */
+  symbol: string;
+  /** Secondary sales royalty basis points (0-10000) */
+  sellerFeeBasisPoints: Amount<'%', 2>;
+  /** Max supply of each individual asset (default 0) */
+  maxEditionSupply: bigint;
+  /** Indicates if the asset is mutable or not (default yes) */
+  isMutable: boolean;
+  /** List of creators */
+  creators: Array<Creator>;
+  /** Config line settings */
+  configLineSettings: Option<ConfigLineSettings>;
+  /** Hidden setttings */
+  hiddenSettings: Option<HiddenSettings>;
+  tokenStandard: CandyMachineTokenStandard;
+};
+
+export type InitializeCandyMachineWithoutCollectionInstructionDataArgs = {
+  /** Number of assets available */
+  itemsAvailable: number | bigint;
+  /** Symbol for the asset */
+  symbol?: string;
+  /** Secondary sales royalty basis points (0-10000) */
+  sellerFeeBasisPoints: Amount<'%', 2>;
+  /** Max supply of each individual asset (default 0) */
+  maxEditionSupply?: number | bigint;
+  /** Indicates if the asset is mutable or not (default yes) */
+  isMutable?: boolean;
+  /** List of creators */
+  creators: Array<CreatorArgs>;
+  /** Config line settings */
+  configLineSettings?: OptionOrNullable<ConfigLineSettingsArgs>;
+  /** Hidden setttings */
+  hiddenSettings?: OptionOrNullable<HiddenSettingsArgs>;
+  tokenStandard: CandyMachineTokenStandardArgs;
+};
+
+export function getInitializeCandyMachineWithoutCollectionInstructionDataSerializer(): Serializer<
+  InitializeCandyMachineWithoutCollectionInstructionDataArgs,
+  InitializeCandyMachineWithoutCollectionInstructionData
+> {
+  return mapSerializer<
+    InitializeCandyMachineWithoutCollectionInstructionDataArgs,
+    any,
+    InitializeCandyMachineWithoutCollectionInstructionData
+  >(
+    struct<InitializeCandyMachineWithoutCollectionInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['itemsAvailable', u64()],
+        ['symbol', string()],
+        ['sellerFeeBasisPoints', mapAmountSerializer(u16(), '%', 2)],
+        ['maxEditionSupply', u64()],
+        ['isMutable', bool()],
+        ['creators', array(getCreatorSerializer())],
+        ['configLineSettings', option(getConfigLineSettingsSerializer())],
+        ['hiddenSettings', option(getHiddenSettingsSerializer())],
+        ['tokenStandard', getCandyMachineTokenStandardSerializer()],
+      ],
+      { description: 'InitializeCandyMachineWithoutCollectionInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [20, 102, 131, 231, 92, 222, 213, 43],
+      symbol: value.symbol ?? '',
+      maxEditionSupply: value.maxEditionSupply ?? 0,
+      isMutable: value.isMutable ?? true,
+      configLineSettings: value.configLineSettings ?? none(),
+      hiddenSettings: value.hiddenSettings ?? none(),
+    })
+  ) as Serializer<
+    InitializeCandyMachineWithoutCollectionInstructionDataArgs,
+    InitializeCandyMachineWithoutCollectionInstructionData
+  >;
+}
+
+// Args.
+export type InitializeCandyMachineWithoutCollectionInstructionArgs =
+  InitializeCandyMachineWithoutCollectionInstructionDataArgs;
+
+// Instruction.
+export function initializeCandyMachineWithoutCollection(
+  context: Pick<Context, 'identity' | 'payer' | 'programs'>,
+  input: InitializeCandyMachineWithoutCollectionInstructionAccounts &
+    InitializeCandyMachineWithoutCollectionInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.candyMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+    payer: { index: 2, isWritable: true, value: input.payer ?? null },
+    ruleSet: { index: 3, isWritable: false, value: input.ruleSet ?? null },
+    systemProgram: {
+      index: 4,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: InitializeCandyMachineWithoutCollectionInstructionArgs = {
+    ...input,
+  };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity.publicKey;
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getInitializeCandyMachineWithoutCollectionInstructionDataSerializer().serialize(
+      resolvedArgs as InitializeCandyMachineWithoutCollectionInstructionDataArgs
+    );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
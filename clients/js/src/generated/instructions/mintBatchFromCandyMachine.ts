@@ -0,0 +1,410 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  MetadataDelegateRole,
+  findMasterEditionPda,
+  findMetadataDelegateRecordPda,
+  findMetadataPda,
+} from '@metaplex-foundation/mpl-token-metadata';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findCandyMachineAuthorityPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type MintBatchFromCandyMachineInstructionAccounts = {
+  /** Candy machine account. */
+  candyMachine: PublicKey | Pda;
+  /**
+   * Candy machine authority account. This is the account that holds a delegate
+   * to verify an item into the collection.
+   *
+   */
+
+  authorityPda?: PublicKey | Pda;
+  /**
+   * Candy machine authority (batch mint only allowed for the authority). This is also
+   * the mint authority of the NFTs.
+   */
+
+  authority?: Signer;
+  /** Payer for the transaction and account allocation (rent). */
+  payer?: Signer;
+  /**
+   * Collection authority or metadata delegate record.
+   *
+   */
+
+  collectionDelegateRecord?: PublicKey | Pda;
+  /**
+   * Mint account of the collection NFT.
+   *
+   */
+
+  collectionMint: PublicKey | Pda;
+  /**
+   * Metadata account of the collection NFT.
+   *
+   */
+
+  collectionMetadata?: PublicKey | Pda;
+  /**
+   * Master edition account of the collection NFT.
+   *
+   */
+
+  collectionMasterEdition?: PublicKey | Pda;
+  /**
+   * Update authority of the collection NFT.
+   *
+   */
+
+  collectionUpdateAuthority: PublicKey | Pda;
+  /**
+   * Token Metadata program.
+   *
+   */
+
+  tokenMetadataProgram?: PublicKey | Pda;
+  /** SPL Token program. */
+  splTokenProgram?: PublicKey | Pda;
+  /** SPL Associated Token program. */
+  splAtaProgram?: PublicKey | Pda;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+  /**
+   * Instructions sysvar account.
+   *
+   */
+
+  sysvarInstructions?: PublicKey | Pda;
+  /**
+   * SlotHashes sysvar cluster data.
+   *
+   */
+
+  recentSlothashes?: PublicKey | Pda;
+  /**
+   * MPL Core program (required for Core assets).
+   *
+   */
+
+  mplCoreProgram?: PublicKey | Pda;
+  /**
+   * Merkle tree account (required for compressed NFTs).
+   *
+   */
+
+  merkleTree?: PublicKey | Pda;
+  /**
+   * Tree config account of the merkle tree (required for compressed NFTs).
+   *
+   */
+
+  treeConfig?: PublicKey | Pda;
+  /**
+   * Bubblegum collection signer (required for compressed NFTs).
+   *
+   */
+
+  bubblegumSigner?: PublicKey | Pda;
+  /**
+   * Bubblegum program (required for compressed NFTs).
+   *
+   */
+
+  bubblegumProgram?: PublicKey | Pda;
+  /**
+   * SPL Noop program (required for compressed NFTs).
+   *
+   */
+
+  logWrapper?: PublicKey | Pda;
+  /**
+   * SPL Account Compression program (required for compressed NFTs).
+   *
+   */
+
+  compressionProgram?: PublicKey | Pda;
+  /**
+   * SPL Token-2022 program (required for Token-2022 NFTs).
+   *
+   */
+
+  splToken2022Program?: PublicKey | Pda;
+};
+
+// Data.
+export type MintBatchFromCandyMachineInstructionData = {
+  discriminator: Array<number>;
+};
+
+export type MintBatchFromCandyMachineInstructionDataArgs = {};
+
+export function getMintBatchFromCandyMachineInstructionDataSerializer(): Serializer<
+  MintBatchFromCandyMachineInstructionDataArgs,
+  MintBatchFromCandyMachineInstructionData
+> {
+  return mapSerializer<
+    MintBatchFromCandyMachineInstructionDataArgs,
+    any,
+    MintBatchFromCandyMachineInstructionData
+  >(
+    struct<MintBatchFromCandyMachineInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'MintBatchFromCandyMachineInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [235, 173, 151, 27, 251, 97, 247, 81],
+    })
+  ) as Serializer<
+    MintBatchFromCandyMachineInstructionDataArgs,
+    MintBatchFromCandyMachineInstructionData
+  >;
+}
+
+// Instruction.
+export function mintBatchFromCandyMachine(
+  context: Pick<Context, 'eddsa' | 'identity' | 'payer' | 'programs'>,
+  input: MintBatchFromCandyMachineInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.candyMachine ?? null,
+    },
+    authorityPda: {
+      index: 1,
+      isWritable: true,
+      value: input.authorityPda ?? null,
+    },
+    authority: { index: 2, isWritable: false, value: input.authority ?? null },
+    payer: { index: 3, isWritable: true, value: input.payer ?? null },
+    collectionDelegateRecord: {
+      index: 4,
+      isWritable: false,
+      value: input.collectionDelegateRecord ?? null,
+    },
+    collectionMint: {
+      index: 5,
+      isWritable: false,
+      value: input.collectionMint ?? null,
+    },
+    collectionMetadata: {
+      index: 6,
+      isWritable: true,
+      value: input.collectionMetadata ?? null,
+    },
+    collectionMasterEdition: {
+      index: 7,
+      isWritable: false,
+      value: input.collectionMasterEdition ?? null,
+    },
+    collectionUpdateAuthority: {
+      index: 8,
+      isWritable: false,
+      value: input.collectionUpdateAuthority ?? null,
+    },
+    tokenMetadataProgram: {
+      index: 9,
+      isWritable: false,
+      value: input.tokenMetadataProgram ?? null,
+    },
+    splTokenProgram: {
+      index: 10,
+      isWritable: false,
+      value: input.splTokenProgram ?? null,
+    },
+    splAtaProgram: {
+      index: 11,
+      isWritable: false,
+      value: input.splAtaProgram ?? null,
+    },
+    systemProgram: {
+      index: 12,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+    sysvarInstructions: {
+      index: 13,
+      isWritable: false,
+      value: input.sysvarInstructions ?? null,
+    },
+    recentSlothashes: {
+      index: 14,
+      isWritable: false,
+      value: input.recentSlothashes ?? null,
+    },
+    mplCoreProgram: {
+      index: 15,
+      isWritable: false,
+      value: input.mplCoreProgram ?? null,
+    },
+    merkleTree: {
+      index: 16,
+      isWritable: true,
+      value: input.merkleTree ?? null,
+    },
+    treeConfig: {
+      index: 17,
+      isWritable: true,
+      value: input.treeConfig ?? null,
+    },
+    bubblegumSigner: {
+      index: 18,
+      isWritable: false,
+      value: input.bubblegumSigner ?? null,
+    },
+    bubblegumProgram: {
+      index: 19,
+      isWritable: false,
+      value: input.bubblegumProgram ?? null,
+    },
+    logWrapper: {
+      index: 20,
+      isWritable: false,
+      value: input.logWrapper ?? null,
+    },
+    compressionProgram: {
+      index: 21,
+      isWritable: false,
+      value: input.compressionProgram ?? null,
+    },
+    splToken2022Program: {
+      index: 22,
+      isWritable: false,
+      value: input.splToken2022Program ?? null,
+    },
+  };
+
+  // Default values.
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = findCandyMachineAuthorityPda(
+      context,
+      { candyMachine: expectPublicKey(resolvedAccounts.candyMachine.value) }
+    );
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.collectionDelegateRecord.value) {
+    resolvedAccounts.collectionDelegateRecord.value =
+      findMetadataDelegateRecordPda(context, {
+        mint: expectPublicKey(resolvedAccounts.collectionMint.value),
+        delegateRole: MetadataDelegateRole.Collection,
+        updateAuthority: expectPublicKey(
+          resolvedAccounts.collectionUpdateAuthority.value
+        ),
+        delegate: expectPublicKey(resolvedAccounts.authorityPda.value),
+      });
+  }
+  if (!resolvedAccounts.collectionMetadata.value) {
+    resolvedAccounts.collectionMetadata.value = findMetadataPda(context, {
+      mint: expectPublicKey(resolvedAccounts.collectionMint.value),
+    });
+  }
+  if (!resolvedAccounts.collectionMasterEdition.value) {
+    resolvedAccounts.collectionMasterEdition.value = findMasterEditionPda(
+      context,
+      { mint: expectPublicKey(resolvedAccounts.collectionMint.value) }
+    );
+  }
+  if (!resolvedAccounts.tokenMetadataProgram.value) {
+    resolvedAccounts.tokenMetadataProgram.value = context.programs.getPublicKey(
+      'mplTokenMetadata',
+      'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s'
+    );
+    resolvedAccounts.tokenMetadataProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.splTokenProgram.value) {
+    resolvedAccounts.splTokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.splTokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.splAtaProgram.value) {
+    resolvedAccounts.splAtaProgram.value = context.programs.getPublicKey(
+      'splAssociatedToken',
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+    );
+    resolvedAccounts.splAtaProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.sysvarInstructions.value) {
+    resolvedAccounts.sysvarInstructions.value = publicKey(
+      'Sysvar1nstructions1111111111111111111111111'
+    );
+  }
+  if (!resolvedAccounts.recentSlothashes.value) {
+    resolvedAccounts.recentSlothashes.value = publicKey(
+      'SysvarS1otHashes111111111111111111111111111'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getMintBatchFromCandyMachineInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
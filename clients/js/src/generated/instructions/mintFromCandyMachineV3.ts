@@ -0,0 +1,579 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  MetadataDelegateRole,
+  findMasterEditionPda,
+  findMetadataDelegateRecordPda,
+  findMetadataPda,
+} from '@metaplex-foundation/mpl-token-metadata';
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  none,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  option,
+  string,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findCandyMachineAuthorityPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  ConfigLineProof,
+  ConfigLineProofArgs,
+  getConfigLineProofSerializer,
+} from '../types';
+
+// Accounts.
+export type MintFromCandyMachineV3InstructionAccounts = {
+  /** Candy machine account. */
+  candyMachine: PublicKey | Pda;
+  /**
+   * Candy machine authority account. This is the account that holds a delegate
+   * to verify an item into the collection.
+   *
+   */
+
+  authorityPda?: PublicKey | Pda;
+  /** Candy machine mint authority (mint only allowed for the mint_authority). */
+  mintAuthority: Signer;
+  /** Payer for the transaction and account allocation (rent). */
+  payer?: Signer;
+  /**
+   * NFT account owner.
+   *
+   */
+
+  nftOwner: PublicKey | Pda;
+  /**
+   * Mint account of the NFT. The account will be initialized if necessary.
+   *
+   */
+
+  nftMint: PublicKey | Pda | Signer;
+  /** Mint authority of the NFT. In most cases this will be the owner of the NFT. */
+  nftMintAuthority?: Signer;
+  /**
+   * Metadata account of the NFT. This account must be uninitialized.
+   *
+   */
+
+  nftMetadata?: PublicKey | Pda;
+  /**
+   * Master edition account of the NFT. The account will be initialized if necessary.
+   *
+   */
+
+  nftMasterEdition?: PublicKey | Pda;
+  /**
+   * Destination token account (required for pNFT).
+   *
+   */
+
+  token?: PublicKey | Pda;
+  /**
+   * Token record (required for pNFT).
+   *
+   */
+
+  tokenRecord?: PublicKey | Pda;
+  /**
+   * Collection authority or metadata delegate record.
+   *
+   */
+
+  collectionDelegateRecord?: PublicKey | Pda;
+  /**
+   * Mint account of the collection NFT.
+   *
+   */
+
+  collectionMint: PublicKey | Pda;
+  /**
+   * Metadata account of the collection NFT.
+   *
+   */
+
+  collectionMetadata?: PublicKey | Pda;
+  /**
+   * Master edition account of the collection NFT.
+   *
+   */
+
+  collectionMasterEdition?: PublicKey | Pda;
+  /**
+   * Update authority of the collection NFT.
+   *
+   */
+
+  collectionUpdateAuthority: PublicKey | Pda;
+  /**
+   * Token Metadata program.
+   *
+   */
+
+  tokenMetadataProgram?: PublicKey | Pda;
+  /** SPL Token program. */
+  splTokenProgram?: PublicKey | Pda;
+  /** SPL Associated Token program. */
+  splAtaProgram?: PublicKey | Pda;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+  /**
+   * Instructions sysvar account.
+   *
+   */
+
+  sysvarInstructions?: PublicKey | Pda;
+  /**
+   * SlotHashes sysvar cluster data.
+   *
+   */
+
+  recentSlothashes?: PublicKey | Pda;
+  /**
+   * Token Authorization Rules program.
+   *
+   */
+
+  authorizationRulesProgram?: PublicKey | Pda;
+  /**
+   * Token Authorization rules account for the collection metadata (if any).
+   *
+   */
+
+  authorizationRules?: PublicKey | Pda;
+  /**
+   * MPL Core program (required for Core assets).
+   *
+   */
+
+  mplCoreProgram?: PublicKey | Pda;
+  /**
+   * Merkle tree account (required for compressed NFTs).
+   *
+   */
+
+  merkleTree?: PublicKey | Pda;
+  /**
+   * Tree config account of the merkle tree (required for compressed NFTs).
+   *
+   */
+
+  treeConfig?: PublicKey | Pda;
+  /**
+   * Bubblegum collection signer (required for compressed NFTs).
+   *
+   */
+
+  bubblegumSigner?: PublicKey | Pda;
+  /**
+   * Bubblegum program (required for compressed NFTs).
+   *
+   */
+
+  bubblegumProgram?: PublicKey | Pda;
+  /**
+   * SPL Noop program (required for compressed NFTs).
+   *
+   */
+
+  logWrapper?: PublicKey | Pda;
+  /**
+   * SPL Account Compression program (required for compressed NFTs).
+   *
+   */
+
+  compressionProgram?: PublicKey | Pda;
+  /**
+   * SPL Token-2022 program (required for Token-2022 NFTs).
+   *
+   */
+
+  splToken2022Program?: PublicKey | Pda;
+  /**
+   * Mint request account (required when commit-reveal is enabled).
+   *
+   */
+
+  mintRequest?: PublicKey | Pda;
+  /**
+   * Mint receipt account (required when mint receipts are enabled).
+   *
+   */
+
+  mintReceipt?: PublicKey | Pda;
+};
+
+// Data.
+export type MintFromCandyMachineV3InstructionData = {
+  discriminator: Array<number>;
+  /** Index of the item to mint (requires the pick item mode). */
+  index: Option<number>;
+  /**
+   * Config line of the item to mint with its merkle proof (required when config lines
+   * are verified using a merkle root).
+   */
+  configLine: Option<ConfigLineProof>;
+  /** Group label recorded on the mint receipt (if any). */
+  group: Option<string>;
+};
+
+export type MintFromCandyMachineV3InstructionDataArgs = {
+  /** Index of the item to mint (requires the pick item mode). */
+  index?: OptionOrNullable<number>;
+  /**
+   * Config line of the item to mint with its merkle proof (required when config lines
+   * are verified using a merkle root).
+   */
+  configLine?: OptionOrNullable<ConfigLineProofArgs>;
+  /** Group label recorded on the mint receipt (if any). */
+  group?: OptionOrNullable<string>;
+};
+
+export function getMintFromCandyMachineV3InstructionDataSerializer(): Serializer<
+  MintFromCandyMachineV3InstructionDataArgs,
+  MintFromCandyMachineV3InstructionData
+> {
+  return mapSerializer<
+    MintFromCandyMachineV3InstructionDataArgs,
+    any,
+    MintFromCandyMachineV3InstructionData
+  >(
+    struct<MintFromCandyMachineV3InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['index', option(u32())],
+        ['configLine', option(getConfigLineProofSerializer())],
+        ['group', option(string())],
+      ],
+      { description: 'MintFromCandyMachineV3InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [51, 125, 82, 21, 125, 115, 4, 20],
+      index: value.index ?? none(),
+      configLine: value.configLine ?? none(),
+      group: value.group ?? none(),
+    })
+  ) as Serializer<
+    MintFromCandyMachineV3InstructionDataArgs,
+    MintFromCandyMachineV3InstructionData
+  >;
+}
+
+// Args.
+export type MintFromCandyMachineV3InstructionArgs =
+  MintFromCandyMachineV3InstructionDataArgs;
+
+// Instruction.
+export function mintFromCandyMachineV3(
+  context: Pick<Context, 'eddsa' | 'identity' | 'payer' | 'programs'>,
+  input: MintFromCandyMachineV3InstructionAccounts &
+    MintFromCandyMachineV3InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.candyMachine ?? null,
+    },
+    authorityPda: {
+      index: 1,
+      isWritable: true,
+      value: input.authorityPda ?? null,
+    },
+    mintAuthority: {
+      index: 2,
+      isWritable: false,
+      value: input.mintAuthority ?? null,
+    },
+    payer: { index: 3, isWritable: true, value: input.payer ?? null },
+    nftOwner: { index: 4, isWritable: false, value: input.nftOwner ?? null },
+    nftMint: { index: 5, isWritable: true, value: input.nftMint ?? null },
+    nftMintAuthority: {
+      index: 6,
+      isWritable: false,
+      value: input.nftMintAuthority ?? null,
+    },
+    nftMetadata: {
+      index: 7,
+      isWritable: true,
+      value: input.nftMetadata ?? null,
+    },
+    nftMasterEdition: {
+      index: 8,
+      isWritable: true,
+      value: input.nftMasterEdition ?? null,
+    },
+    token: { index: 9, isWritable: true, value: input.token ?? null },
+    tokenRecord: {
+      index: 10,
+      isWritable: true,
+      value: input.tokenRecord ?? null,
+    },
+    collectionDelegateRecord: {
+      index: 11,
+      isWritable: false,
+      value: input.collectionDelegateRecord ?? null,
+    },
+    collectionMint: {
+      index: 12,
+      isWritable: false,
+      value: input.collectionMint ?? null,
+    },
+    collectionMetadata: {
+      index: 13,
+      isWritable: true,
+      value: input.collectionMetadata ?? null,
+    },
+    collectionMasterEdition: {
+      index: 14,
+      isWritable: false,
+      value: input.collectionMasterEdition ?? null,
+    },
+    collectionUpdateAuthority: {
+      index: 15,
+      isWritable: false,
+      value: input.collectionUpdateAuthority ?? null,
+    },
+    tokenMetadataProgram: {
+      index: 16,
+      isWritable: false,
+      value: input.tokenMetadataProgram ?? null,
+    },
+    splTokenProgram: {
+      index: 17,
+      isWritable: false,
+      value: input.splTokenProgram ?? null,
+    },
+    splAtaProgram: {
+      index: 18,
+      isWritable: false,
+      value: input.splAtaProgram ?? null,
+    },
+    systemProgram: {
+      index: 19,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+    sysvarInstructions: {
+      index: 20,
+      isWritable: false,
+      value: input.sysvarInstructions ?? null,
+    },
+    recentSlothashes: {
+      index: 21,
+      isWritable: false,
+      value: input.recentSlothashes ?? null,
+    },
+    authorizationRulesProgram: {
+      index: 22,
+      isWritable: false,
+      value: input.authorizationRulesProgram ?? null,
+    },
+    authorizationRules: {
+      index: 23,
+      isWritable: false,
+      value: input.authorizationRules ?? null,
+    },
+    mplCoreProgram: {
+      index: 24,
+      isWritable: false,
+      value: input.mplCoreProgram ?? null,
+    },
+    merkleTree: {
+      index: 25,
+      isWritable: true,
+      value: input.merkleTree ?? null,
+    },
+    treeConfig: {
+      index: 26,
+      isWritable: true,
+      value: input.treeConfig ?? null,
+    },
+    bubblegumSigner: {
+      index: 27,
+      isWritable: false,
+      value: input.bubblegumSigner ?? null,
+    },
+    bubblegumProgram: {
+      index: 28,
+      isWritable: false,
+      value: input.bubblegumProgram ?? null,
+    },
+    logWrapper: {
+      index: 29,
+      isWritable: false,
+      value: input.logWrapper ?? null,
+    },
+    compressionProgram: {
+      index: 30,
+      isWritable: false,
+      value: input.compressionProgram ?? null,
+    },
+    splToken2022Program: {
+      index: 31,
+      isWritable: false,
+      value: input.splToken2022Program ?? null,
+    },
+    mintRequest: {
+      index: 32,
+      isWritable: true,
+      value: input.mintRequest ?? null,
+    },
+    mintReceipt: {
+      index: 33,
+      isWritable: true,
+      value: input.mintReceipt ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: MintFromCandyMachineV3InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = findCandyMachineAuthorityPda(
+      context,
+      { candyMachine: expectPublicKey(resolvedAccounts.candyMachine.value) }
+    );
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.nftMintAuthority.value) {
+    resolvedAccounts.nftMintAuthority.value = context.identity;
+  }
+  if (!resolvedAccounts.nftMetadata.value) {
+    resolvedAccounts.nftMetadata.value = findMetadataPda(context, {
+      mint: expectPublicKey(resolvedAccounts.nftMint.value),
+    });
+  }
+  if (!resolvedAccounts.nftMasterEdition.value) {
+    resolvedAccounts.nftMasterEdition.value = findMasterEditionPda(context, {
+      mint: expectPublicKey(resolvedAccounts.nftMint.value),
+    });
+  }
+  if (!resolvedAccounts.token.value) {
+    resolvedAccounts.token.value = findAssociatedTokenPda(context, {
+      mint: expectPublicKey(resolvedAccounts.nftMint.value),
+      owner: expectPublicKey(resolvedAccounts.nftOwner.value),
+    });
+  }
+  if (!resolvedAccounts.collectionDelegateRecord.value) {
+    resolvedAccounts.collectionDelegateRecord.value =
+      findMetadataDelegateRecordPda(context, {
+        mint: expectPublicKey(resolvedAccounts.collectionMint.value),
+        delegateRole: MetadataDelegateRole.Collection,
+        updateAuthority: expectPublicKey(
+          resolvedAccounts.collectionUpdateAuthority.value
+        ),
+        delegate: expectPublicKey(resolvedAccounts.authorityPda.value),
+      });
+  }
+  if (!resolvedAccounts.collectionMetadata.value) {
+    resolvedAccounts.collectionMetadata.value = findMetadataPda(context, {
+      mint: expectPublicKey(resolvedAccounts.collectionMint.value),
+    });
+  }
+  if (!resolvedAccounts.collectionMasterEdition.value) {
+    resolvedAccounts.collectionMasterEdition.value = findMasterEditionPda(
+      context,
+      { mint: expectPublicKey(resolvedAccounts.collectionMint.value) }
+    );
+  }
+  if (!resolvedAccounts.tokenMetadataProgram.value) {
+    resolvedAccounts.tokenMetadataProgram.value = context.programs.getPublicKey(
+      'mplTokenMetadata',
+      'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s'
+    );
+    resolvedAccounts.tokenMetadataProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.splTokenProgram.value) {
+    resolvedAccounts.splTokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.splTokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.splAtaProgram.value) {
+    resolvedAccounts.splAtaProgram.value = context.programs.getPublicKey(
+      'splAssociatedToken',
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+    );
+    resolvedAccounts.splAtaProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.sysvarInstructions.value) {
+    resolvedAccounts.sysvarInstructions.value = publicKey(
+      'Sysvar1nstructions1111111111111111111111111'
+    );
+  }
+  if (!resolvedAccounts.recentSlothashes.value) {
+    resolvedAccounts.recentSlothashes.value = publicKey(
+      'SysvarS1otHashes111111111111111111111111111'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getMintFromCandyMachineV3InstructionDataSerializer().serialize(
+    resolvedArgs as MintFromCandyMachineV3InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
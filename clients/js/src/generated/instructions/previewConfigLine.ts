@@ -0,0 +1,120 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type PreviewConfigLineInstructionAccounts = {
+  /** Candy Machine account. */
+  candyMachine: PublicKey | Pda;
+};
+
+// Data.
+export type PreviewConfigLineInstructionData = {
+  discriminator: Array<number>;
+  index: number;
+};
+
+export type PreviewConfigLineInstructionDataArgs = { index: number };
+
+export function getPreviewConfigLineInstructionDataSerializer(): Serializer<
+  PreviewConfigLineInstructionDataArgs,
+  PreviewConfigLineInstructionData
+> {
+  return mapSerializer<
+    PreviewConfigLineInstructionDataArgs,
+    any,
+    PreviewConfigLineInstructionData
+  >(
+    struct<PreviewConfigLineInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['index', u32()],
+      ],
+      { description: 'PreviewConfigLineInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [148, 237, 26, 146, 49, 82, 175, 96],
+    })
+  ) as Serializer<
+    PreviewConfigLineInstructionDataArgs,
+    PreviewConfigLineInstructionData
+  >;
+}
+
+// Args.
+export type PreviewConfigLineInstructionArgs =
+  PreviewConfigLineInstructionDataArgs;
+
+// Instruction.
+export function previewConfigLine(
+  context: Pick<Context, 'programs'>,
+  input: PreviewConfigLineInstructionAccounts & PreviewConfigLineInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: false,
+      value: input.candyMachine ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: PreviewConfigLineInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getPreviewConfigLineInstructionDataSerializer().serialize(
+    resolvedArgs as PreviewConfigLineInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,330 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  findMasterEditionPda,
+  findMetadataPda,
+} from '@metaplex-foundation/mpl-token-metadata';
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findCandyMachineAuthorityPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ReleaseSoulboundInstructionAccounts = {
+  /** Candy Machine account. */
+  candyMachine: PublicKey | Pda;
+  /** Candy Machine authority. */
+  authority?: Signer;
+  /**
+   * Authority PDA, the delegate that locked the item.
+   *
+   */
+
+  authorityPda?: PublicKey | Pda;
+  /** Payer of the transaction. */
+  payer?: Signer;
+  /**
+   * Owner of the item.
+   *
+   */
+
+  tokenOwner: PublicKey | Pda;
+  /**
+   * Token account of the item.
+   *
+   */
+
+  token?: PublicKey | Pda;
+  /**
+   * Mint account of the item.
+   *
+   */
+
+  nftMint: PublicKey | Pda;
+  /**
+   * Metadata account of the item.
+   *
+   */
+
+  nftMetadata?: PublicKey | Pda;
+  /**
+   * Master edition account of the item.
+   *
+   */
+
+  nftMasterEdition?: PublicKey | Pda;
+  /**
+   * Token record of the item (required for Programmable NFTs).
+   *
+   */
+
+  tokenRecord?: PublicKey | Pda;
+  /**
+   * Metadata account of the collection (required to burn items of a sized
+   * collection).
+   *
+   */
+
+  collectionMetadata?: PublicKey | Pda;
+  /**
+   * Token Metadata program.
+   *
+   */
+
+  tokenMetadataProgram?: PublicKey | Pda;
+  /** SPL Token program. */
+  splTokenProgram?: PublicKey | Pda;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+  /**
+   * Instructions sysvar account.
+   *
+   */
+
+  sysvarInstructions?: PublicKey | Pda;
+  /**
+   * Token Authorization Rules program.
+   *
+   */
+
+  authorizationRulesProgram?: PublicKey | Pda;
+  /**
+   * Token Authorization rules account of the item (if any).
+   *
+   */
+
+  authorizationRules?: PublicKey | Pda;
+};
+
+// Data.
+export type ReleaseSoulboundInstructionData = {
+  discriminator: Array<number>;
+  burn: boolean;
+};
+
+export type ReleaseSoulboundInstructionDataArgs = { burn: boolean };
+
+export function getReleaseSoulboundInstructionDataSerializer(): Serializer<
+  ReleaseSoulboundInstructionDataArgs,
+  ReleaseSoulboundInstructionData
+> {
+  return mapSerializer<
+    ReleaseSoulboundInstructionDataArgs,
+    any,
+    ReleaseSoulboundInstructionData
+  >(
+    struct<ReleaseSoulboundInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['burn', bool()],
+      ],
+      { description: 'ReleaseSoulboundInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [119, 85, 27, 169, 120, 114, 63, 214],
+    })
+  ) as Serializer<
+    ReleaseSoulboundInstructionDataArgs,
+    ReleaseSoulboundInstructionData
+  >;
+}
+
+// Args.
+export type ReleaseSoulboundInstructionArgs =
+  ReleaseSoulboundInstructionDataArgs;
+
+// Instruction.
+export function releaseSoulbound(
+  context: Pick<Context, 'eddsa' | 'identity' | 'payer' | 'programs'>,
+  input: ReleaseSoulboundInstructionAccounts & ReleaseSoulboundInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: false,
+      value: input.candyMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+    authorityPda: {
+      index: 2,
+      isWritable: false,
+      value: input.authorityPda ?? null,
+    },
+    payer: { index: 3, isWritable: true, value: input.payer ?? null },
+    tokenOwner: {
+      index: 4,
+      isWritable: false,
+      value: input.tokenOwner ?? null,
+    },
+    token: { index: 5, isWritable: true, value: input.token ?? null },
+    nftMint: { index: 6, isWritable: true, value: input.nftMint ?? null },
+    nftMetadata: {
+      index: 7,
+      isWritable: true,
+      value: input.nftMetadata ?? null,
+    },
+    nftMasterEdition: {
+      index: 8,
+      isWritable: true,
+      value: input.nftMasterEdition ?? null,
+    },
+    tokenRecord: {
+      index: 9,
+      isWritable: true,
+      value: input.tokenRecord ?? null,
+    },
+    collectionMetadata: {
+      index: 10,
+      isWritable: true,
+      value: input.collectionMetadata ?? null,
+    },
+    tokenMetadataProgram: {
+      index: 11,
+      isWritable: false,
+      value: input.tokenMetadataProgram ?? null,
+    },
+    splTokenProgram: {
+      index: 12,
+      isWritable: false,
+      value: input.splTokenProgram ?? null,
+    },
+    systemProgram: {
+      index: 13,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+    sysvarInstructions: {
+      index: 14,
+      isWritable: false,
+      value: input.sysvarInstructions ?? null,
+    },
+    authorizationRulesProgram: {
+      index: 15,
+      isWritable: false,
+      value: input.authorizationRulesProgram ?? null,
+    },
+    authorizationRules: {
+      index: 16,
+      isWritable: false,
+      value: input.authorizationRules ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: ReleaseSoulboundInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = findCandyMachineAuthorityPda(
+      context,
+      { candyMachine: expectPublicKey(resolvedAccounts.candyMachine.value) }
+    );
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.token.value) {
+    resolvedAccounts.token.value = findAssociatedTokenPda(context, {
+      mint: expectPublicKey(resolvedAccounts.nftMint.value),
+      owner: expectPublicKey(resolvedAccounts.tokenOwner.value),
+    });
+  }
+  if (!resolvedAccounts.nftMetadata.value) {
+    resolvedAccounts.nftMetadata.value = findMetadataPda(context, {
+      mint: expectPublicKey(resolvedAccounts.nftMint.value),
+    });
+  }
+  if (!resolvedAccounts.nftMasterEdition.value) {
+    resolvedAccounts.nftMasterEdition.value = findMasterEditionPda(context, {
+      mint: expectPublicKey(resolvedAccounts.nftMint.value),
+    });
+  }
+  if (!resolvedAccounts.tokenMetadataProgram.value) {
+    resolvedAccounts.tokenMetadataProgram.value = context.programs.getPublicKey(
+      'mplTokenMetadata',
+      'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s'
+    );
+    resolvedAccounts.tokenMetadataProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.splTokenProgram.value) {
+    resolvedAccounts.splTokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.splTokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.sysvarInstructions.value) {
+    resolvedAccounts.sysvarInstructions.value = publicKey(
+      'Sysvar1nstructions1111111111111111111111111'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getReleaseSoulboundInstructionDataSerializer().serialize(
+    resolvedArgs as ReleaseSoulboundInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,149 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RemoveConfigLineInstructionAccounts = {
+  /** Candy Machine account. */
+  candyMachine: PublicKey | Pda;
+  /** Candy Machine authority. */
+  authority?: Signer;
+  /** Payer of the transaction. */
+  payer?: Signer;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type RemoveConfigLineInstructionData = {
+  discriminator: Array<number>;
+  index: number;
+};
+
+export type RemoveConfigLineInstructionDataArgs = { index: number };
+
+export function getRemoveConfigLineInstructionDataSerializer(): Serializer<
+  RemoveConfigLineInstructionDataArgs,
+  RemoveConfigLineInstructionData
+> {
+  return mapSerializer<
+    RemoveConfigLineInstructionDataArgs,
+    any,
+    RemoveConfigLineInstructionData
+  >(
+    struct<RemoveConfigLineInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['index', u32()],
+      ],
+      { description: 'RemoveConfigLineInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [144, 13, 136, 27, 198, 158, 99, 62],
+    })
+  ) as Serializer<
+    RemoveConfigLineInstructionDataArgs,
+    RemoveConfigLineInstructionData
+  >;
+}
+
+// Args.
+export type RemoveConfigLineInstructionArgs =
+  RemoveConfigLineInstructionDataArgs;
+
+// Instruction.
+export function removeConfigLine(
+  context: Pick<Context, 'identity' | 'payer' | 'programs'>,
+  input: RemoveConfigLineInstructionAccounts & RemoveConfigLineInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.candyMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+    payer: { index: 2, isWritable: true, value: input.payer ?? null },
+    systemProgram: {
+      index: 3,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: RemoveConfigLineInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRemoveConfigLineInstructionDataSerializer().serialize(
+    resolvedArgs as RemoveConfigLineInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
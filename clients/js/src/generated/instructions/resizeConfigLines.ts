@@ -0,0 +1,154 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ResizeConfigLinesInstructionAccounts = {
+  /** Candy Machine account. */
+  candyMachine: PublicKey | Pda;
+  /** Candy Machine authority. */
+  authority?: Signer;
+  /** Payer of the transaction. */
+  payer?: Signer;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type ResizeConfigLinesInstructionData = {
+  discriminator: Array<number>;
+  nameLength: number;
+  uriLength: number;
+};
+
+export type ResizeConfigLinesInstructionDataArgs = {
+  nameLength: number;
+  uriLength: number;
+};
+
+export function getResizeConfigLinesInstructionDataSerializer(): Serializer<
+  ResizeConfigLinesInstructionDataArgs,
+  ResizeConfigLinesInstructionData
+> {
+  return mapSerializer<
+    ResizeConfigLinesInstructionDataArgs,
+    any,
+    ResizeConfigLinesInstructionData
+  >(
+    struct<ResizeConfigLinesInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['nameLength', u32()],
+        ['uriLength', u32()],
+      ],
+      { description: 'ResizeConfigLinesInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [142, 103, 120, 55, 65, 199, 22, 56],
+    })
+  ) as Serializer<
+    ResizeConfigLinesInstructionDataArgs,
+    ResizeConfigLinesInstructionData
+  >;
+}
+
+// Args.
+export type ResizeConfigLinesInstructionArgs =
+  ResizeConfigLinesInstructionDataArgs;
+
+// Instruction.
+export function resizeConfigLines(
+  context: Pick<Context, 'identity' | 'payer' | 'programs'>,
+  input: ResizeConfigLinesInstructionAccounts & ResizeConfigLinesInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.candyMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+    payer: { index: 2, isWritable: true, value: input.payer ?? null },
+    systemProgram: {
+      index: 3,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: ResizeConfigLinesInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getResizeConfigLinesInstructionDataSerializer().serialize(
+    resolvedArgs as ResizeConfigLinesInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,258 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  findMasterEditionPda,
+  findMetadataPda,
+} from '@metaplex-foundation/mpl-token-metadata';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findCandyMachineAuthorityPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  ConfigLineProof,
+  ConfigLineProofArgs,
+  getConfigLineProofSerializer,
+} from '../types';
+
+// Accounts.
+export type RevealItemInstructionAccounts = {
+  /** Candy Machine account. */
+  candyMachine: PublicKey | Pda;
+  /**
+   * Authority PDA, the update authority of items not revealed yet.
+   *
+   */
+
+  authorityPda?: PublicKey | Pda;
+  /** Payer of the transaction. */
+  payer?: Signer;
+  /**
+   * Mint account of the NFT.
+   *
+   */
+
+  nftMint: PublicKey | Pda;
+  /**
+   * Metadata account of the NFT.
+   *
+   */
+
+  nftMetadata?: PublicKey | Pda;
+  /**
+   * Master edition account of the NFT.
+   *
+   */
+
+  nftMasterEdition?: PublicKey | Pda;
+  /**
+   * Metadata account of the collection. Not used when the candy machine does not have
+   * a collection.
+   *
+   */
+
+  collectionMetadata?: PublicKey | Pda;
+  /**
+   * Token Metadata program.
+   *
+   */
+
+  tokenMetadataProgram?: PublicKey | Pda;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+  /**
+   * Instructions sysvar account.
+   *
+   */
+
+  sysvarInstructions?: PublicKey | Pda;
+};
+
+// Data.
+export type RevealItemInstructionData = {
+  discriminator: Array<number>;
+  index: number;
+  configLine: ConfigLineProof;
+};
+
+export type RevealItemInstructionDataArgs = {
+  index: number;
+  configLine: ConfigLineProofArgs;
+};
+
+export function getRevealItemInstructionDataSerializer(): Serializer<
+  RevealItemInstructionDataArgs,
+  RevealItemInstructionData
+> {
+  return mapSerializer<
+    RevealItemInstructionDataArgs,
+    any,
+    RevealItemInstructionData
+  >(
+    struct<RevealItemInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['index', u32()],
+        ['configLine', getConfigLineProofSerializer()],
+      ],
+      { description: 'RevealItemInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [180, 178, 73, 191, 108, 128, 41, 7],
+    })
+  ) as Serializer<RevealItemInstructionDataArgs, RevealItemInstructionData>;
+}
+
+// Args.
+export type RevealItemInstructionArgs = RevealItemInstructionDataArgs;
+
+// Instruction.
+export function revealItem(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: RevealItemInstructionAccounts & RevealItemInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: false,
+      value: input.candyMachine ?? null,
+    },
+    authorityPda: {
+      index: 1,
+      isWritable: false,
+      value: input.authorityPda ?? null,
+    },
+    payer: { index: 2, isWritable: true, value: input.payer ?? null },
+    nftMint: { index: 3, isWritable: false, value: input.nftMint ?? null },
+    nftMetadata: {
+      index: 4,
+      isWritable: true,
+      value: input.nftMetadata ?? null,
+    },
+    nftMasterEdition: {
+      index: 5,
+      isWritable: false,
+      value: input.nftMasterEdition ?? null,
+    },
+    collectionMetadata: {
+      index: 6,
+      isWritable: false,
+      value: input.collectionMetadata ?? null,
+    },
+    tokenMetadataProgram: {
+      index: 7,
+      isWritable: false,
+      value: input.tokenMetadataProgram ?? null,
+    },
+    systemProgram: {
+      index: 8,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+    sysvarInstructions: {
+      index: 9,
+      isWritable: false,
+      value: input.sysvarInstructions ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: RevealItemInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = findCandyMachineAuthorityPda(
+      context,
+      { candyMachine: expectPublicKey(resolvedAccounts.candyMachine.value) }
+    );
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.nftMetadata.value) {
+    resolvedAccounts.nftMetadata.value = findMetadataPda(context, {
+      mint: expectPublicKey(resolvedAccounts.nftMint.value),
+    });
+  }
+  if (!resolvedAccounts.nftMasterEdition.value) {
+    resolvedAccounts.nftMasterEdition.value = findMasterEditionPda(context, {
+      mint: expectPublicKey(resolvedAccounts.nftMint.value),
+    });
+  }
+  if (!resolvedAccounts.tokenMetadataProgram.value) {
+    resolvedAccounts.tokenMetadataProgram.value = context.programs.getPublicKey(
+      'mplTokenMetadata',
+      'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s'
+    );
+    resolvedAccounts.tokenMetadataProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.sysvarInstructions.value) {
+    resolvedAccounts.sysvarInstructions.value = publicKey(
+      'Sysvar1nstructions1111111111111111111111111'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRevealItemInstructionDataSerializer().serialize(
+    resolvedArgs as RevealItemInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,128 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetCommitRevealInstructionAccounts = {
+  /** Candy Machine account. */
+  candyMachine: PublicKey | Pda;
+  /** Candy Machine authority. */
+  authority?: Signer;
+};
+
+// Data.
+export type SetCommitRevealInstructionData = {
+  discriminator: Array<number>;
+  enabled: boolean;
+};
+
+export type SetCommitRevealInstructionDataArgs = { enabled: boolean };
+
+export function getSetCommitRevealInstructionDataSerializer(): Serializer<
+  SetCommitRevealInstructionDataArgs,
+  SetCommitRevealInstructionData
+> {
+  return mapSerializer<
+    SetCommitRevealInstructionDataArgs,
+    any,
+    SetCommitRevealInstructionData
+  >(
+    struct<SetCommitRevealInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['enabled', bool()],
+      ],
+      { description: 'SetCommitRevealInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [53, 8, 229, 223, 15, 231, 241, 132],
+    })
+  ) as Serializer<
+    SetCommitRevealInstructionDataArgs,
+    SetCommitRevealInstructionData
+  >;
+}
+
+// Args.
+export type SetCommitRevealInstructionArgs = SetCommitRevealInstructionDataArgs;
+
+// Instruction.
+export function setCommitReveal(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: SetCommitRevealInstructionAccounts & SetCommitRevealInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.candyMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+  };
+
+  // Arguments.
+  const resolvedArgs: SetCommitRevealInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetCommitRevealInstructionDataSerializer().serialize(
+    resolvedArgs as SetCommitRevealInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
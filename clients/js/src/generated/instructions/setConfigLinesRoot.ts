@@ -0,0 +1,150 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetConfigLinesRootInstructionAccounts = {
+  /** Candy Machine account. */
+  candyMachine: PublicKey | Pda;
+  /** Candy Machine authority. */
+  authority?: Signer;
+  /** Payer of the transaction. */
+  payer?: Signer;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type SetConfigLinesRootInstructionData = {
+  discriminator: Array<number>;
+  root: Uint8Array;
+};
+
+export type SetConfigLinesRootInstructionDataArgs = { root: Uint8Array };
+
+export function getSetConfigLinesRootInstructionDataSerializer(): Serializer<
+  SetConfigLinesRootInstructionDataArgs,
+  SetConfigLinesRootInstructionData
+> {
+  return mapSerializer<
+    SetConfigLinesRootInstructionDataArgs,
+    any,
+    SetConfigLinesRootInstructionData
+  >(
+    struct<SetConfigLinesRootInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['root', bytes({ size: 32 })],
+      ],
+      { description: 'SetConfigLinesRootInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [212, 73, 41, 216, 159, 187, 147, 11],
+    })
+  ) as Serializer<
+    SetConfigLinesRootInstructionDataArgs,
+    SetConfigLinesRootInstructionData
+  >;
+}
+
+// Args.
+export type SetConfigLinesRootInstructionArgs =
+  SetConfigLinesRootInstructionDataArgs;
+
+// Instruction.
+export function setConfigLinesRoot(
+  context: Pick<Context, 'identity' | 'payer' | 'programs'>,
+  input: SetConfigLinesRootInstructionAccounts &
+    SetConfigLinesRootInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.candyMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+    payer: { index: 2, isWritable: true, value: input.payer ?? null },
+    systemProgram: {
+      index: 3,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: SetConfigLinesRootInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetConfigLinesRootInstructionDataSerializer().serialize(
+    resolvedArgs as SetConfigLinesRootInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,149 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetFungibleAmountInstructionAccounts = {
+  /** Candy Machine account. */
+  candyMachine: PublicKey | Pda;
+  /** Candy Machine authority. */
+  authority?: Signer;
+  /** Payer of the transaction. */
+  payer?: Signer;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type SetFungibleAmountInstructionData = {
+  discriminator: Array<number>;
+  amount: bigint;
+};
+
+export type SetFungibleAmountInstructionDataArgs = { amount: number | bigint };
+
+export function getSetFungibleAmountInstructionDataSerializer(): Serializer<
+  SetFungibleAmountInstructionDataArgs,
+  SetFungibleAmountInstructionData
+> {
+  return mapSerializer<
+    SetFungibleAmountInstructionDataArgs,
+    any,
+    SetFungibleAmountInstructionData
+  >(
+    struct<SetFungibleAmountInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['amount', u64()],
+      ],
+      { description: 'SetFungibleAmountInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [79, 150, 48, 111, 78, 13, 63, 101],
+    })
+  ) as Serializer<
+    SetFungibleAmountInstructionDataArgs,
+    SetFungibleAmountInstructionData
+  >;
+}
+
+// Args.
+export type SetFungibleAmountInstructionArgs =
+  SetFungibleAmountInstructionDataArgs;
+
+// Instruction.
+export function setFungibleAmount(
+  context: Pick<Context, 'identity' | 'payer' | 'programs'>,
+  input: SetFungibleAmountInstructionAccounts & SetFungibleAmountInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.candyMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+    payer: { index: 2, isWritable: true, value: input.payer ?? null },
+    systemProgram: {
+      index: 3,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: SetFungibleAmountInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetFungibleAmountInstructionDataSerializer().serialize(
+    resolvedArgs as SetFungibleAmountInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
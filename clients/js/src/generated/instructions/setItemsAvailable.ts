@@ -0,0 +1,151 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetItemsAvailableInstructionAccounts = {
+  /** Candy Machine account. */
+  candyMachine: PublicKey | Pda;
+  /** Candy Machine authority. */
+  authority?: Signer;
+  /** Payer of the transaction. */
+  payer?: Signer;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type SetItemsAvailableInstructionData = {
+  discriminator: Array<number>;
+  itemsAvailable: bigint;
+};
+
+export type SetItemsAvailableInstructionDataArgs = {
+  itemsAvailable: number | bigint;
+};
+
+export function getSetItemsAvailableInstructionDataSerializer(): Serializer<
+  SetItemsAvailableInstructionDataArgs,
+  SetItemsAvailableInstructionData
+> {
+  return mapSerializer<
+    SetItemsAvailableInstructionDataArgs,
+    any,
+    SetItemsAvailableInstructionData
+  >(
+    struct<SetItemsAvailableInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['itemsAvailable', u64()],
+      ],
+      { description: 'SetItemsAvailableInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [250, 102, 198, 197, 232, 117, 252, 139],
+    })
+  ) as Serializer<
+    SetItemsAvailableInstructionDataArgs,
+    SetItemsAvailableInstructionData
+  >;
+}
+
+// Args.
+export type SetItemsAvailableInstructionArgs =
+  SetItemsAvailableInstructionDataArgs;
+
+// Instruction.
+export function setItemsAvailable(
+  context: Pick<Context, 'identity' | 'payer' | 'programs'>,
+  input: SetItemsAvailableInstructionAccounts & SetItemsAvailableInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.candyMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+    payer: { index: 2, isWritable: true, value: input.payer ?? null },
+    systemProgram: {
+      index: 3,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: SetItemsAvailableInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetItemsAvailableInstructionDataSerializer().serialize(
+    resolvedArgs as SetItemsAvailableInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
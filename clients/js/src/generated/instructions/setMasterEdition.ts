@@ -0,0 +1,154 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetMasterEditionInstructionAccounts = {
+  /** Candy Machine account. */
+  candyMachine: PublicKey | Pda;
+  /** Candy Machine authority. */
+  authority?: Signer;
+  /** Payer of the transaction. */
+  payer?: Signer;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type SetMasterEditionInstructionData = {
+  discriminator: Array<number>;
+  masterEditionMint: Option<PublicKey>;
+};
+
+export type SetMasterEditionInstructionDataArgs = {
+  masterEditionMint: OptionOrNullable<PublicKey>;
+};
+
+export function getSetMasterEditionInstructionDataSerializer(): Serializer<
+  SetMasterEditionInstructionDataArgs,
+  SetMasterEditionInstructionData
+> {
+  return mapSerializer<
+    SetMasterEditionInstructionDataArgs,
+    any,
+    SetMasterEditionInstructionData
+  >(
+    struct<SetMasterEditionInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['masterEditionMint', option(publicKeySerializer())],
+      ],
+      { description: 'SetMasterEditionInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [167, 81, 246, 143, 219, 9, 19, 91],
+    })
+  ) as Serializer<
+    SetMasterEditionInstructionDataArgs,
+    SetMasterEditionInstructionData
+  >;
+}
+
+// Args.
+export type SetMasterEditionInstructionArgs =
+  SetMasterEditionInstructionDataArgs;
+
+// Instruction.
+export function setMasterEdition(
+  context: Pick<Context, 'identity' | 'payer' | 'programs'>,
+  input: SetMasterEditionInstructionAccounts & SetMasterEditionInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.candyMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+    payer: { index: 2, isWritable: true, value: input.payer ?? null },
+    systemProgram: {
+      index: 3,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: SetMasterEditionInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetMasterEditionInstructionDataSerializer().serialize(
+    resolvedArgs as SetMasterEditionInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,154 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  option,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  MintDelegate,
+  MintDelegateArgs,
+  getMintDelegateSerializer,
+} from '../types';
+
+// Accounts.
+export type SetMintDelegateInstructionAccounts = {
+  /** Candy Machine account. */
+  candyMachine: PublicKey | Pda;
+  /** Candy Machine authority. */
+  authority?: Signer;
+  /** Payer of the transaction. */
+  payer?: Signer;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type SetMintDelegateInstructionData = {
+  discriminator: Array<number>;
+  mintDelegate: Option<MintDelegate>;
+};
+
+export type SetMintDelegateInstructionDataArgs = {
+  mintDelegate: OptionOrNullable<MintDelegateArgs>;
+};
+
+export function getSetMintDelegateInstructionDataSerializer(): Serializer<
+  SetMintDelegateInstructionDataArgs,
+  SetMintDelegateInstructionData
+> {
+  return mapSerializer<
+    SetMintDelegateInstructionDataArgs,
+    any,
+    SetMintDelegateInstructionData
+  >(
+    struct<SetMintDelegateInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['mintDelegate', option(getMintDelegateSerializer())],
+      ],
+      { description: 'SetMintDelegateInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: [58, 148, 25, 68, 39, 73, 103, 14] })
+  ) as Serializer<
+    SetMintDelegateInstructionDataArgs,
+    SetMintDelegateInstructionData
+  >;
+}
+
+// Args.
+export type SetMintDelegateInstructionArgs = SetMintDelegateInstructionDataArgs;
+
+// Instruction.
+export function setMintDelegate(
+  context: Pick<Context, 'identity' | 'payer' | 'programs'>,
+  input: SetMintDelegateInstructionAccounts & SetMintDelegateInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.candyMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+    payer: { index: 2, isWritable: true, value: input.payer ?? null },
+    systemProgram: {
+      index: 3,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: SetMintDelegateInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetMintDelegateInstructionDataSerializer().serialize(
+    resolvedArgs as SetMintDelegateInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,125 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetMintReceiptInstructionAccounts = {
+  /** Candy Machine account. */
+  candyMachine: PublicKey | Pda;
+  /** Candy Machine authority. */
+  authority?: Signer;
+};
+
+// Data.
+export type SetMintReceiptInstructionData = {
+  discriminator: Array<number>;
+  enabled: boolean;
+};
+
+export type SetMintReceiptInstructionDataArgs = { enabled: boolean };
+
+export function getSetMintReceiptInstructionDataSerializer(): Serializer<
+  SetMintReceiptInstructionDataArgs,
+  SetMintReceiptInstructionData
+> {
+  return mapSerializer<
+    SetMintReceiptInstructionDataArgs,
+    any,
+    SetMintReceiptInstructionData
+  >(
+    struct<SetMintReceiptInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['enabled', bool()],
+      ],
+      { description: 'SetMintReceiptInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: [143, 143, 82, 63, 191, 87, 3, 79] })
+  ) as Serializer<
+    SetMintReceiptInstructionDataArgs,
+    SetMintReceiptInstructionData
+  >;
+}
+
+// Args.
+export type SetMintReceiptInstructionArgs = SetMintReceiptInstructionDataArgs;
+
+// Instruction.
+export function setMintReceipt(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: SetMintReceiptInstructionAccounts & SetMintReceiptInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.candyMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+  };
+
+  // Arguments.
+  const resolvedArgs: SetMintReceiptInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetMintReceiptInstructionDataSerializer().serialize(
+    resolvedArgs as SetMintReceiptInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
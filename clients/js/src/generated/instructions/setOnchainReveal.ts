@@ -0,0 +1,129 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetOnchainRevealInstructionAccounts = {
+  /** Candy Machine account. */
+  candyMachine: PublicKey | Pda;
+  /** Candy Machine authority. */
+  authority?: Signer;
+};
+
+// Data.
+export type SetOnchainRevealInstructionData = {
+  discriminator: Array<number>;
+  enabled: boolean;
+};
+
+export type SetOnchainRevealInstructionDataArgs = { enabled: boolean };
+
+export function getSetOnchainRevealInstructionDataSerializer(): Serializer<
+  SetOnchainRevealInstructionDataArgs,
+  SetOnchainRevealInstructionData
+> {
+  return mapSerializer<
+    SetOnchainRevealInstructionDataArgs,
+    any,
+    SetOnchainRevealInstructionData
+  >(
+    struct<SetOnchainRevealInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['enabled', bool()],
+      ],
+      { description: 'SetOnchainRevealInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [231, 23, 227, 148, 172, 118, 172, 64],
+    })
+  ) as Serializer<
+    SetOnchainRevealInstructionDataArgs,
+    SetOnchainRevealInstructionData
+  >;
+}
+
+// Args.
+export type SetOnchainRevealInstructionArgs =
+  SetOnchainRevealInstructionDataArgs;
+
+// Instruction.
+export function setOnchainReveal(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: SetOnchainRevealInstructionAccounts & SetOnchainRevealInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.candyMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+  };
+
+  // Arguments.
+  const resolvedArgs: SetOnchainRevealInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetOnchainRevealInstructionDataSerializer().serialize(
+    resolvedArgs as SetOnchainRevealInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,125 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetPausedInstructionAccounts = {
+  /** Candy Machine account. */
+  candyMachine: PublicKey | Pda;
+  /** Candy Machine authority. */
+  authority?: Signer;
+};
+
+// Data.
+export type SetPausedInstructionData = {
+  discriminator: Array<number>;
+  paused: boolean;
+};
+
+export type SetPausedInstructionDataArgs = { paused: boolean };
+
+export function getSetPausedInstructionDataSerializer(): Serializer<
+  SetPausedInstructionDataArgs,
+  SetPausedInstructionData
+> {
+  return mapSerializer<
+    SetPausedInstructionDataArgs,
+    any,
+    SetPausedInstructionData
+  >(
+    struct<SetPausedInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['paused', bool()],
+      ],
+      { description: 'SetPausedInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [91, 60, 125, 192, 176, 225, 166, 218],
+    })
+  ) as Serializer<SetPausedInstructionDataArgs, SetPausedInstructionData>;
+}
+
+// Args.
+export type SetPausedInstructionArgs = SetPausedInstructionDataArgs;
+
+// Instruction.
+export function setPaused(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: SetPausedInstructionAccounts & SetPausedInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.candyMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+  };
+
+  // Arguments.
+  const resolvedArgs: SetPausedInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetPausedInstructionDataSerializer().serialize(
+    resolvedArgs as SetPausedInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
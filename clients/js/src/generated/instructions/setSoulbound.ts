@@ -0,0 +1,125 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetSoulboundInstructionAccounts = {
+  /** Candy Machine account. */
+  candyMachine: PublicKey | Pda;
+  /** Candy Machine authority. */
+  authority?: Signer;
+};
+
+// Data.
+export type SetSoulboundInstructionData = {
+  discriminator: Array<number>;
+  enabled: boolean;
+};
+
+export type SetSoulboundInstructionDataArgs = { enabled: boolean };
+
+export function getSetSoulboundInstructionDataSerializer(): Serializer<
+  SetSoulboundInstructionDataArgs,
+  SetSoulboundInstructionData
+> {
+  return mapSerializer<
+    SetSoulboundInstructionDataArgs,
+    any,
+    SetSoulboundInstructionData
+  >(
+    struct<SetSoulboundInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['enabled', bool()],
+      ],
+      { description: 'SetSoulboundInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [115, 150, 244, 172, 44, 69, 5, 217],
+    })
+  ) as Serializer<SetSoulboundInstructionDataArgs, SetSoulboundInstructionData>;
+}
+
+// Args.
+export type SetSoulboundInstructionArgs = SetSoulboundInstructionDataArgs;
+
+// Instruction.
+export function setSoulbound(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: SetSoulboundInstructionAccounts & SetSoulboundInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.candyMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+  };
+
+  // Arguments.
+  const resolvedArgs: SetSoulboundInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetSoulboundInstructionDataSerializer().serialize(
+    resolvedArgs as SetSoulboundInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -8,11 +8,8 @@
 
 import {
   MetadataDelegateRole,
-  TokenStandard,
-  TokenStandardArgs,
   findMetadataDelegateRecordPda,
   findMetadataPda,
-  getTokenStandardSerializer,
 } from '@metaplex-foundation/mpl-token-metadata';
 import {
   Context,
@@ -30,7 +27,12 @@ import {
   struct,
   u8,
 } from '@metaplex-foundation/umi/serializers';
-import { findCandyMachineAuthorityPda } from '../../hooked';
+import {
+  CandyMachineTokenStandard,
+  CandyMachineTokenStandardArgs,
+  findCandyMachineAuthorityPda,
+  getCandyMachineTokenStandardSerializer,
+} from '../../hooked';
 import {
   ResolvedAccount,
   ResolvedAccountsWithIndices,
@@ -115,11 +117,11 @@ export type SetTokenStandardInstructionAccounts = {
 // Data.
 export type SetTokenStandardInstructionData = {
   discriminator: Array<number>;
-  tokenStandard: TokenStandard;
+  tokenStandard: CandyMachineTokenStandard;
 };
 
 export type SetTokenStandardInstructionDataArgs = {
-  tokenStandard: TokenStandardArgs;
+  tokenStandard: CandyMachineTokenStandardArgs;
 };
 
 export function getSetTokenStandardInstructionDataSerializer(): Serializer<
@@ -134,7 +136,7 @@ export function getSetTokenStandardInstructionDataSerializer(): Serializer<
     struct<SetTokenStandardInstructionData>(
       [
         ['discriminator', array(u8(), { size: 8 })],
-        ['tokenStandard', getTokenStandardSerializer()],
+        ['tokenStandard', getCandyMachineTokenStandardSerializer()],
       ],
       { description: 'SetTokenStandardInstructionData' }
     ),
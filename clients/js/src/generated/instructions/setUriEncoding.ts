@@ -0,0 +1,132 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  UriEncoding,
+  UriEncodingArgs,
+  getUriEncodingSerializer,
+} from '../types';
+
+// Accounts.
+export type SetUriEncodingInstructionAccounts = {
+  /** Candy Machine account. */
+  candyMachine: PublicKey | Pda;
+  /** Candy Machine authority. */
+  authority?: Signer;
+};
+
+// Data.
+export type SetUriEncodingInstructionData = {
+  discriminator: Array<number>;
+  encoding: UriEncoding;
+};
+
+export type SetUriEncodingInstructionDataArgs = { encoding: UriEncodingArgs };
+
+export function getSetUriEncodingInstructionDataSerializer(): Serializer<
+  SetUriEncodingInstructionDataArgs,
+  SetUriEncodingInstructionData
+> {
+  return mapSerializer<
+    SetUriEncodingInstructionDataArgs,
+    any,
+    SetUriEncodingInstructionData
+  >(
+    struct<SetUriEncodingInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['encoding', getUriEncodingSerializer()],
+      ],
+      { description: 'SetUriEncodingInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [188, 59, 111, 52, 237, 19, 128, 42],
+    })
+  ) as Serializer<
+    SetUriEncodingInstructionDataArgs,
+    SetUriEncodingInstructionData
+  >;
+}
+
+// Args.
+export type SetUriEncodingInstructionArgs = SetUriEncodingInstructionDataArgs;
+
+// Instruction.
+export function setUriEncoding(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: SetUriEncodingInstructionAccounts & SetUriEncodingInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCandyMachineCore',
+    'CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.candyMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+  };
+
+  // Arguments.
+  const resolvedArgs: SetUriEncodingInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetUriEncodingInstructionDataSerializer().serialize(
+    resolvedArgs as SetUriEncodingInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
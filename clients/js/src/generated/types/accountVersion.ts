@@ -12,6 +12,7 @@ import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
 export enum AccountVersion {
   V1,
   V2,
+  V3,
 }
 
 export type AccountVersionArgs = AccountVersion;
@@ -6,11 +6,6 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
-import {
-  TokenStandard,
-  TokenStandardArgs,
-  getTokenStandardSerializer,
-} from '@metaplex-foundation/mpl-token-metadata';
 import { PublicKey } from '@metaplex-foundation/umi';
 import {
   Serializer,
@@ -21,6 +16,11 @@ import {
   u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
+import {
+  CandyMachineTokenStandard,
+  CandyMachineTokenStandardArgs,
+  getCandyMachineTokenStandardSerializer,
+} from '../../hooked';
 import {
   AccountVersion,
   AccountVersionArgs,
@@ -36,8 +36,11 @@ export type CandyMachineAccountData = {
   /** Version of the account. */
   version: AccountVersion;
   /** Token standard to mint NFTs. */
-  tokenStandard: TokenStandard;
-  /** Features flags. */
+  tokenStandard: CandyMachineTokenStandard;
+  /**
+   * Features flags, state flags, the number of pending mint requests and the sequence
+   * of the next mint request to be revealed.
+   */
   features: Array<number>;
   /** Authority address. */
   authority: PublicKey;
@@ -55,8 +58,11 @@ export type CandyMachineAccountDataArgs = {
   /** Version of the account. */
   version: AccountVersionArgs;
   /** Token standard to mint NFTs. */
-  tokenStandard: TokenStandardArgs;
-  /** Features flags. */
+  tokenStandard: CandyMachineTokenStandardArgs;
+  /**
+   * Features flags, state flags, the number of pending mint requests and the sequence
+   * of the next mint request to be revealed.
+   */
   features: Array<number>;
   /** Authority address. */
   authority: PublicKey;
@@ -83,7 +89,7 @@ export function getCandyMachineAccountDataSerializer(): Serializer<
       [
         ['discriminator', array(u8(), { size: 8 })],
         ['version', getAccountVersionSerializer()],
-        ['tokenStandard', getTokenStandardSerializer()],
+        ['tokenStandard', getCandyMachineTokenStandardSerializer()],
         ['features', array(u8(), { size: 6 })],
         ['authority', publicKeySerializer()],
         ['mintAuthority', publicKeySerializer()],
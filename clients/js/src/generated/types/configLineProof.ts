@@ -0,0 +1,46 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  bytes,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Config line supplied at mint time, together with the merkle proof of the line.
+ *
+ * The leaf of the merkle tree is the hash of the (index, name, uri) of the line.
+ */
+
+export type ConfigLineProof = {
+  /** Name of the asset. */
+  name: string;
+  /** URI to JSON metadata. */
+  uri: string;
+  /** Merkle proof of the config line. */
+  proof: Array<Uint8Array>;
+};
+
+export type ConfigLineProofArgs = ConfigLineProof;
+
+export function getConfigLineProofSerializer(): Serializer<
+  ConfigLineProofArgs,
+  ConfigLineProof
+> {
+  return struct<ConfigLineProof>(
+    [
+      ['name', string()],
+      ['uri', string()],
+      ['proof', array(bytes({ size: 32 }))],
+    ],
+    { description: 'ConfigLineProof' }
+  ) as Serializer<ConfigLineProofArgs, ConfigLineProof>;
+}
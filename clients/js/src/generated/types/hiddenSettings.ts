@@ -19,7 +19,10 @@ export type HiddenSettings = {
   name: string;
   /** Shared URI */
   uri: string;
-  /** Hash of the hidden settings file */
+  /**
+   * Hash of the hidden settings file (merkle root of the revealed items when using
+   * the on-chain reveal)
+   */
   hash: Uint8Array;
 };
 
@@ -13,6 +13,7 @@ export * from './allowList';
 export * from './botTax';
 export * from './candyMachineData';
 export * from './configLine';
+export * from './configLineProof';
 export * from './configLineSettings';
 export * from './creator';
 export * from './endDate';
@@ -22,11 +23,16 @@ export * from './freezeTokenPayment';
 export * from './gatekeeper';
 export * from './guardType';
 export * from './hiddenSettings';
+export * from './itemRange';
+export * from './mintArgs';
+export * from './mintDelegate';
+export * from './mintDelegateRole';
 export * from './mintLimit';
 export * from './nftBurn';
 export * from './nftGate';
 export * from './nftPayment';
 export * from './programGate';
+export * from './rarityTier';
 export * from './redeemedAmount';
 export * from './solPayment';
 export * from './startDate';
@@ -35,3 +41,4 @@ export * from './token2022Payment';
 export * from './tokenBurn';
 export * from './tokenGate';
 export * from './tokenPayment';
+export * from './uriEncoding';
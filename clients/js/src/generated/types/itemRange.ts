@@ -0,0 +1,36 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct, u32 } from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Guard that restricts the mint to items requested within a range of indices.
+ *
+ * The mint must request a specific item (`index` argument of the `mint_v3`
+ * instruction). Combined with groups, this guard allows setting a different price or
+ * access rules for each range of items.
+ */
+
+export type ItemRange = {
+  /** First index of the range (inclusive). */
+  start: number;
+  /** Last index of the range (inclusive). */
+  end: number;
+};
+
+export type ItemRangeArgs = ItemRange;
+
+export function getItemRangeSerializer(): Serializer<ItemRangeArgs, ItemRange> {
+  return struct<ItemRange>(
+    [
+      ['start', u32()],
+      ['end', u32()],
+    ],
+    { description: 'ItemRange' }
+  ) as Serializer<ItemRangeArgs, ItemRange>;
+}
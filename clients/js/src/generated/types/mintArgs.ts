@@ -0,0 +1,57 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  option,
+  string,
+  struct,
+  u32,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ConfigLineProof,
+  ConfigLineProofArgs,
+  getConfigLineProofSerializer,
+} from '.';
+
+/** Arguments of a mint. */
+export type MintArgs = {
+  /** Index of the item to mint (requires the pick item mode). */
+  index: Option<number>;
+  /**
+   * Config line of the item to mint with its merkle proof (required when config lines
+   * are verified using a merkle root).
+   */
+  configLine: Option<ConfigLineProof>;
+  /** Group label recorded on the mint receipt (if any). */
+  group: Option<string>;
+};
+
+export type MintArgsArgs = {
+  /** Index of the item to mint (requires the pick item mode). */
+  index: OptionOrNullable<number>;
+  /**
+   * Config line of the item to mint with its merkle proof (required when config lines
+   * are verified using a merkle root).
+   */
+  configLine: OptionOrNullable<ConfigLineProofArgs>;
+  /** Group label recorded on the mint receipt (if any). */
+  group: OptionOrNullable<string>;
+};
+
+export function getMintArgsSerializer(): Serializer<MintArgsArgs, MintArgs> {
+  return struct<MintArgs>(
+    [
+      ['index', option(u32())],
+      ['configLine', option(getConfigLineProofSerializer())],
+      ['group', option(string())],
+    ],
+    { description: 'MintArgs' }
+  ) as Serializer<MintArgsArgs, MintArgs>;
+}
@@ -0,0 +1,64 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  MintDelegateRole,
+  MintDelegateRoleArgs,
+  getMintDelegateRoleSerializer,
+} from '.';
+
+/**
+ * Token delegate approved on the token record of minted Programmable NFTs.
+ *
+ * The role is stored as its value plus one, so a zero byte indicates that there is
+ * no delegate.
+ */
+
+export type MintDelegate = {
+  /** Role of the delegate. */
+  role: MintDelegateRole;
+  /** Address of the delegate (e.g., a program PDA). */
+  delegate: PublicKey;
+  /**
+   * Address the token can be transferred to by a `LockedTransfer` delegate (ignored
+   * by the other roles).
+   */
+  lockedAddress: PublicKey;
+};
+
+export type MintDelegateArgs = {
+  /** Role of the delegate. */
+  role: MintDelegateRoleArgs;
+  /** Address of the delegate (e.g., a program PDA). */
+  delegate: PublicKey;
+  /**
+   * Address the token can be transferred to by a `LockedTransfer` delegate (ignored
+   * by the other roles).
+   */
+  lockedAddress: PublicKey;
+};
+
+export function getMintDelegateSerializer(): Serializer<
+  MintDelegateArgs,
+  MintDelegate
+> {
+  return struct<MintDelegate>(
+    [
+      ['role', getMintDelegateRoleSerializer()],
+      ['delegate', publicKeySerializer()],
+      ['lockedAddress', publicKeySerializer()],
+    ],
+    { description: 'MintDelegate' }
+  ) as Serializer<MintDelegateArgs, MintDelegate>;
+}
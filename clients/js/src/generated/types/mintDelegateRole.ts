@@ -0,0 +1,27 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+/** Role of the token delegate approved when a Programmable NFT is minted. */
+export enum MintDelegateRole {
+  Staking,
+  LockedTransfer,
+  Utility,
+}
+
+export type MintDelegateRoleArgs = MintDelegateRole;
+
+export function getMintDelegateRoleSerializer(): Serializer<
+  MintDelegateRoleArgs,
+  MintDelegateRole
+> {
+  return scalarEnum<MintDelegateRole>(MintDelegateRole, {
+    description: 'MintDelegateRole',
+  }) as Serializer<MintDelegateRoleArgs, MintDelegateRole>;
+}
@@ -0,0 +1,38 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct, u32 } from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Rarity tier of a contiguous range of config lines.
+ *
+ * Tiers are sequential: the first tier includes the first `size` config lines, the
+ * second tier the following `size` config lines and so on.
+ */
+
+export type RarityTier = {
+  /** Draw weight of each config line of the tier. */
+  weight: number;
+  /** Number of config lines of the tier. */
+  size: number;
+};
+
+export type RarityTierArgs = RarityTier;
+
+export function getRarityTierSerializer(): Serializer<
+  RarityTierArgs,
+  RarityTier
+> {
+  return struct<RarityTier>(
+    [
+      ['weight', u32()],
+      ['size', u32()],
+    ],
+    { description: 'RarityTier' }
+  ) as Serializer<RarityTierArgs, RarityTier>;
+}
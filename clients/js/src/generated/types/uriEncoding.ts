@@ -0,0 +1,35 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Encoding of the config line URIs stored on the account.
+ *
+ * Except for `Utf8`, the URI of a config line is stored as raw bytes (the URI length
+ * being the number of bytes) and is encoded when the config line is retrieved. The
+ * URI of a config line is provided in its encoded form when adding config lines.
+ */
+
+export enum UriEncoding {
+  Utf8,
+  Base64Url,
+  Base58,
+  Base32,
+}
+
+export type UriEncodingArgs = UriEncoding;
+
+export function getUriEncodingSerializer(): Serializer<
+  UriEncodingArgs,
+  UriEncoding
+> {
+  return scalarEnum<UriEncoding>(UriEncoding, {
+    description: 'UriEncoding',
+  }) as Serializer<UriEncodingArgs, UriEncoding>;
+}
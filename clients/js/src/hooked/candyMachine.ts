@@ -1,27 +1,36 @@
-import {
-  isProgrammable,
-  TokenStandard,
-} from '@metaplex-foundation/mpl-token-metadata';
+import { TokenStandard } from '@metaplex-foundation/mpl-token-metadata';
 import {
   isNone,
   isOption,
   OptionOrNullable,
   wrapNullable,
 } from '@metaplex-foundation/umi';
-import { CANDY_MACHINE_HIDDEN_SECTION } from '../constants';
+import {
+  CANDY_MACHINE_HEADER_V3,
+  CANDY_MACHINE_HIDDEN_SECTION,
+  MAX_SYMBOL_LENGTH,
+} from '../constants';
+import {
+  CandyMachineDataArgs,
+  getCandyMachineDataSerializer,
+} from '../generated/types/candyMachineData';
 import { ConfigLineSettingsArgs } from '../generated/types/configLineSettings';
+import {
+  CandyMachineTokenStandard,
+  isProgrammableTokenStandard,
+} from './candyMachineTokenStandard';
 
 export function getCandyMachineSize(
   itemsAvailable: number | bigint,
   configLineSettings: OptionOrNullable<
     Pick<ConfigLineSettingsArgs, 'nameLength' | 'uriLength'>
   >,
-  tokenStandard = TokenStandard.NonFungible
+  tokenStandard: CandyMachineTokenStandard = TokenStandard.NonFungible
 ): number {
   configLineSettings = isOption(configLineSettings)
     ? configLineSettings
     : wrapNullable(configLineSettings);
-  const base = isProgrammable(tokenStandard)
+  const base = isProgrammableTokenStandard(tokenStandard)
     ? CANDY_MACHINE_HIDDEN_SECTION + 33
     : CANDY_MACHINE_HIDDEN_SECTION;
 
@@ -45,3 +54,66 @@ export function getCandyMachineSize(
       (4 + items * 4)
   );
 }
+
+/**
+ * Returns the position of the hidden section of a V3 Candy Machine,
+ * which starts right after its data. The symbol is always stored
+ * with its maximum length.
+ */
+export function getCandyMachineHiddenSectionV3(
+  data: CandyMachineDataArgs
+): number {
+  const serializedData = getCandyMachineDataSerializer().serialize({
+    ...data,
+    symbol: '',
+  });
+  return CANDY_MACHINE_HEADER_V3 + serializedData.length + MAX_SYMBOL_LENGTH;
+}
+
+/**
+ * Returns the size of each value of the mint indices array of a V3
+ * Candy Machine, which uses `u16` values when the number of items
+ * fits in a `u16`.
+ */
+export function getCandyMachineMintIndexSizeV3(
+  itemsAvailable: number | bigint
+): number {
+  return Number(itemsAvailable) <= 0xffff ? 2 : 4;
+}
+
+export function getCandyMachineSizeV3(
+  data: CandyMachineDataArgs,
+  tokenStandard: CandyMachineTokenStandard = TokenStandard.NonFungible
+): number {
+  const configLineSettings = isOption(data.configLineSettings)
+    ? data.configLineSettings
+    : wrapNullable(data.configLineSettings);
+  const hiddenSettings = isOption(data.hiddenSettings)
+    ? data.hiddenSettings
+    : wrapNullable(data.hiddenSettings);
+  const hiddenSection = getCandyMachineHiddenSectionV3(data);
+  const base = isProgrammableTokenStandard(tokenStandard)
+    ? hiddenSection + 33
+    : hiddenSection;
+
+  if (!isNone(hiddenSettings)) {
+    return base;
+  }
+
+  const items = Number(data.itemsAvailable);
+  const configLineSize = isNone(configLineSettings)
+    ? 0
+    : configLineSettings.value.nameLength + configLineSettings.value.uriLength;
+
+  return (
+    base +
+    // Number of currently items inserted.
+    4 +
+    // Config line data.
+    items * configLineSize +
+    // Bit mask to keep track of which ConfigLines have been added.
+    (Math.floor(items / 8) + 1) +
+    // Mint indices.
+    items * getCandyMachineMintIndexSizeV3(items)
+  );
+}
@@ -1,27 +1,68 @@
-import { isProgrammable } from '@metaplex-foundation/mpl-token-metadata';
-import { isNone, none, Option, PublicKey } from '@metaplex-foundation/umi';
+import {
+  defaultPublicKey,
+  isNone,
+  none,
+  Option,
+  PublicKey,
+  some,
+} from '@metaplex-foundation/umi';
 import {
   array,
+  base58,
+  base64,
   bitArray,
+  bytes as bytesSerializer,
   mapSerializer,
   option,
   publicKey,
+  removeNullCharacters,
   Serializer,
   string,
   struct,
+  u16,
   u32,
+  u64,
+  u8,
+  utf8,
 } from '@metaplex-foundation/umi/serializers';
 import { CANDY_MACHINE_HIDDEN_SECTION } from '../constants';
+import { AccountVersion } from '../generated/types/accountVersion';
 import {
   CandyMachineAccountData as BaseCandyMachineAccountData,
   CandyMachineAccountDataArgs as BaseCandyMachineAccountDataArgs,
   getCandyMachineAccountDataSerializer as baseGetCandyMachineAccountDataSerializer,
 } from '../generated/types/candyMachineAccountData';
+import { MintDelegate } from '../generated/types/mintDelegate';
+import { MintDelegateRole } from '../generated/types/mintDelegateRole';
+import { RarityTier } from '../generated/types/rarityTier';
+import { UriEncoding } from '../generated/types/uriEncoding';
+import { getCandyMachineHiddenSectionV3 } from './candyMachine';
+import { isProgrammableTokenStandard } from './candyMachineTokenStandard';
 
 export type CandyMachineAccountData = BaseCandyMachineAccountData & {
   itemsLoaded: number;
   items: CandyMachineItem[];
   ruleSet: Option<PublicKey>;
+  /** The merkle root (or commitment of finalized) config lines, if any. */
+  configLinesRoot: Option<Uint8Array>;
+  rarityTiers: CandyMachineRarityTier[];
+  /** The page accounts storing the config lines, in page order. */
+  configLinePages: PublicKey[];
+  /** The delegate approved on the token record of minted items, if any. */
+  mintDelegate: Option<MintDelegate>;
+  /** The number of config lines removed from the items available. */
+  itemsRemoved: bigint;
+  /** The mint of the master edition printed by the candy machine, if any. */
+  masterEditionMint: Option<PublicKey>;
+  /** The number of tokens minted per item of fungible token standards. */
+  fungibleAmount: bigint;
+  /**
+   * The group update authority of a Token-2022 collection before it was
+   * transferred to the candy machine, if any.
+   */
+  groupAuthority: Option<PublicKey>;
+  /** Whether minting is paused. */
+  paused: boolean;
 };
 
 export type CandyMachineAccountDataArgs = BaseCandyMachineAccountDataArgs;
@@ -38,7 +79,7 @@ export type CandyMachineItem = {
   /** The index of the config line. */
   readonly index: number;
 
-  /** Whether the item has been minted or not. */
+  /** Whether the item has been minted (or removed) or not. */
   readonly minted: boolean;
 
   /** The name of the NFT to be. */
@@ -48,13 +89,37 @@ export type CandyMachineItem = {
   readonly uri: string;
 };
 
+/**
+ * Represent a rarity tier of a Candy Machine, including the
+ * number of its config lines that are still available to mint.
+ */
+export type CandyMachineRarityTier = RarityTier & {
+  /** The number of config lines of the tier that are still available. */
+  readonly remaining: number;
+};
+
 type CandyMachineHiddenSection = {
   itemsLoaded: number;
-  rawConfigLines: { name: string; uri: string }[];
+  rawConfigLines: { name: string; uri: Uint8Array }[];
   itemsLoadedMap: boolean[];
   itemsLeftToMint: number[];
 };
 
+const MERKLE_CONFIG_LINES_FEATURE = 0b0000_0100;
+const PAUSED_FLAG = 0b0000_0001;
+const CONFIG_LINE_PAGES_FLAG = 0b0000_0010;
+const ITEMS_TRACKED_FLAG = 0b0000_1000;
+const MAX_RARITY_TIERS = 16;
+const RARITY_TIER_LENGTH = 4 + 4 + 4;
+const MAX_CONFIG_LINE_PAGES = 16;
+const MINT_DELEGATE_LENGTH = 1 + 32 + 32;
+const ITEMS_REMOVED_LENGTH = 8;
+const PUBLIC_KEY_LENGTH = 32;
+const FUNGIBLE_AMOUNT_LENGTH = 8;
+const URI_ENCODING_MASK = 0b1100_0000;
+const URI_ENCODING_SHIFT = 6;
+const BASE32_ALPHABET = 'abcdefghijklmnopqrstuvwxyz234567';
+
 export function getCandyMachineAccountDataSerializer(): Serializer<
   CandyMachineAccountDataArgs,
   CandyMachineAccountData
@@ -68,13 +133,26 @@ export function getCandyMachineAccountDataSerializer(): Serializer<
     baseGetCandyMachineAccountDataSerializer(),
     (args) => args,
     (base, bytes, offset) => {
-      const slice = bytes.slice(offset + CANDY_MACHINE_HIDDEN_SECTION);
+      // V3 accounts store the hidden section right after the data and
+      // use u16 mint indices when the number of items fits in a u16.
+      const hiddenSectionStart =
+        base.version === AccountVersion.V3
+          ? getCandyMachineHiddenSectionV3(base.data)
+          : CANDY_MACHINE_HIDDEN_SECTION;
+      const indexSerializer =
+        base.version === AccountVersion.V3 &&
+        Number(base.data.itemsAvailable) <= 0xffff
+          ? u16()
+          : u32();
+      const slice = bytes.slice(offset + hiddenSectionStart);
 
       const deserializeRuleSet = (
         ruleBytes: Uint8Array,
         ruleOffset = 0
       ): [Option<PublicKey>, number] => {
-        if (!isProgrammable(base.tokenStandard)) return [none(), ruleOffset];
+        if (!isProgrammableTokenStandard(base.tokenStandard)) {
+          return [none(), ruleOffset];
+        }
         return option(publicKey(), { fixed: true }).deserialize(
           ruleBytes,
           ruleOffset
@@ -84,17 +162,25 @@ export function getCandyMachineAccountDataSerializer(): Serializer<
       if (isNone(base.data.configLineSettings)) {
         return {
           ...base,
+          paused: isPaused(base.features),
           items: [],
           itemsLoaded: 0,
           ruleSet: deserializeRuleSet(slice)[0],
+          configLinesRoot: none(),
+          rarityTiers: [],
+          configLinePages: [],
+          ...deserializeSettings(slice, getMintDelegateOffset(0)),
         };
       }
 
       const itemsAvailable = Number(base.data.itemsAvailable);
-      const itemsMinted = Number(base.itemsRedeemed);
-      const itemsRemaining = itemsAvailable - itemsMinted;
       const { isSequential, nameLength, uriLength, prefixName, prefixUri } =
         base.data.configLineSettings.value;
+      // Config lines are not stored on the account when they are
+      // verified using a merkle root or stored on page accounts.
+      const storesConfigLines =
+        (base.features[0] & MERKLE_CONFIG_LINES_FEATURE) === 0 &&
+        (base.features[1] & CONFIG_LINE_PAGES_FLAG) === 0;
 
       const hiddenSectionSerializer: Serializer<CandyMachineHiddenSection> =
         struct<CandyMachineHiddenSection>([
@@ -102,48 +188,289 @@ export function getCandyMachineAccountDataSerializer(): Serializer<
           [
             'rawConfigLines',
             array(
-              struct<{ name: string; uri: string }>([
+              struct<{ name: string; uri: Uint8Array }>([
                 ['name', string({ size: nameLength })],
-                ['uri', string({ size: uriLength })],
+                ['uri', bytesSerializer({ size: uriLength })],
               ]),
-              { size: itemsAvailable }
+              { size: storesConfigLines ? itemsAvailable : 0 }
             ),
           ],
           ['itemsLoadedMap', bitArray(Math.floor(itemsAvailable / 8) + 1)],
-          ['itemsLeftToMint', array(u32(), { size: itemsAvailable })],
+          [
+            'itemsLeftToMint',
+            array(indexSerializer, { size: itemsAvailable }),
+          ],
         ]);
 
       const [hiddenSection, hiddenSectionOffset] =
         hiddenSectionSerializer.deserialize(slice);
 
-      const itemsLeftToMint = hiddenSection.itemsLeftToMint.slice(
-        0,
-        itemsRemaining
+      // The config lines root (or commitment) and the rarity tiers are
+      // stored after the rule set space.
+      const rootOffset = hiddenSectionOffset + 1 + 32;
+      const root = slice.slice(rootOffset, rootOffset + 32);
+      const configLinesRoot =
+        root.length === 32 && root.some((byte) => byte !== 0)
+          ? some(root)
+          : none<Uint8Array>();
+      const rarityTiers = deserializeRarityTiers(slice, rootOffset + 32);
+      const pagesOffset =
+        rootOffset + 32 + 1 + MAX_RARITY_TIERS * RARITY_TIER_LENGTH;
+      const configLinePages =
+        slice.length > pagesOffset
+          ? array(publicKey(), { size: u8() }).deserialize(
+              slice,
+              pagesOffset
+            )[0]
+          : [];
+      const settings = deserializeSettings(
+        slice,
+        getMintDelegateOffset(hiddenSectionOffset)
+      );
+      // Items reserved by pending mint requests are counted as redeemed,
+      // but they are only taken out of the mint indices once revealed.
+      // Removed config lines are taken out of the mint indices as well.
+      const itemsMinted =
+        Number(base.itemsRedeemed) - getPendingRequests(base.features);
+      const itemsRemaining =
+        itemsAvailable - itemsMinted - Number(settings.itemsRemoved);
+      const itemsLeftToMint = getItemsLeftToMint(
+        hiddenSection.itemsLeftToMint,
+        itemsRemaining,
+        rarityTiers
       );
+      const uriEncoding: UriEncoding =
+        (base.features[0] & URI_ENCODING_MASK) >> URI_ENCODING_SHIFT;
+      // Once all config lines are loaded, the loaded bit-mask keeps track
+      // of the items taken (minted or removed) instead.
+      const itemsTracked =
+        (base.features[1] & ITEMS_TRACKED_FLAG) === ITEMS_TRACKED_FLAG;
+      const isTaken = (loaded: boolean, index: number): boolean => {
+        if (itemsTracked) return !loaded;
+        return isSequential ? index < itemsMinted : !itemsLeftToMint.has(index);
+      };
       const items: CandyMachineItem[] = [];
       hiddenSection.itemsLoadedMap.forEach((loaded, index) => {
-        if (!loaded) return;
+        if (!storesConfigLines || index >= itemsAvailable) return;
+        if (!loaded && !itemsTracked) return;
         const rawItem = hiddenSection.rawConfigLines[index];
         items.push({
           index,
-          minted: isSequential
-            ? index < itemsMinted
-            : !itemsLeftToMint.includes(index),
+          minted: isTaken(loaded, index),
           name: replaceItemPattern(prefixName, index) + rawItem.name,
-          uri: replaceItemPattern(prefixUri, index) + rawItem.uri,
+          uri:
+            replaceItemPattern(prefixUri, index) +
+            encodeUri(uriEncoding, rawItem.uri),
         });
       });
 
       return {
         ...base,
+        paused: isPaused(base.features),
         items,
         itemsLoaded: hiddenSection.itemsLoaded,
         ruleSet: deserializeRuleSet(slice, hiddenSectionOffset)[0],
+        configLinesRoot,
+        rarityTiers,
+        configLinePages,
+        ...settings,
       };
     }
   );
 }
 
+/** Returns whether the pause flag is set on the features. */
+function isPaused(features: number[]): boolean {
+  return (features[1] & PAUSED_FLAG) === PAUSED_FLAG;
+}
+
+/** Returns the number of mint requests waiting to be revealed. */
+function getPendingRequests(features: number[]): number {
+  return features[2] | (features[3] << 8);
+}
+
+/** Returns the rarity tiers stored at the given offset, if any. */
+function deserializeRarityTiers(
+  bytes: Uint8Array,
+  offset: number
+): CandyMachineRarityTier[] {
+  if (bytes.length <= offset) {
+    return [];
+  }
+  return array(
+    struct<CandyMachineRarityTier>([
+      ['weight', u32()],
+      ['size', u32()],
+      ['remaining', u32()],
+    ]),
+    { size: u8() }
+  ).deserialize(bytes, offset)[0];
+}
+
+/**
+ * Returns the offset of the mint delegate, which is stored after the
+ * rule set, config lines root, rarity tiers and config line pages spaces.
+ */
+function getMintDelegateOffset(spaceEnd: number): number {
+  return (
+    spaceEnd +
+    1 +
+    32 +
+    32 +
+    1 +
+    MAX_RARITY_TIERS * RARITY_TIER_LENGTH +
+    1 +
+    MAX_CONFIG_LINE_PAGES * 32
+  );
+}
+
+/**
+ * Returns the settings stored after the config line pages space, starting
+ * with the mint delegate at the given offset. Each setting is only stored
+ * once set, so missing settings get their default value.
+ */
+function deserializeSettings(
+  bytes: Uint8Array,
+  offset: number
+): Pick<
+  CandyMachineAccountData,
+  | 'mintDelegate'
+  | 'itemsRemoved'
+  | 'masterEditionMint'
+  | 'fungibleAmount'
+  | 'groupAuthority'
+> {
+  const itemsRemovedOffset = offset + MINT_DELEGATE_LENGTH;
+  const masterEditionMintOffset = itemsRemovedOffset + ITEMS_REMOVED_LENGTH;
+  const fungibleAmountOffset = masterEditionMintOffset + PUBLIC_KEY_LENGTH;
+  const groupAuthorityOffset = fungibleAmountOffset + FUNGIBLE_AMOUNT_LENGTH;
+  return {
+    mintDelegate: deserializeMintDelegate(bytes, offset),
+    itemsRemoved: deserializeItemsRemoved(bytes, itemsRemovedOffset),
+    masterEditionMint: deserializeOptionalPublicKey(
+      bytes,
+      masterEditionMintOffset
+    ),
+    fungibleAmount: deserializeFungibleAmount(bytes, fungibleAmountOffset),
+    groupAuthority: deserializeOptionalPublicKey(bytes, groupAuthorityOffset),
+  };
+}
+
+/**
+ * Returns the mint delegate stored at the given offset, if any. The role
+ * is stored as its value plus one, so a zero byte means no delegate.
+ */
+function deserializeMintDelegate(
+  bytes: Uint8Array,
+  offset: number
+): Option<MintDelegate> {
+  if (bytes.length < offset + MINT_DELEGATE_LENGTH) {
+    return none();
+  }
+  const role = bytes[offset];
+  if (role < 1 || role > 3) {
+    return none();
+  }
+  return some({
+    role: (role - 1) as MintDelegateRole,
+    delegate: publicKey().deserialize(bytes, offset + 1)[0],
+    lockedAddress: publicKey().deserialize(bytes, offset + 1 + 32)[0],
+  });
+}
+
+/** Returns the number of removed config lines stored at the given offset. */
+function deserializeItemsRemoved(bytes: Uint8Array, offset: number): bigint {
+  if (bytes.length < offset + ITEMS_REMOVED_LENGTH) {
+    return BigInt(0);
+  }
+  return u64().deserialize(bytes, offset)[0];
+}
+
+/**
+ * Returns the public key stored at the given offset, if any. The default
+ * public key means that no public key is set.
+ */
+function deserializeOptionalPublicKey(
+  bytes: Uint8Array,
+  offset: number
+): Option<PublicKey> {
+  if (bytes.length < offset + PUBLIC_KEY_LENGTH) {
+    return none();
+  }
+  const key = publicKey().deserialize(bytes, offset)[0];
+  return key === defaultPublicKey() ? none() : some(key);
+}
+
+/**
+ * Returns the number of tokens minted per item stored at the given
+ * offset, which is one when not set.
+ */
+function deserializeFungibleAmount(bytes: Uint8Array, offset: number): bigint {
+  if (bytes.length < offset + FUNGIBLE_AMOUNT_LENGTH) {
+    return BigInt(1);
+  }
+  const amount = u64().deserialize(bytes, offset)[0];
+  return amount > BigInt(1) ? amount : BigInt(1);
+}
+
+/**
+ * Returns the indices of the items that are still available to mint.
+ *
+ * The indices of each rarity tier are stored contiguously on the mint
+ * indices, with the available ones at the start of the tier range.
+ */
+function getItemsLeftToMint(
+  mintIndices: number[],
+  itemsRemaining: number,
+  rarityTiers: CandyMachineRarityTier[]
+): Set<number> {
+  if (rarityTiers.length === 0) {
+    return new Set(mintIndices.slice(0, itemsRemaining));
+  }
+  const itemsLeftToMint = new Set<number>();
+  let start = 0;
+  rarityTiers.forEach(({ size, remaining }) => {
+    mintIndices
+      .slice(start, start + remaining)
+      .forEach((index) => itemsLeftToMint.add(index));
+    start += size;
+  });
+  return itemsLeftToMint;
+}
+
+/** Encodes the raw bytes of a config line URI. */
+function encodeUri(encoding: UriEncoding, bytes: Uint8Array): string {
+  switch (encoding) {
+    case UriEncoding.Base64Url:
+      return base64
+        .deserialize(bytes)[0]
+        .replace(/\+/g, '-')
+        .replace(/\//g, '_')
+        .replace(/=+$/, '');
+    case UriEncoding.Base58:
+      return base58.deserialize(bytes)[0];
+    case UriEncoding.Base32: {
+      let uri = '';
+      let buffer = 0;
+      let bits = 0;
+      bytes.forEach((byte) => {
+        buffer = ((buffer << 8) | byte) & 0xffff;
+        bits += 8;
+        while (bits >= 5) {
+          bits -= 5;
+          uri += BASE32_ALPHABET[(buffer >> bits) & 0x1f];
+        }
+      });
+      if (bits > 0) {
+        uri += BASE32_ALPHABET[(buffer << (5 - bits)) & 0x1f];
+      }
+      return uri;
+    }
+    default:
+      return removeNullCharacters(utf8.deserialize(bytes)[0]);
+  }
+}
+
 function replaceItemPattern(value: string, index: number): string {
   return value.replace('$ID+1$', `${index + 1}`).replace('$ID$', `${index}`);
 }
@@ -0,0 +1,49 @@
+import { TokenStandard } from '@metaplex-foundation/mpl-token-metadata';
+import {
+  mapSerializer,
+  Serializer,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Token standards minted by a Candy Machine that are not part
+ * of the Token Metadata `TokenStandard` enum. Their values start
+ * at 100 so both can be stored in the same field of the account.
+ */
+export enum ExtendedTokenStandard {
+  Core = 100,
+  Compressed,
+  Token2022,
+  PrintEdition,
+}
+
+/** The token standard of the items minted by a Candy Machine. */
+export type CandyMachineTokenStandard = TokenStandard | ExtendedTokenStandard;
+
+export type CandyMachineTokenStandardArgs = CandyMachineTokenStandard;
+
+export function getCandyMachineTokenStandardSerializer(): Serializer<
+  CandyMachineTokenStandardArgs,
+  CandyMachineTokenStandard
+> {
+  return mapSerializer<
+    CandyMachineTokenStandardArgs,
+    number,
+    CandyMachineTokenStandard
+  >(
+    u8(),
+    (value) => value,
+    (value) => {
+      if (!(value in TokenStandard) && !(value in ExtendedTokenStandard)) {
+        throw new Error(`Invalid Candy Machine token standard: ${value}`);
+      }
+      return value;
+    }
+  );
+}
+
+export function isProgrammableTokenStandard(
+  tokenStandard: CandyMachineTokenStandard
+): boolean {
+  return tokenStandard === TokenStandard.ProgrammableNonFungible;
+}
@@ -9,12 +9,14 @@ export * from './create';
 export * from './createCandyGuard';
 export * from './createCandyMachine';
 export * from './createCandyMachineV2';
+export * from './createCandyMachineV3';
 export * from './createLutForCandyMachine';
 export * from './errors';
 export * from './getCandyMachineRuleSet';
 export * from './merkle';
 export * from './mint';
 export * from './mintV2';
+export * from './mintV3';
 export * from './plugin';
 export * from './route';
 export * from './updateCandyGuard';
@@ -1,5 +1,6 @@
 import { MerkleTree } from 'merkletreejs';
 import { keccak_256 } from '@noble/hashes/sha3';
+import { string, tuple, u32 } from '@metaplex-foundation/umi/serializers';
 
 /**
  * Describes the required data input for
@@ -51,3 +52,18 @@ export const getMerkleProofAtIndex = (
   data: MerkleTreeInput[],
   index: number
 ): Uint8Array[] => getMerkleProof(data, data[index], index);
+
+/**
+ * Creates the Merkle Tree data of the given config lines,
+ * where each item is the serialized index, name and URI
+ * of a config line.
+ *
+ * The Merkle Root of this data can be set on a Candy Machine
+ * to verify the config lines supplied at mint time.
+ */
+export const getConfigLinesMerkleData = (
+  configLines: { name: string; uri: string }[]
+): Uint8Array[] =>
+  configLines.map(({ name, uri }, index) =>
+    tuple([u32(), string(), string()]).serialize([index, name, uri])
+  );
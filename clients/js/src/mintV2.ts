@@ -2,7 +2,6 @@ import {
   TokenStandard,
   findTokenRecordPda,
   getTokenRecordSize,
-  isProgrammable,
 } from '@metaplex-foundation/mpl-token-metadata';
 import {
   findAssociatedTokenPda,
@@ -33,7 +32,11 @@ import {
   parseGuardRemainingAccounts,
   parseMintArgs,
 } from './guards';
-import { findCandyGuardPda } from './hooked';
+import {
+  CandyMachineTokenStandard,
+  findCandyGuardPda,
+  isProgrammableTokenStandard,
+} from './hooked';
 
 export { MintV2InstructionAccounts };
 
@@ -47,7 +50,7 @@ export type MintV2InstructionDataArgs<MA extends GuardSetMintArgs> = {
   mintArgs?: Partial<MA>;
   group?: OptionOrNullable<string>;
   /** @defaultValue `TokenStandard.NonFungible`. */
-  tokenStandard?: TokenStandard;
+  tokenStandard?: CandyMachineTokenStandard;
 };
 
 export function mintV2<MA extends GuardSetMintArgs = DefaultGuardSetMintArgs>(
@@ -80,7 +83,7 @@ export function mintV2<MA extends GuardSetMintArgs = DefaultGuardSetMintArgs>(
 
   // Default token Record value.
   const tokenStandard = input.tokenStandard ?? TokenStandard.NonFungible;
-  const defaultTokenRecord = isProgrammable(tokenStandard)
+  const defaultTokenRecord = isProgrammableTokenStandard(tokenStandard)
     ? findTokenRecordPda(context, {
         mint: publicKey(input.nftMint, false),
         token: publicKey(
@@ -112,7 +115,7 @@ export function mintV2<MA extends GuardSetMintArgs = DefaultGuardSetMintArgs>(
       getMintSize() + getTokenSize() + 2 * ACCOUNT_HEADER_SIZE;
   }
 
-  if (isProgrammable(tokenStandard)) {
+  if (isProgrammableTokenStandard(tokenStandard)) {
     ix.bytesCreatedOnChain += getTokenRecordSize() + ACCOUNT_HEADER_SIZE;
   }
 
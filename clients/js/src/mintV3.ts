@@ -0,0 +1,138 @@
+import {
+  TokenStandard,
+  findTokenRecordPda,
+  getTokenRecordSize,
+} from '@metaplex-foundation/mpl-token-metadata';
+import {
+  findAssociatedTokenPda,
+  getMintSize,
+  getTokenSize,
+} from '@metaplex-foundation/mpl-toolbox';
+import {
+  ACCOUNT_HEADER_SIZE,
+  Option,
+  OptionOrNullable,
+  TransactionBuilder,
+  isSigner,
+  none,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import { MASTER_EDITION_SIZE, METADATA_SIZE } from './constants';
+import { DefaultGuardSetMintArgs } from './defaultGuards';
+import {
+  MintV3InstructionAccounts,
+  mintV3 as baseMintV3,
+} from './generated/instructions/mintV3';
+import { ConfigLineProof, ConfigLineProofArgs } from './generated/types';
+import {
+  CandyGuardProgram,
+  GuardRepository,
+  GuardSetMintArgs,
+  MintContext,
+  parseGuardRemainingAccounts,
+  parseMintArgs,
+} from './guards';
+import {
+  CandyMachineTokenStandard,
+  findCandyGuardPda,
+  isProgrammableTokenStandard,
+} from './hooked';
+
+export { MintV3InstructionAccounts };
+
+export type MintV3InstructionData<MA extends GuardSetMintArgs> = {
+  discriminator: Array<number>;
+  index: Option<number>;
+  configLine: Option<ConfigLineProof>;
+  mintArgs: MA;
+  group: Option<string>;
+};
+
+export type MintV3InstructionDataArgs<MA extends GuardSetMintArgs> = {
+  /** The index of the item to mint (requires the pick item mode). */
+  index?: OptionOrNullable<number>;
+  /** The config line to mint with its merkle proof (merkle config lines). */
+  configLine?: OptionOrNullable<ConfigLineProofArgs>;
+  mintArgs?: Partial<MA>;
+  group?: OptionOrNullable<string>;
+  /** @defaultValue `TokenStandard.NonFungible`. */
+  tokenStandard?: CandyMachineTokenStandard;
+};
+
+export function mintV3<MA extends GuardSetMintArgs = DefaultGuardSetMintArgs>(
+  context: Parameters<typeof baseMintV3>[0] & {
+    guards: GuardRepository;
+  },
+  input: MintV3InstructionAccounts &
+    MintV3InstructionDataArgs<
+      MA extends undefined ? DefaultGuardSetMintArgs : MA
+    >
+): TransactionBuilder {
+  const {
+    index = none(),
+    configLine = none(),
+    mintArgs = {},
+    group = none(),
+    ...rest
+  } = input;
+
+  // Parsing mint data.
+  const program = context.programs.get<CandyGuardProgram>('mplCandyGuard');
+  const candyMachine = publicKey(input.candyMachine, false);
+  const mintContext: MintContext = {
+    minter: input.minter ?? context.identity,
+    payer: input.payer ?? context.payer,
+    mint: publicKey(input.nftMint, false),
+    candyMachine,
+    candyGuard: publicKey(
+      input.candyGuard ?? findCandyGuardPda(context, { base: candyMachine }),
+      false
+    ),
+  };
+  const { data, remainingAccounts } = parseMintArgs<
+    MA extends undefined ? DefaultGuardSetMintArgs : MA
+  >(context, program, mintContext, mintArgs);
+
+  // Default token Record value.
+  const tokenStandard = input.tokenStandard ?? TokenStandard.NonFungible;
+  const defaultTokenRecord = isProgrammableTokenStandard(tokenStandard)
+    ? findTokenRecordPda(context, {
+        mint: publicKey(input.nftMint, false),
+        token: publicKey(
+          input.token ??
+            findAssociatedTokenPda(context, {
+              mint: publicKey(input.nftMint),
+              owner: publicKey(input.minter ?? context.identity),
+            }),
+          false
+        ),
+      })
+    : undefined;
+
+  const ix = baseMintV3(context, {
+    ...rest,
+    tokenRecord: input.tokenRecord ?? defaultTokenRecord,
+    index,
+    configLine,
+    mintArgs: data,
+    group,
+  }).items[0];
+
+  const [keys, signers] = parseGuardRemainingAccounts(remainingAccounts);
+  ix.instruction.keys.push(...keys);
+  ix.signers.push(...signers);
+  ix.bytesCreatedOnChain =
+    METADATA_SIZE + MASTER_EDITION_SIZE + 2 * ACCOUNT_HEADER_SIZE;
+
+  if (isSigner(input.nftMint)) {
+    ix.bytesCreatedOnChain +=
+      getMintSize() + getTokenSize() + 2 * ACCOUNT_HEADER_SIZE;
+  }
+
+  if (isProgrammableTokenStandard(tokenStandard)) {
+    ix.bytesCreatedOnChain += getTokenRecordSize() + ACCOUNT_HEADER_SIZE;
+  }
+
+  return transactionBuilder([ix]);
+}
@@ -10,6 +10,7 @@ import {
   freezeSolPaymentGuardManifest,
   freezeTokenPaymentGuardManifest,
   gatekeeperGuardManifest,
+  itemRangeGuardManifest,
   mintLimitGuardManifest,
   nftBurnGuardManifest,
   nftGateGuardManifest,
@@ -77,7 +78,8 @@ export const mplCandyMachine = (): UmiPlugin => ({
       freezeTokenPaymentGuardManifest,
       programGateGuardManifest,
       allocationGuardManifest,
-      token2022PaymentGuardManifest
+      token2022PaymentGuardManifest,
+      itemRangeGuardManifest
     );
   },
 });
@@ -0,0 +1,152 @@
+import {
+  createAccount,
+  setComputeUnitLimit,
+} from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  PublicKey,
+  Signer,
+  transactionBuilder,
+  Umi,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  addConfigLinePage,
+  addConfigLines,
+  CandyMachine,
+  ConfigLinePage,
+  fetchCandyMachine,
+  fetchConfigLinePage,
+  mintFromCandyMachineV2,
+} from '../src';
+import {
+  assertSuccessfulMint,
+  createCollectionNft,
+  createUmi,
+  createV2,
+} from './_setup';
+
+const configLines = [
+  { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+  { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+  { name: 'Degen #3', uri: 'https://example.com/degen/3' },
+];
+
+// The page header (discriminator and candy machine) followed by the
+// config lines of the default settings (32 bytes name and 200 bytes uri).
+const PAGE_SPACE = 8 + 32 + configLines.length * (32 + 200);
+
+const addPage = async (umi: Umi, candyMachine: PublicKey, page: Signer) => {
+  await transactionBuilder()
+    .add(
+      createAccount(umi, {
+        newAccount: page,
+        lamports: await umi.rpc.getRent(PAGE_SPACE),
+        space: PAGE_SPACE,
+        programId: umi.programs.get('mplCandyMachineCore').publicKey,
+      })
+    )
+    .add(
+      addConfigLinePage(umi, { candyMachine, configLinePage: page.publicKey })
+    )
+    .sendAndConfirm(umi);
+};
+
+test('it can add a config line page to a candy machine', async (t) => {
+  // Given an empty candy machine.
+  const umi = await createUmi();
+  const candyMachine = await createV2(umi, {
+    itemsAvailable: configLines.length,
+  });
+
+  // When we add a config line page to it.
+  const page = generateSigner(umi);
+  await addPage(umi, candyMachine.publicKey, page);
+
+  // Then the page was linked to the candy machine.
+  const candyMachineAccount = await fetchCandyMachine(
+    umi,
+    candyMachine.publicKey
+  );
+  t.like(candyMachineAccount, <CandyMachine>{
+    configLinePages: [page.publicKey],
+    itemsLoaded: 0,
+  });
+  const pageAccount = await fetchConfigLinePage(umi, page.publicKey);
+  t.like(pageAccount, <ConfigLinePage>{
+    candyMachine: candyMachine.publicKey,
+  });
+});
+
+test('it cannot add a config line page once config lines are loaded', async (t) => {
+  // Given a candy machine with config lines.
+  const umi = await createUmi();
+  const candyMachine = await createV2(umi, { configLines });
+
+  // When we try to add a config line page to it.
+  const promise = addPage(umi, candyMachine.publicKey, generateSigner(umi));
+
+  // Then we expect an error from the program.
+  await t.throwsAsync(promise, { message: /ConfigLinesAlreadyLoaded/ });
+});
+
+test('it mints items from the config lines stored on a page', async (t) => {
+  // Given a candy machine storing its config lines on a page.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const candyMachine = await createV2(umi, {
+    collectionMint,
+    itemsAvailable: configLines.length,
+  });
+  const page = generateSigner(umi);
+  await addPage(umi, candyMachine.publicKey, page);
+  await transactionBuilder()
+    .add(
+      addConfigLines(umi, {
+        candyMachine: candyMachine.publicKey,
+        index: 0,
+        configLines,
+      }).addRemainingAccounts({
+        pubkey: page.publicKey,
+        isSigner: false,
+        isWritable: true,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When we mint an item, providing the page as a remaining account.
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600000 }))
+    .add(
+      mintFromCandyMachineV2(umi, {
+        candyMachine: candyMachine.publicKey,
+        mintAuthority: umi.identity,
+        nftOwner: umi.identity.publicKey,
+        nftMint: mint,
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+      }).addRemainingAccounts({
+        pubkey: page.publicKey,
+        isSigner: false,
+        isWritable: false,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the item was minted using a config line of the page.
+  await assertSuccessfulMint(t, umi, {
+    mint,
+    owner: umi.identity,
+    name: /^Degen #[1-3]$/,
+    uri: /^https:\/\/example\.com\/degen\/[1-3]$/,
+  });
+  const candyMachineAccount = await fetchCandyMachine(
+    umi,
+    candyMachine.publicKey
+  );
+  t.like(candyMachineAccount, <CandyMachine>{
+    itemsRedeemed: 1n,
+    itemsLoaded: 3,
+  });
+});
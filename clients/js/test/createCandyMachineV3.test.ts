@@ -0,0 +1,305 @@
+import { TokenStandard } from '@metaplex-foundation/mpl-token-metadata';
+import {
+  generateSigner,
+  none,
+  percentAmount,
+  publicKey,
+  some,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  AccountVersion,
+  addConfigLines,
+  CandyMachine,
+  CandyMachineItem,
+  createCandyMachineV3,
+  Creator,
+  fetchCandyMachine,
+  getCandyMachineSizeV3,
+  updateCandyMachine,
+} from '../src';
+import { createCollectionNft, createUmi } from './_setup';
+
+test('it can create a compact candy machine using config line settings', async (t) => {
+  // Given an existing collection NFT.
+  const umi = await createUmi();
+  const collectionMint = await createCollectionNft(umi);
+
+  // When we create a new V3 candy machine with config line settings.
+  const candyMachine = generateSigner(umi);
+  const creator = generateSigner(umi);
+  const data = {
+    itemsAvailable: 100,
+    symbol: 'DEGEN',
+    sellerFeeBasisPoints: percentAmount(1.23),
+    maxEditionSupply: 0,
+    isMutable: true,
+    creators: [
+      { address: creator.publicKey, verified: false, percentageShare: 100 },
+    ],
+    configLineSettings: some({
+      prefixName: 'My NFT #',
+      nameLength: 8,
+      prefixUri: 'https://example.com/',
+      uriLength: 20,
+      isSequential: false,
+    }),
+    hiddenSettings: none(),
+  };
+  await transactionBuilder()
+    .add(
+      await createCandyMachineV3(umi, {
+        ...data,
+        candyMachine,
+        tokenStandard: TokenStandard.NonFungible,
+        collectionMint: collectionMint.publicKey,
+        collectionUpdateAuthority: umi.identity,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect the candy machine account to have the right data.
+  const candyMachineAccount = await fetchCandyMachine(
+    umi,
+    candyMachine.publicKey
+  );
+  t.like(candyMachineAccount, <CandyMachine>{
+    publicKey: publicKey(candyMachine),
+    authority: publicKey(umi.identity),
+    mintAuthority: publicKey(umi.identity),
+    collectionMint: publicKey(collectionMint),
+    version: AccountVersion.V3,
+    tokenStandard: TokenStandard.NonFungible,
+    itemsRedeemed: 0n,
+    itemsLoaded: 0,
+    data: {
+      itemsAvailable: 100n,
+      sellerFeeBasisPoints: percentAmount(1.23),
+      maxEditionSupply: 0n,
+      isMutable: true,
+      creators: [
+        {
+          address: publicKey(creator),
+          verified: false,
+          percentageShare: 100,
+        },
+      ] as Creator[],
+      hiddenSettings: none(),
+    },
+  });
+});
+
+test('it can add config lines to a compact candy machine', async (t) => {
+  // Given an existing V3 candy machine.
+  const umi = await createUmi();
+  const collectionMint = await createCollectionNft(umi);
+  const candyMachine = generateSigner(umi);
+  await transactionBuilder()
+    .add(
+      await createCandyMachineV3(umi, {
+        candyMachine,
+        tokenStandard: TokenStandard.NonFungible,
+        collectionMint: collectionMint.publicKey,
+        collectionUpdateAuthority: umi.identity,
+        itemsAvailable: 2,
+        sellerFeeBasisPoints: percentAmount(1.23),
+        creators: [
+          {
+            address: umi.identity.publicKey,
+            verified: true,
+            percentageShare: 100,
+          },
+        ],
+        configLineSettings: some({
+          prefixName: '',
+          nameLength: 32,
+          prefixUri: '',
+          uriLength: 200,
+          isSequential: false,
+        }),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When we add config lines to it.
+  await addConfigLines(umi, {
+    candyMachine: candyMachine.publicKey,
+    index: 0,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+    ],
+  }).sendAndConfirm(umi);
+
+  // Then the config lines are read from the compact layout.
+  const candyMachineAccount = await fetchCandyMachine(
+    umi,
+    candyMachine.publicKey
+  );
+  t.like(candyMachineAccount, <CandyMachine>{
+    itemsLoaded: 2,
+    items: [
+      {
+        index: 0,
+        minted: false,
+        name: 'Degen #1',
+        uri: 'https://example.com/degen/1',
+      },
+      {
+        index: 1,
+        minted: false,
+        name: 'Degen #2',
+        uri: 'https://example.com/degen/2',
+      },
+    ] as CandyMachineItem[],
+  });
+});
+
+test('it moves the config lines when the data of a compact candy machine gets smaller', async (t) => {
+  // Given a loaded V3 candy machine with two creators.
+  const umi = await createUmi();
+  const collectionMint = await createCollectionNft(umi);
+  const candyMachine = generateSigner(umi);
+  const creator = generateSigner(umi).publicKey;
+  const data = {
+    itemsAvailable: 2,
+    symbol: 'DEGEN',
+    sellerFeeBasisPoints: percentAmount(1.23),
+    maxEditionSupply: 0,
+    isMutable: true,
+    creators: [
+      { address: umi.identity.publicKey, verified: true, percentageShare: 50 },
+      { address: creator, verified: false, percentageShare: 50 },
+    ],
+    configLineSettings: some({
+      prefixName: '',
+      nameLength: 32,
+      prefixUri: '',
+      uriLength: 200,
+      isSequential: false,
+    }),
+    hiddenSettings: none(),
+  };
+  await transactionBuilder()
+    .add(
+      await createCandyMachineV3(umi, {
+        ...data,
+        candyMachine,
+        tokenStandard: TokenStandard.NonFungible,
+        collectionMint: collectionMint.publicKey,
+        collectionUpdateAuthority: umi.identity,
+      })
+    )
+    .add(
+      addConfigLines(umi, {
+        candyMachine: candyMachine.publicKey,
+        index: 0,
+        configLines: [
+          { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+          { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+        ],
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When we update its data to a single creator.
+  const newData = {
+    ...data,
+    creators: [
+      { address: umi.identity.publicKey, verified: true, percentageShare: 100 },
+    ],
+  };
+  await updateCandyMachine(umi, {
+    candyMachine: candyMachine.publicKey,
+    data: newData,
+  }).sendAndConfirm(umi);
+
+  // Then the account was shrunk to the size of the new data.
+  const account = await umi.rpc.getAccount(candyMachine.publicKey);
+  t.true(account.exists);
+  t.is(account.exists && account.data.length, getCandyMachineSizeV3(newData));
+
+  // And the config lines are still read from the compact layout.
+  const candyMachineAccount = await fetchCandyMachine(
+    umi,
+    candyMachine.publicKey
+  );
+  t.like(candyMachineAccount, <CandyMachine>{
+    version: AccountVersion.V3,
+    itemsLoaded: 2,
+    items: [
+      {
+        index: 0,
+        minted: false,
+        name: 'Degen #1',
+        uri: 'https://example.com/degen/1',
+      },
+      {
+        index: 1,
+        minted: false,
+        name: 'Degen #2',
+        uri: 'https://example.com/degen/2',
+      },
+    ] as CandyMachineItem[],
+  });
+});
+
+test('it cannot make the data of a compact candy machine larger', async (t) => {
+  // Given an existing V3 candy machine with a single creator.
+  const umi = await createUmi();
+  const collectionMint = await createCollectionNft(umi);
+  const candyMachine = generateSigner(umi);
+  const data = {
+    itemsAvailable: 2,
+    sellerFeeBasisPoints: percentAmount(1.23),
+    maxEditionSupply: 0,
+    isMutable: true,
+    creators: [
+      { address: umi.identity.publicKey, verified: true, percentageShare: 100 },
+    ],
+    configLineSettings: some({
+      prefixName: '',
+      nameLength: 32,
+      prefixUri: '',
+      uriLength: 200,
+      isSequential: false,
+    }),
+    hiddenSettings: none(),
+  };
+  await transactionBuilder()
+    .add(
+      await createCandyMachineV3(umi, {
+        ...data,
+        candyMachine,
+        tokenStandard: TokenStandard.NonFungible,
+        collectionMint: collectionMint.publicKey,
+        collectionUpdateAuthority: umi.identity,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When we try to update its data with a second creator.
+  const promise = updateCandyMachine(umi, {
+    candyMachine: candyMachine.publicKey,
+    data: {
+      ...data,
+      symbol: '',
+      creators: [
+        {
+          address: umi.identity.publicKey,
+          verified: true,
+          percentageShare: 50,
+        },
+        {
+          address: generateSigner(umi).publicKey,
+          verified: false,
+          percentageShare: 50,
+        },
+      ],
+    },
+  }).sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /CandyMachineDataTooLarge/ });
+});
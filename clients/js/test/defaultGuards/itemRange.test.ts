@@ -0,0 +1,150 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  some,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import { mintV3, setPickItem } from '../../src';
+import {
+  assertSuccessfulMint,
+  createCollectionNft,
+  createUmi,
+  createV2,
+} from '../_setup';
+
+test('it allows minting an item inside the range', async (t) => {
+  // Given a candy machine with pick item enabled and an item range guard.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collectionMint,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+      { name: 'Degen #3', uri: 'https://example.com/degen/3' },
+    ],
+    guards: {
+      itemRange: some({ start: 0, end: 1 }),
+    },
+  });
+  await setPickItem(umi, { candyMachine, enabled: true }).sendAndConfirm(umi);
+
+  // When we mint an item inside the range.
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV3(umi, {
+        candyMachine,
+        nftMint: mint,
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+        index: some(1),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the mint was successful.
+  await assertSuccessfulMint(t, umi, {
+    mint,
+    owner: umi.identity,
+    name: 'Degen #2',
+  });
+});
+
+test('it forbids minting an item outside the range', async (t) => {
+  // Given a candy machine with pick item enabled and an item range guard.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collectionMint,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+      { name: 'Degen #3', uri: 'https://example.com/degen/3' },
+    ],
+    guards: {
+      itemRange: some({ start: 0, end: 1 }),
+    },
+  });
+  await setPickItem(umi, { candyMachine, enabled: true }).sendAndConfirm(umi);
+
+  // When we try to mint an item outside the range.
+  const mint = generateSigner(umi);
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV3(umi, {
+        candyMachine,
+        nftMint: mint,
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+        index: some(2),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /RequestedItemOutOfRange/ });
+});
+
+test('it forbids minting without requesting an item', async (t) => {
+  // Given a candy machine with an item range guard.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collectionMint,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      itemRange: some({ start: 0, end: 1 }),
+    },
+  });
+
+  // When we try to mint without requesting an item.
+  const mint = generateSigner(umi);
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV3(umi, {
+        candyMachine,
+        nftMint: mint,
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /MissingRequestedItem/ });
+});
+
+test('it forbids requesting an item without an item range guard', async (t) => {
+  // Given a candy machine with pick item enabled and no item range guard.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collectionMint,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {},
+  });
+  await setPickItem(umi, { candyMachine, enabled: true }).sendAndConfirm(umi);
+
+  // When we try to mint a specific item.
+  const mint = generateSigner(umi);
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV3(umi, {
+        candyMachine,
+        nftMint: mint,
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+        index: some(0),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /ItemRangeRequired/ });
+});
@@ -0,0 +1,135 @@
+import {
+  findAssociatedTokenPda,
+  setComputeUnitLimit,
+} from '@metaplex-foundation/mpl-toolbox';
+import {
+  findMasterEditionPda,
+  findMetadataPda,
+} from '@metaplex-foundation/mpl-token-metadata';
+import {
+  AccountMeta,
+  generateSigner,
+  PublicKey,
+  Signer,
+  SignerMeta,
+  transactionBuilder,
+  Umi,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  CandyMachine,
+  fetchCandyMachine,
+  getMplCandyMachineCoreProgramId,
+  mintBatchFromCandyMachine,
+  setSoulbound,
+} from '../src';
+import {
+  assertSuccessfulMint,
+  createCollectionNft,
+  createUmi,
+  createV2,
+} from './_setup';
+
+const configLines = [
+  { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+  { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+  { name: 'Degen #3', uri: 'https://example.com/degen/3' },
+];
+
+const getBatchItemAccounts = (
+  umi: Umi,
+  owner: PublicKey,
+  mint: Signer
+): (AccountMeta | SignerMeta)[] => {
+  const programId = getMplCandyMachineCoreProgramId(umi);
+  return [
+    { pubkey: owner, isSigner: false, isWritable: false },
+    { signer: mint, isWritable: true },
+    {
+      pubkey: findMetadataPda(umi, { mint: mint.publicKey })[0],
+      isSigner: false,
+      isWritable: true,
+    },
+    {
+      pubkey: findMasterEditionPda(umi, { mint: mint.publicKey })[0],
+      isSigner: false,
+      isWritable: true,
+    },
+    {
+      pubkey: findAssociatedTokenPda(umi, { mint: mint.publicKey, owner })[0],
+      isSigner: false,
+      isWritable: true,
+    },
+    // The token record and the mint receipt are not used.
+    { pubkey: programId, isSigner: false, isWritable: false },
+    { pubkey: programId, isSigner: false, isWritable: false },
+  ];
+};
+
+test('the authority can mint a batch of items to a list of owners', async (t) => {
+  // Given a loaded candy machine.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const candyMachine = await createV2(umi, { collectionMint, configLines });
+
+  // When the authority mints a batch of two items to different owners.
+  const ownerA = generateSigner(umi).publicKey;
+  const ownerB = generateSigner(umi).publicKey;
+  const mintA = generateSigner(umi);
+  const mintB = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 1_000_000 }))
+    .add(
+      mintBatchFromCandyMachine(umi, {
+        candyMachine: candyMachine.publicKey,
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+      }).addRemainingAccounts([
+        ...getBatchItemAccounts(umi, ownerA, mintA),
+        ...getBatchItemAccounts(umi, ownerB, mintB),
+      ])
+    )
+    .sendAndConfirm(umi);
+
+  // Then both items were minted to their owners.
+  await assertSuccessfulMint(t, umi, { mint: mintA, owner: ownerA });
+  await assertSuccessfulMint(t, umi, { mint: mintB, owner: ownerB });
+
+  // And the candy machine was updated.
+  const candyMachineAccount = await fetchCandyMachine(
+    umi,
+    candyMachine.publicKey
+  );
+  t.like(candyMachineAccount, <CandyMachine>{ itemsRedeemed: 2n });
+  t.is(candyMachineAccount.items.filter((item) => item.minted).length, 2);
+});
+
+test('it cannot mint a batch of soulbound items', async (t) => {
+  // Given a loaded candy machine minting soulbound items.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const candyMachine = await createV2(umi, { collectionMint, configLines });
+  await transactionBuilder()
+    .add(
+      setSoulbound(umi, { candyMachine: candyMachine.publicKey, enabled: true })
+    )
+    .sendAndConfirm(umi);
+
+  // When the authority tries to mint a batch from it.
+  const owner = generateSigner(umi).publicKey;
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 1_000_000 }))
+    .add(
+      mintBatchFromCandyMachine(umi, {
+        candyMachine: candyMachine.publicKey,
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+      }).addRemainingAccounts(
+        getBatchItemAccounts(umi, owner, generateSigner(umi))
+      )
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error from the program.
+  await t.throwsAsync(promise, { message: /BatchMintNotSupported/ });
+});
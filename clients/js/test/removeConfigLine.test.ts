@@ -0,0 +1,146 @@
+/* eslint-disable no-await-in-loop */
+import { fetchDigitalAsset } from '@metaplex-foundation/mpl-token-metadata';
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  PublicKey,
+  Signer,
+  some,
+  transactionBuilder,
+  Umi,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  CandyMachine,
+  CandyMachineItem,
+  fetchCandyMachine,
+  mintFromCandyMachineV2,
+  removeConfigLine,
+} from '../src';
+import {
+  assertSuccessfulMint,
+  createCollectionNft,
+  createUmi,
+  createV2,
+} from './_setup';
+
+const configLines = [
+  { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+  { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+  { name: 'Degen #3', uri: 'https://example.com/degen/3' },
+];
+
+const configLineSettings = (isSequential: boolean) =>
+  some({
+    prefixName: '',
+    nameLength: 32,
+    prefixUri: '',
+    uriLength: 200,
+    isSequential,
+  });
+
+const mintItem = (
+  umi: Umi,
+  candyMachine: PublicKey,
+  collectionMint: PublicKey,
+  nftMint: Signer = generateSigner(umi)
+) =>
+  transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600000 }))
+    .add(
+      mintFromCandyMachineV2(umi, {
+        candyMachine,
+        mintAuthority: umi.identity,
+        nftOwner: umi.identity.publicKey,
+        nftMint,
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+      })
+    )
+    .sendAndConfirm(umi);
+
+test('it can remove a config line from a sequential candy machine', async (t) => {
+  // Given a loaded sequential candy machine.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collectionMint,
+    configLines,
+    configLineSettings: configLineSettings(true),
+  });
+
+  // When we remove its first config line.
+  await removeConfigLine(umi, { candyMachine, index: 0 }).sendAndConfirm(umi);
+
+  // And mint from it.
+  const mint = generateSigner(umi);
+  await mintItem(umi, candyMachine, collectionMint, mint);
+
+  // Then the removed config line was skipped.
+  await assertSuccessfulMint(t, umi, {
+    mint,
+    owner: umi.identity,
+    name: 'Degen #2',
+  });
+
+  // And both items are no longer available.
+  t.like(await fetchCandyMachine(umi, candyMachine), <CandyMachine>{
+    itemsRedeemed: 1n,
+    itemsRemoved: 1n,
+    items: [
+      { index: 0, minted: true, ...configLines[0] },
+      { index: 1, minted: true, ...configLines[1] },
+      { index: 2, minted: false, ...configLines[2] },
+    ] as CandyMachineItem[],
+  });
+});
+
+test('it never mints a config line removed from a random candy machine', async (t) => {
+  // Given a loaded candy machine with random minting.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collectionMint,
+    configLines,
+    configLineSettings: configLineSettings(false),
+  });
+
+  // When we remove its second config line.
+  await removeConfigLine(umi, { candyMachine, index: 1 }).sendAndConfirm(umi);
+
+  // And mint all the remaining items.
+  const names: string[] = [];
+  for (let i = 0; i < 2; i += 1) {
+    const mint = generateSigner(umi);
+    await mintItem(umi, candyMachine, collectionMint, mint);
+    names.push((await fetchDigitalAsset(umi, mint.publicKey)).metadata.name);
+  }
+
+  // Then the removed config line was never minted.
+  t.deepEqual(names.sort(), ['Degen #1', 'Degen #3']);
+
+  // And the candy machine is empty.
+  const promise = mintItem(umi, candyMachine, collectionMint);
+  await t.throwsAsync(promise, { message: /CandyMachineEmpty/ });
+});
+
+test('it cannot remove a config line that was already minted', async (t) => {
+  // Given a loaded sequential candy machine with its first item minted.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collectionMint,
+    configLines,
+    configLineSettings: configLineSettings(true),
+  });
+  await mintItem(umi, candyMachine, collectionMint);
+
+  // When we try to remove its first config line.
+  const promise = removeConfigLine(umi, {
+    candyMachine,
+    index: 0,
+  }).sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /ItemNotAvailable/ });
+});
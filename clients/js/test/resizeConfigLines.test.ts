@@ -0,0 +1,115 @@
+import { some, transactionBuilder } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  CandyMachine,
+  CandyMachineItem,
+  fetchCandyMachine,
+  getConfigLinesMerkleData,
+  getMerkleRoot,
+  resizeConfigLines,
+  setConfigLinesRoot,
+} from '../src';
+import { createUmi, createV2 } from './_setup';
+
+const configLines = [
+  { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+  { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+  { name: 'Degen #3', uri: 'https://example.com/degen/3' },
+];
+
+const configLineSettings = some({
+  prefixName: '',
+  nameLength: 10,
+  prefixUri: '',
+  uriLength: 50,
+  isSequential: false,
+});
+
+test('it can increase the config line lengths of a candy machine', async (t) => {
+  // Given a loaded candy machine with short config lines.
+  const umi = await createUmi();
+  const candyMachine = await createV2(umi, { configLines, configLineSettings });
+
+  // When we increase the lengths of its config lines.
+  await transactionBuilder()
+    .add(
+      resizeConfigLines(umi, {
+        candyMachine: candyMachine.publicKey,
+        nameLength: 32,
+        uriLength: 200,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the config lines were moved to the new layout.
+  const candyMachineAccount = await fetchCandyMachine(
+    umi,
+    candyMachine.publicKey
+  );
+  t.like(candyMachineAccount, <CandyMachine>{
+    itemsLoaded: 3,
+    items: [
+      { index: 0, minted: false, ...configLines[0] },
+      { index: 1, minted: false, ...configLines[1] },
+      { index: 2, minted: false, ...configLines[2] },
+    ] as CandyMachineItem[],
+  });
+  t.like(candyMachineAccount.data, {
+    configLineSettings: some({ nameLength: 32, uriLength: 200 }),
+  });
+});
+
+test('it cannot resize config lines verified using a merkle root', async (t) => {
+  // Given an empty candy machine using a config lines root.
+  const umi = await createUmi();
+  const candyMachine = await createV2(umi, {
+    itemsAvailable: configLines.length,
+    configLineSettings,
+  });
+  await transactionBuilder()
+    .add(
+      setConfigLinesRoot(umi, {
+        candyMachine: candyMachine.publicKey,
+        root: getMerkleRoot(getConfigLinesMerkleData(configLines)),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When we try to resize its config lines.
+  const promise = transactionBuilder()
+    .add(
+      resizeConfigLines(umi, {
+        candyMachine: candyMachine.publicKey,
+        nameLength: 32,
+        uriLength: 200,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error from the program.
+  await t.throwsAsync(promise, { message: /MerkleConfigLinesEnabled/ });
+});
+
+test('it cannot grow the account by more than 10 KB at once', async (t) => {
+  // Given an empty candy machine with 100 items available.
+  const umi = await createUmi();
+  const candyMachine = await createV2(umi, {
+    itemsAvailable: 100,
+    configLineSettings,
+  });
+
+  // When we try to increase the lengths of its config lines, which
+  // requires 100 * (22 + 150) = 17,200 additional bytes.
+  const promise = transactionBuilder()
+    .add(
+      resizeConfigLines(umi, {
+        candyMachine: candyMachine.publicKey,
+        nameLength: 32,
+        uriLength: 200,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error from the program.
+  await t.throwsAsync(promise, { message: /AccountGrowthTooLarge/ });
+});
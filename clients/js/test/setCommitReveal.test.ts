@@ -0,0 +1,269 @@
+/* eslint-disable no-await-in-loop */
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  PublicKey,
+  Signer,
+  transactionBuilder,
+  Umi,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  CandyMachine,
+  closeMintRequest,
+  fetchCandyMachine,
+  fetchMintRequest,
+  findMintRequestPda,
+  mintFromCandyMachineV2,
+  mintFromCandyMachineV3,
+  MintRequest,
+  revealMint,
+  setCommitReveal,
+  setPaused,
+  setSoulbound,
+} from '../src';
+import {
+  assertSuccessfulMint,
+  createCollectionNft,
+  createUmi,
+  createV2,
+} from './_setup';
+
+const configLines = [
+  { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+  { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+];
+
+const createCommitRevealV2 = async (umi: Umi, collectionMint: PublicKey) => {
+  const candyMachine = await createV2(umi, { collectionMint, configLines });
+  await transactionBuilder()
+    .add(
+      setCommitReveal(umi, {
+        candyMachine: candyMachine.publicKey,
+        enabled: true,
+      })
+    )
+    .sendAndConfirm(umi);
+  return candyMachine.publicKey;
+};
+
+const requestMint = (
+  umi: Umi,
+  candyMachine: PublicKey,
+  collectionMint: PublicKey,
+  nftMint: Signer
+) =>
+  transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600000 }))
+    .add(
+      mintFromCandyMachineV3(umi, {
+        candyMachine,
+        mintAuthority: umi.identity,
+        nftOwner: umi.identity.publicKey,
+        nftMint,
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+        mintRequest: findMintRequestPda(umi, {
+          candyMachine,
+          owner: umi.identity.publicKey,
+        }),
+      })
+    )
+    .sendAndConfirm(umi);
+
+const revealRequest = async (
+  umi: Umi,
+  candyMachine: PublicKey,
+  collectionMint: PublicKey,
+  nftMint: Signer
+) => {
+  // The request can only be revealed once the next slot is known.
+  const { slot } = await fetchMintRequest(
+    umi,
+    findMintRequestPda(umi, { candyMachine, owner: umi.identity.publicKey })
+  );
+  while ((await umi.rpc.getSlot()) <= Number(slot) + 1) {
+    await new Promise((resolve) => setTimeout(resolve, 400));
+  }
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600000 }))
+    .add(
+      revealMint(umi, {
+        candyMachine,
+        mintRequestPayer: umi.payer.publicKey,
+        nftOwner: umi.identity.publicKey,
+        nftMint,
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+      })
+    )
+    .sendAndConfirm(umi);
+};
+
+test('a mint creates a mint request when commit-reveal is enabled', async (t) => {
+  // Given a loaded candy machine using commit-reveal.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const candyMachine = await createCommitRevealV2(umi, collectionMint);
+
+  // When we mint from it.
+  await requestMint(umi, candyMachine, collectionMint, generateSigner(umi));
+
+  // Then a mint request was created for the owner.
+  const mintRequest = await fetchMintRequest(
+    umi,
+    findMintRequestPda(umi, { candyMachine, owner: umi.identity.publicKey })
+  );
+  t.like(mintRequest, <MintRequest>{
+    candyMachine,
+    owner: umi.identity.publicKey,
+    payer: umi.payer.publicKey,
+    sequence: 0,
+  });
+
+  // And the mint was reserved without minting an item.
+  const candyMachineAccount = await fetchCandyMachine(umi, candyMachine);
+  t.like(candyMachineAccount, <CandyMachine>{ itemsRedeemed: 1n });
+  t.is(candyMachineAccount.items.filter((item) => item.minted).length, 0);
+});
+
+test('it mints the item when the mint request is revealed', async (t) => {
+  // Given a mint request of a candy machine using commit-reveal.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const candyMachine = await createCommitRevealV2(umi, collectionMint);
+  const mint = generateSigner(umi);
+  await requestMint(umi, candyMachine, collectionMint, mint);
+
+  // When we reveal the request.
+  await revealRequest(umi, candyMachine, collectionMint, mint);
+
+  // Then the item was minted to the owner.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+
+  // And the mint request was closed.
+  const [mintRequest] = findMintRequestPda(umi, {
+    candyMachine,
+    owner: umi.identity.publicKey,
+  });
+  t.false(await umi.rpc.accountExists(mintRequest));
+  const candyMachineAccount = await fetchCandyMachine(umi, candyMachine);
+  t.like(candyMachineAccount, <CandyMachine>{ itemsRedeemed: 1n });
+  t.is(candyMachineAccount.items.filter((item) => item.minted).length, 1);
+});
+
+test('it can reveal a mint request while the candy machine is paused', async (t) => {
+  // Given a mint request of a candy machine using commit-reveal.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const candyMachine = await createCommitRevealV2(umi, collectionMint);
+  const mint = generateSigner(umi);
+  await requestMint(umi, candyMachine, collectionMint, mint);
+
+  // And the candy machine was paused.
+  await setPaused(umi, { candyMachine, paused: true }).sendAndConfirm(umi);
+
+  // When we reveal the request.
+  await revealRequest(umi, candyMachine, collectionMint, mint);
+
+  // Then the item was minted to the owner.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+
+  // But new mint requests are rejected.
+  const promise = requestMint(
+    umi,
+    candyMachine,
+    collectionMint,
+    generateSigner(umi)
+  );
+  await t.throwsAsync(promise, { message: /CandyMachinePaused/ });
+});
+
+test('it cannot create a mint request with the mint v2 instruction', async (t) => {
+  // Given a loaded candy machine using commit-reveal.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const candyMachine = await createCommitRevealV2(umi, collectionMint);
+
+  // When we try to mint from it using the mint v2 instruction.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600000 }))
+    .add(
+      mintFromCandyMachineV2(umi, {
+        candyMachine,
+        mintAuthority: umi.identity,
+        nftOwner: umi.identity.publicKey,
+        nftMint: generateSigner(umi),
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error from the program.
+  await t.throwsAsync(promise, { message: /MissingMintRequest/ });
+});
+
+test('it cannot close a mint request that can still be revealed', async (t) => {
+  // Given a mint request of a candy machine using commit-reveal.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const candyMachine = await createCommitRevealV2(umi, collectionMint);
+  await requestMint(umi, candyMachine, collectionMint, generateSigner(umi));
+
+  // When we try to close the request.
+  const promise = transactionBuilder()
+    .add(
+      closeMintRequest(umi, {
+        candyMachine,
+        mintRequest: findMintRequestPda(umi, {
+          candyMachine,
+          owner: umi.identity.publicKey,
+        }),
+        mintRequestPayer: umi.payer.publicKey,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error from the program.
+  await t.throwsAsync(promise, { message: /MintRequestNotExpired/ });
+});
+
+test('it cannot enable commit-reveal on a candy machine minting soulbound items', async (t) => {
+  // Given a loaded candy machine minting soulbound items.
+  const umi = await createUmi();
+  const candyMachine = await createV2(umi, { configLines });
+  await transactionBuilder()
+    .add(
+      setSoulbound(umi, { candyMachine: candyMachine.publicKey, enabled: true })
+    )
+    .sendAndConfirm(umi);
+
+  // When we try to enable commit-reveal.
+  const promise = transactionBuilder()
+    .add(
+      setCommitReveal(umi, {
+        candyMachine: candyMachine.publicKey,
+        enabled: true,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error from the program.
+  await t.throwsAsync(promise, { message: /CommitRevealConflict/ });
+});
+
+test('it cannot enable soulbound items on a candy machine using commit-reveal', async (t) => {
+  // Given a loaded candy machine using commit-reveal.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const candyMachine = await createCommitRevealV2(umi, collectionMint);
+
+  // When we try to enable soulbound items.
+  const promise = transactionBuilder()
+    .add(setSoulbound(umi, { candyMachine, enabled: true }))
+    .sendAndConfirm(umi);
+
+  // Then we expect an error from the program.
+  await t.throwsAsync(promise, { message: /CommitRevealConflict/ });
+});
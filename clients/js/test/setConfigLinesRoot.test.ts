@@ -0,0 +1,194 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  none,
+  PublicKey,
+  some,
+  transactionBuilder,
+  Umi,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  CandyMachine,
+  ConfigLineProofArgs,
+  fetchCandyMachine,
+  getConfigLinesMerkleData,
+  getMerkleProofAtIndex,
+  getMerkleRoot,
+  mintFromCandyMachineV3,
+  setConfigLinesRoot,
+} from '../src';
+import {
+  assertSuccessfulMint,
+  createCollectionNft,
+  createUmi,
+  createV2,
+} from './_setup';
+
+const configLines = [
+  { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+  { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+  { name: 'Degen #3', uri: 'https://example.com/degen/3' },
+];
+const merkleData = getConfigLinesMerkleData(configLines);
+
+const getConfigLineProof = (index: number): ConfigLineProofArgs => ({
+  ...configLines[index],
+  proof: getMerkleProofAtIndex(merkleData, index),
+});
+
+const createSequentialV2 = async (umi: Umi, collectionMint: PublicKey) => {
+  const candyMachine = await createV2(umi, {
+    collectionMint,
+    itemsAvailable: configLines.length,
+    configLineSettings: some({
+      prefixName: '',
+      nameLength: 32,
+      prefixUri: '',
+      uriLength: 200,
+      isSequential: true,
+    }),
+  });
+  await transactionBuilder()
+    .add(
+      setConfigLinesRoot(umi, {
+        candyMachine: candyMachine.publicKey,
+        root: getMerkleRoot(merkleData),
+      })
+    )
+    .sendAndConfirm(umi);
+  return candyMachine.publicKey;
+};
+
+test('it can set the config lines root of a candy machine', async (t) => {
+  // Given an empty candy machine.
+  const umi = await createUmi();
+  const candyMachine = await createV2(umi, {
+    itemsAvailable: configLines.length,
+  });
+
+  // When we set the merkle root of its config lines.
+  const root = getMerkleRoot(merkleData);
+  await transactionBuilder()
+    .add(
+      setConfigLinesRoot(umi, { candyMachine: candyMachine.publicKey, root })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the root was stored on the candy machine.
+  const candyMachineAccount = await fetchCandyMachine(
+    umi,
+    candyMachine.publicKey
+  );
+  t.like(candyMachineAccount, <CandyMachine>{
+    configLinesRoot: some(root),
+    itemsLoaded: 0,
+    items: [],
+  });
+});
+
+test('it cannot set the config lines root once config lines are loaded', async (t) => {
+  // Given a candy machine with config lines.
+  const umi = await createUmi();
+  const candyMachine = await createV2(umi, { configLines });
+
+  // When we try to set the merkle root of its config lines.
+  const promise = transactionBuilder()
+    .add(
+      setConfigLinesRoot(umi, {
+        candyMachine: candyMachine.publicKey,
+        root: getMerkleRoot(merkleData),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error from the program.
+  await t.throwsAsync(promise, { message: /ConfigLinesAlreadyLoaded/ });
+});
+
+test('it mints the config lines supplied with a valid proof', async (t) => {
+  // Given a sequential candy machine using a config lines root.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const candyMachine = await createSequentialV2(umi, collectionMint);
+
+  // When we mint the first item, supplying its config line and proof.
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600000 }))
+    .add(
+      mintFromCandyMachineV3(umi, {
+        candyMachine,
+        mintAuthority: umi.identity,
+        nftOwner: umi.identity.publicKey,
+        nftMint: mint,
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+        configLine: some(getConfigLineProof(0)),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the item was minted using the supplied config line.
+  await assertSuccessfulMint(t, umi, {
+    mint,
+    owner: umi.identity,
+    name: 'Degen #1',
+    uri: 'https://example.com/degen/1',
+  });
+  const candyMachineAccount = await fetchCandyMachine(umi, candyMachine);
+  t.like(candyMachineAccount, <CandyMachine>{ itemsRedeemed: 1n });
+});
+
+test('it cannot mint a config line with an invalid proof', async (t) => {
+  // Given a sequential candy machine using a config lines root.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const candyMachine = await createSequentialV2(umi, collectionMint);
+
+  // When we try to mint the first item supplying the config line of
+  // the second item.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600000 }))
+    .add(
+      mintFromCandyMachineV3(umi, {
+        candyMachine,
+        mintAuthority: umi.identity,
+        nftOwner: umi.identity.publicKey,
+        nftMint: generateSigner(umi),
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+        configLine: some(getConfigLineProof(1)),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error from the program.
+  await t.throwsAsync(promise, { message: /InvalidConfigLineProof/ });
+});
+
+test('it cannot mint without a config line when using a config lines root', async (t) => {
+  // Given a sequential candy machine using a config lines root.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const candyMachine = await createSequentialV2(umi, collectionMint);
+
+  // When we try to mint without supplying a config line.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600000 }))
+    .add(
+      mintFromCandyMachineV3(umi, {
+        candyMachine,
+        mintAuthority: umi.identity,
+        nftOwner: umi.identity.publicKey,
+        nftMint: generateSigner(umi),
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+        configLine: none(),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error from the program.
+  await t.throwsAsync(promise, { message: /MissingConfigLineProof/ });
+});
@@ -0,0 +1,79 @@
+/* eslint-disable no-await-in-loop */
+import {
+  fetchDigitalAssetWithAssociatedToken,
+  TokenStandard,
+} from '@metaplex-foundation/mpl-token-metadata';
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  none,
+  some,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  CandyMachine,
+  fetchCandyMachine,
+  mintFromCandyMachineV2,
+  setFungibleAmount,
+} from '../src';
+import { createCollectionNft, createUmi, createV2 } from './_setup';
+
+test('items of the same token type are minted from the same mint', async (t) => {
+  // Given a fungible candy machine with two items of the same token type.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const configLine = { name: 'Gold', uri: 'https://example.com/gold' };
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collectionMint,
+    tokenStandard: TokenStandard.Fungible,
+    configLines: [configLine, configLine],
+    configLineSettings: some({
+      prefixName: '',
+      nameLength: 32,
+      prefixUri: '',
+      uriLength: 200,
+      isSequential: true,
+    }),
+  });
+
+  // And a fungible amount of 5 tokens per item.
+  await setFungibleAmount(umi, { candyMachine, amount: 5 }).sendAndConfirm(umi);
+
+  // When we mint both items, the second one from the mint of the first one.
+  const mint = generateSigner(umi);
+  for (const nftMint of [mint, mint.publicKey]) {
+    await transactionBuilder()
+      .add(setComputeUnitLimit(umi, { units: 600000 }))
+      .add(
+        mintFromCandyMachineV2(umi, {
+          candyMachine,
+          mintAuthority: umi.identity,
+          nftOwner: umi.identity.publicKey,
+          nftMint,
+          collectionMint,
+          collectionUpdateAuthority: umi.identity.publicKey,
+        })
+      )
+      .sendAndConfirm(umi);
+  }
+
+  // Then the owner holds the supply of the token type.
+  const asset = await fetchDigitalAssetWithAssociatedToken(
+    umi,
+    mint.publicKey,
+    umi.identity.publicKey
+  );
+  t.is(asset.mint.supply, 10n);
+  t.is(asset.token.amount, 10n);
+  t.is(asset.metadata.name, configLine.name);
+
+  // And the token type was not verified into the collection.
+  t.deepEqual(asset.metadata.collection, none());
+
+  // And both items were redeemed.
+  t.like(await fetchCandyMachine(umi, candyMachine), <CandyMachine>{
+    itemsRedeemed: 2n,
+    fungibleAmount: 5n,
+  });
+});
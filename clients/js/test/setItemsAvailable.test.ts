@@ -0,0 +1,74 @@
+import { transactionBuilder } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  addConfigLines,
+  CandyMachine,
+  fetchCandyMachine,
+  setItemsAvailable,
+} from '../src';
+import { createUmi, createV2 } from './_setup';
+
+const configLines = [
+  { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+  { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+  { name: 'Degen #3', uri: 'https://example.com/degen/3' },
+];
+
+test('it can increase the items available of a candy machine', async (t) => {
+  // Given a candy machine loaded with two config lines.
+  const umi = await createUmi();
+  const candyMachine = await createV2(umi, {
+    configLines: configLines.slice(0, 2),
+  });
+
+  // When we increase its items available and load a third config line.
+  await transactionBuilder()
+    .add(
+      setItemsAvailable(umi, {
+        candyMachine: candyMachine.publicKey,
+        itemsAvailable: 3,
+      })
+    )
+    .add(
+      addConfigLines(umi, {
+        candyMachine: candyMachine.publicKey,
+        index: 2,
+        configLines: configLines.slice(2),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the candy machine holds the three config lines.
+  const candyMachineAccount = await fetchCandyMachine(
+    umi,
+    candyMachine.publicKey
+  );
+  t.like(candyMachineAccount, <CandyMachine>{
+    itemsLoaded: 3,
+    data: { itemsAvailable: 3n },
+  });
+  t.deepEqual(
+    candyMachineAccount.items.map(({ name, uri }) => ({ name, uri })),
+    configLines
+  );
+});
+
+test('it cannot grow the account by more than 10 KB at once', async (t) => {
+  // Given a candy machine loaded with three config lines.
+  const umi = await createUmi();
+  const candyMachine = await createV2(umi, { configLines });
+
+  // When we try to increase its items available to 100, which requires
+  // more than 10 KB for the additional config lines.
+  const promise = transactionBuilder()
+    .add(
+      setItemsAvailable(umi, {
+        candyMachine: candyMachine.publicKey,
+        itemsAvailable: 100,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error from the program.
+  await t.throwsAsync(promise, { message: /AccountGrowthTooLarge/ });
+});
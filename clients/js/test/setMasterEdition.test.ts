@@ -0,0 +1,95 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  none,
+  some,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  CandyMachine,
+  fetchCandyMachine,
+  mintFromCandyMachineV2,
+  setMasterEdition,
+} from '../src';
+import { createCollectionNft, createUmi, createV2 } from './_setup';
+
+const configLines = [
+  { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+  { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+];
+
+test('it can set and clear the master edition mint of a candy machine', async (t) => {
+  // Given a loaded candy machine.
+  const umi = await createUmi();
+  const candyMachine = await createV2(umi, { configLines });
+
+  // When we set its master edition mint.
+  const masterEditionMint = generateSigner(umi).publicKey;
+  await transactionBuilder()
+    .add(
+      setMasterEdition(umi, {
+        candyMachine: candyMachine.publicKey,
+        masterEditionMint: some(masterEditionMint),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the master edition mint was stored on the candy machine.
+  let candyMachineAccount = await fetchCandyMachine(
+    umi,
+    candyMachine.publicKey
+  );
+  t.like(candyMachineAccount, <CandyMachine>{
+    masterEditionMint: some(masterEditionMint),
+  });
+
+  // And when we clear it.
+  await transactionBuilder()
+    .add(
+      setMasterEdition(umi, {
+        candyMachine: candyMachine.publicKey,
+        masterEditionMint: none(),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the candy machine no longer has a master edition mint.
+  candyMachineAccount = await fetchCandyMachine(umi, candyMachine.publicKey);
+  t.like(candyMachineAccount, <CandyMachine>{ masterEditionMint: none() });
+});
+
+test('it cannot change the master edition mint after the mint has started', async (t) => {
+  // Given a loaded candy machine that minted an item.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const candyMachine = await createV2(umi, { collectionMint, configLines });
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600000 }))
+    .add(
+      mintFromCandyMachineV2(umi, {
+        candyMachine: candyMachine.publicKey,
+        mintAuthority: umi.identity,
+        nftOwner: umi.identity.publicKey,
+        nftMint: generateSigner(umi),
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When we try to set its master edition mint.
+  const promise = transactionBuilder()
+    .add(
+      setMasterEdition(umi, {
+        candyMachine: candyMachine.publicKey,
+        masterEditionMint: some(generateSigner(umi).publicKey),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error from the program.
+  await t.throwsAsync(promise, {
+    message: /NoChangingMasterEditionDuringMint/,
+  });
+});
@@ -0,0 +1,165 @@
+import {
+  findAssociatedTokenPda,
+  setComputeUnitLimit,
+} from '@metaplex-foundation/mpl-toolbox';
+import {
+  fetchTokenRecord,
+  findTokenRecordPda,
+  TokenDelegateRole,
+  TokenStandard,
+} from '@metaplex-foundation/mpl-token-metadata';
+import {
+  defaultPublicKey,
+  generateSigner,
+  none,
+  some,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  CandyMachine,
+  fetchCandyMachine,
+  MintDelegateRole,
+  mintFromCandyMachineV2,
+  setMintDelegate,
+} from '../src';
+import { createCollectionNft, createUmi, createV2 } from './_setup';
+
+const configLines = [
+  { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+  { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+];
+
+test('it can set and clear the mint delegate of a candy machine', async (t) => {
+  // Given a candy machine minting Programmable NFTs.
+  const umi = await createUmi();
+  const candyMachine = await createV2(umi, {
+    configLines,
+    tokenStandard: TokenStandard.ProgrammableNonFungible,
+  });
+
+  // When we set its mint delegate.
+  const delegate = generateSigner(umi).publicKey;
+  await transactionBuilder()
+    .add(
+      setMintDelegate(umi, {
+        candyMachine: candyMachine.publicKey,
+        mintDelegate: some({
+          role: MintDelegateRole.Utility,
+          delegate,
+          lockedAddress: defaultPublicKey(),
+        }),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the mint delegate was stored on the candy machine.
+  let candyMachineAccount = await fetchCandyMachine(
+    umi,
+    candyMachine.publicKey
+  );
+  t.like(candyMachineAccount, <CandyMachine>{
+    mintDelegate: some({
+      role: MintDelegateRole.Utility,
+      delegate,
+      lockedAddress: defaultPublicKey(),
+    }),
+  });
+
+  // And when we clear it.
+  await transactionBuilder()
+    .add(
+      setMintDelegate(umi, {
+        candyMachine: candyMachine.publicKey,
+        mintDelegate: none(),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the candy machine no longer has a mint delegate.
+  candyMachineAccount = await fetchCandyMachine(umi, candyMachine.publicKey);
+  t.like(candyMachineAccount, <CandyMachine>{ mintDelegate: none() });
+});
+
+test('it cannot set a mint delegate on a candy machine minting NFTs', async (t) => {
+  // Given a candy machine minting NFTs.
+  const umi = await createUmi();
+  const candyMachine = await createV2(umi, { configLines });
+
+  // When we try to set its mint delegate.
+  const promise = transactionBuilder()
+    .add(
+      setMintDelegate(umi, {
+        candyMachine: candyMachine.publicKey,
+        mintDelegate: some({
+          role: MintDelegateRole.Utility,
+          delegate: generateSigner(umi).publicKey,
+          lockedAddress: defaultPublicKey(),
+        }),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error from the program.
+  await t.throwsAsync(promise, { message: /MintDelegateNotSupported/ });
+});
+
+test('it approves the mint delegate on the token record of minted items', async (t) => {
+  // Given a candy machine minting Programmable NFTs with a mint delegate.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const candyMachine = await createV2(umi, {
+    collectionMint,
+    configLines,
+    tokenStandard: TokenStandard.ProgrammableNonFungible,
+  });
+  const delegate = generateSigner(umi).publicKey;
+  await transactionBuilder()
+    .add(
+      setMintDelegate(umi, {
+        candyMachine: candyMachine.publicKey,
+        mintDelegate: some({
+          role: MintDelegateRole.Utility,
+          delegate,
+          lockedAddress: defaultPublicKey(),
+        }),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When we mint an item, providing the delegate as a remaining account.
+  const mint = generateSigner(umi);
+  const token = findAssociatedTokenPda(umi, {
+    mint: mint.publicKey,
+    owner: umi.identity.publicKey,
+  });
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600000 }))
+    .add(
+      mintFromCandyMachineV2(umi, {
+        candyMachine: candyMachine.publicKey,
+        mintAuthority: umi.identity,
+        nftOwner: umi.identity.publicKey,
+        nftMint: mint,
+        token,
+        tokenRecord: findTokenRecordPda(umi, { mint: mint.publicKey, token }),
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+      }).addRemainingAccounts({
+        pubkey: delegate,
+        isSigner: false,
+        isWritable: false,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the delegate was approved on the token record of the item.
+  const tokenRecord = await fetchTokenRecord(
+    umi,
+    findTokenRecordPda(umi, { mint: mint.publicKey, token })
+  );
+  t.like(tokenRecord, {
+    delegate: some(delegate),
+    delegateRole: some(TokenDelegateRole.Utility),
+  });
+});
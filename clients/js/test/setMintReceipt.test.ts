@@ -0,0 +1,105 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  none,
+  PublicKey,
+  transactionBuilder,
+  Umi,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  fetchMintReceipt,
+  findMintReceiptPda,
+  mintFromCandyMachineV2,
+  mintFromCandyMachineV3,
+  MintReceipt,
+  setMintReceipt,
+} from '../src';
+import {
+  assertSuccessfulMint,
+  createCollectionNft,
+  createUmi,
+  createV2,
+} from './_setup';
+
+const createMintReceiptV2 = async (umi: Umi, collectionMint: PublicKey) => {
+  const candyMachine = await createV2(umi, {
+    collectionMint,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+  });
+  await transactionBuilder()
+    .add(
+      setMintReceipt(umi, {
+        candyMachine: candyMachine.publicKey,
+        enabled: true,
+      })
+    )
+    .sendAndConfirm(umi);
+  return candyMachine.publicKey;
+};
+
+test('it creates a mint receipt for each item minted', async (t) => {
+  // Given a loaded candy machine with mint receipts enabled.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const candyMachine = await createMintReceiptV2(umi, collectionMint);
+
+  // When we mint an item, providing its mint receipt.
+  const mint = generateSigner(umi);
+  const mintReceipt = findMintReceiptPda(umi, {
+    candyMachine,
+    mint: mint.publicKey,
+  });
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600000 }))
+    .add(
+      mintFromCandyMachineV3(umi, {
+        candyMachine,
+        mintAuthority: umi.identity,
+        nftOwner: umi.identity.publicKey,
+        nftMint: mint,
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+        mintReceipt,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the item was minted.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+
+  // And its mint receipt was created.
+  t.like(await fetchMintReceipt(umi, mintReceipt), <MintReceipt>{
+    candyMachine,
+    mint: mint.publicKey,
+    payer: umi.payer.publicKey,
+    index: 0,
+    itemsRedeemed: 1n,
+    group: none(),
+  });
+});
+
+test('it cannot mint with the mint v2 instruction when mint receipts are enabled', async (t) => {
+  // Given a loaded candy machine with mint receipts enabled.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const candyMachine = await createMintReceiptV2(umi, collectionMint);
+
+  // When we try to mint from it using the mint v2 instruction.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600000 }))
+    .add(
+      mintFromCandyMachineV2(umi, {
+        candyMachine,
+        mintAuthority: umi.identity,
+        nftOwner: umi.identity.publicKey,
+        nftMint: generateSigner(umi),
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error from the program.
+  await t.throwsAsync(promise, { message: /MissingMintReceipt/ });
+});
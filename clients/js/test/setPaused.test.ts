@@ -0,0 +1,80 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import { generateSigner, transactionBuilder } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  CandyMachine,
+  fetchCandyMachine,
+  mintFromCandyMachineV2,
+  setPaused,
+} from '../src';
+import {
+  assertSuccessfulMint,
+  createCollectionNft,
+  createUmi,
+  createV2,
+} from './_setup';
+
+test('it cannot mint from a paused candy machine', async (t) => {
+  // Given a loaded candy machine.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collectionMint,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+  });
+
+  // When we pause it and try to mint from it.
+  await setPaused(umi, { candyMachine, paused: true }).sendAndConfirm(umi);
+  t.like(await fetchCandyMachine(umi, candyMachine), <CandyMachine>{
+    paused: true,
+  });
+  const mint = generateSigner(umi);
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 400000 }))
+    .add(
+      mintFromCandyMachineV2(umi, {
+        candyMachine,
+        mintAuthority: umi.identity,
+        nftOwner: umi.identity.publicKey,
+        nftMint: mint,
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /CandyMachinePaused/ });
+});
+
+test('it can mint from a candy machine after resuming it', async (t) => {
+  // Given a paused candy machine.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collectionMint,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+  });
+  await setPaused(umi, { candyMachine, paused: true }).sendAndConfirm(umi);
+
+  // When we resume it and mint from it.
+  await setPaused(umi, { candyMachine, paused: false }).sendAndConfirm(umi);
+  const mint = generateSigner(umi);
+  const owner = generateSigner(umi).publicKey;
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 400000 }))
+    .add(
+      mintFromCandyMachineV2(umi, {
+        candyMachine,
+        mintAuthority: umi.identity,
+        nftOwner: owner,
+        nftMint: mint,
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the mint was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner });
+});
@@ -0,0 +1,193 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  PublicKey,
+  some,
+  transactionBuilder,
+  Umi,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  CandyMachine,
+  CandyMachineItem,
+  fetchCandyMachine,
+  mintFromCandyMachineV3,
+  mintV3,
+  setPickItem,
+  setRarityTiers,
+} from '../src';
+import {
+  assertSuccessfulMint,
+  createCollectionNft,
+  createUmi,
+  createV2,
+} from './_setup';
+
+const configLines = [
+  { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+  { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+  { name: 'Degen #3', uri: 'https://example.com/degen/3' },
+];
+
+const mintItem = (
+  umi: Umi,
+  candyMachine: PublicKey,
+  collectionMint: PublicKey,
+  index: number,
+  nftMint = generateSigner(umi)
+) =>
+  transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV3(umi, {
+        candyMachine,
+        nftMint,
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+        index: some(index),
+      })
+    )
+    .sendAndConfirm(umi);
+
+test('it can mint a specific item through the candy guard', async (t) => {
+  // Given a candy machine with pick item enabled and an item range guard.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collectionMint,
+    configLines,
+    guards: { itemRange: some({ start: 0, end: 2 }) },
+  });
+  await setPickItem(umi, { candyMachine, enabled: true }).sendAndConfirm(umi);
+
+  // When we mint the second item.
+  const mint = generateSigner(umi);
+  await mintItem(umi, candyMachine, collectionMint, 1, mint);
+
+  // Then the second item was minted.
+  await assertSuccessfulMint(t, umi, {
+    mint,
+    owner: umi.identity,
+    name: 'Degen #2',
+  });
+
+  // And the candy machine was updated.
+  const candyMachineAccount = await fetchCandyMachine(umi, candyMachine);
+  t.like(candyMachineAccount, <CandyMachine>{
+    itemsRedeemed: 1n,
+    items: [
+      { index: 0, minted: false, ...configLines[0] },
+      { index: 1, minted: true, ...configLines[1] },
+      { index: 2, minted: false, ...configLines[2] },
+    ] as CandyMachineItem[],
+  });
+});
+
+test('it cannot mint a specific item without a candy guard', async (t) => {
+  // Given a loaded candy machine with pick item enabled.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collectionMint,
+    configLines,
+  });
+  await setPickItem(umi, { candyMachine, enabled: true }).sendAndConfirm(umi);
+
+  // When the mint authority tries to mint a specific item directly.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintFromCandyMachineV3(umi, {
+        candyMachine,
+        mintAuthority: umi.identity,
+        nftOwner: umi.identity.publicKey,
+        nftMint: generateSigner(umi),
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+        index: some(1),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /PickItemRequiresCandyGuard/ });
+});
+
+test('it cannot mint a specific item when pick item is disabled', async (t) => {
+  // Given a candy machine with an item range guard and pick item disabled.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collectionMint,
+    configLines,
+    guards: { itemRange: some({ start: 0, end: 2 }) },
+  });
+
+  // When we try to mint a specific item.
+  const promise = mintItem(umi, candyMachine, collectionMint, 1);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /PickItemDisabled/ });
+});
+
+test('it cannot mint the same item twice', async (t) => {
+  // Given a candy machine with pick item enabled and an item range guard.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collectionMint,
+    configLines,
+    guards: { itemRange: some({ start: 0, end: 2 }) },
+  });
+  await setPickItem(umi, { candyMachine, enabled: true }).sendAndConfirm(umi);
+
+  // And the first item was already minted.
+  await mintItem(umi, candyMachine, collectionMint, 0);
+
+  // When we try to mint the first item again.
+  const promise = mintItem(umi, candyMachine, collectionMint, 0);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /ItemNotAvailable/ });
+});
+
+test('it cannot enable pick item on a candy machine with rarity tiers', async (t) => {
+  // Given a loaded candy machine with rarity tiers.
+  const umi = await createUmi();
+  const { publicKey: candyMachine } = await createV2(umi, { configLines });
+  await setRarityTiers(umi, {
+    candyMachine,
+    tiers: [
+      { weight: 1, size: 1 },
+      { weight: 10, size: 2 },
+    ],
+  }).sendAndConfirm(umi);
+
+  // When we try to enable pick item.
+  const promise = setPickItem(umi, {
+    candyMachine,
+    enabled: true,
+  }).sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /PickItemRarityTiersConflict/ });
+});
+
+test('it cannot set rarity tiers on a candy machine with pick item enabled', async (t) => {
+  // Given a loaded candy machine with pick item enabled.
+  const umi = await createUmi();
+  const { publicKey: candyMachine } = await createV2(umi, { configLines });
+  await setPickItem(umi, { candyMachine, enabled: true }).sendAndConfirm(umi);
+
+  // When we try to set its rarity tiers.
+  const promise = setRarityTiers(umi, {
+    candyMachine,
+    tiers: [
+      { weight: 1, size: 1 },
+      { weight: 10, size: 2 },
+    ],
+  }).sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /PickItemRarityTiersConflict/ });
+});
@@ -0,0 +1,227 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  PublicKey,
+  some,
+  transactionBuilder,
+  Umi,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  CandyMachine,
+  fetchCandyMachine,
+  mintFromCandyMachineV2,
+  setRarityTiers,
+} from '../src';
+import { createCollectionNft, createUmi, createV2 } from './_setup';
+
+const configLines = [
+  { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+  { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+  { name: 'Degen #3', uri: 'https://example.com/degen/3' },
+];
+
+const mintFromCandyMachine = async (
+  umi: Umi,
+  candyMachine: PublicKey,
+  collectionMint: PublicKey
+) => {
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 400000 }))
+    .add(
+      mintFromCandyMachineV2(umi, {
+        candyMachine,
+        mintAuthority: umi.identity,
+        nftOwner: umi.identity.publicKey,
+        nftMint: generateSigner(umi),
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+      })
+    )
+    .sendAndConfirm(umi);
+};
+
+test('it can set the rarity tiers of a candy machine', async (t) => {
+  // Given a loaded candy machine with random config lines.
+  const umi = await createUmi();
+  const candyMachine = await createV2(umi, { configLines });
+
+  // When we set its rarity tiers.
+  await transactionBuilder()
+    .add(
+      setRarityTiers(umi, {
+        candyMachine: candyMachine.publicKey,
+        tiers: [
+          { weight: 1, size: 1 },
+          { weight: 10, size: 2 },
+        ],
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the rarity tiers were stored on the candy machine.
+  const candyMachineAccount = await fetchCandyMachine(
+    umi,
+    candyMachine.publicKey
+  );
+  t.like(candyMachineAccount, <CandyMachine>{
+    rarityTiers: [
+      { weight: 1, size: 1, remaining: 1 },
+      { weight: 10, size: 2, remaining: 2 },
+    ],
+  });
+});
+
+test('it cannot set rarity tiers that do not cover all config lines', async (t) => {
+  // Given a loaded candy machine with 3 items.
+  const umi = await createUmi();
+  const candyMachine = await createV2(umi, { configLines });
+
+  // When we try to set rarity tiers covering only 2 items.
+  const promise = transactionBuilder()
+    .add(
+      setRarityTiers(umi, {
+        candyMachine: candyMachine.publicKey,
+        tiers: [
+          { weight: 1, size: 1 },
+          { weight: 10, size: 1 },
+        ],
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error from the program.
+  await t.throwsAsync(promise, { message: /InvalidRarityTiers/ });
+});
+
+test('it cannot set a rarity tier with a zero weight', async (t) => {
+  // Given a loaded candy machine with 3 items.
+  const umi = await createUmi();
+  const candyMachine = await createV2(umi, { configLines });
+
+  // When we try to set a rarity tier with a zero weight.
+  const promise = transactionBuilder()
+    .add(
+      setRarityTiers(umi, {
+        candyMachine: candyMachine.publicKey,
+        tiers: [
+          { weight: 0, size: 1 },
+          { weight: 10, size: 2 },
+        ],
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error from the program.
+  await t.throwsAsync(promise, { message: /InvalidRarityTiers/ });
+});
+
+test('it cannot set rarity tiers on a sequential candy machine', async (t) => {
+  // Given a loaded candy machine with sequential config lines.
+  const umi = await createUmi();
+  const candyMachine = await createV2(umi, {
+    configLines,
+    configLineSettings: some({
+      prefixName: '',
+      nameLength: 32,
+      prefixUri: '',
+      uriLength: 200,
+      isSequential: true,
+    }),
+  });
+
+  // When we try to set its rarity tiers.
+  const promise = transactionBuilder()
+    .add(
+      setRarityTiers(umi, {
+        candyMachine: candyMachine.publicKey,
+        tiers: [{ weight: 1, size: 3 }],
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error from the program.
+  await t.throwsAsync(promise, { message: /InvalidRarityTiers/ });
+});
+
+test('it cannot change the rarity tiers after the mint has started', async (t) => {
+  // Given a loaded candy machine with rarity tiers.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const candyMachine = await createV2(umi, { collectionMint, configLines });
+  await transactionBuilder()
+    .add(
+      setRarityTiers(umi, {
+        candyMachine: candyMachine.publicKey,
+        tiers: [{ weight: 1, size: 3 }],
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // And an item was minted from it.
+  await mintFromCandyMachine(umi, candyMachine.publicKey, collectionMint);
+
+  // When we try to change its rarity tiers.
+  const promise = transactionBuilder()
+    .add(
+      setRarityTiers(umi, {
+        candyMachine: candyMachine.publicKey,
+        tiers: [
+          { weight: 1, size: 1 },
+          { weight: 10, size: 2 },
+        ],
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error from the program.
+  await t.throwsAsync(promise, { message: /NoChangingRarityTiersDuringMint/ });
+});
+
+test('it mints items from the rarity tiers until they are all minted', async (t) => {
+  // Given a loaded candy machine with rarity tiers.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const candyMachine = await createV2(umi, { collectionMint, configLines });
+  await transactionBuilder()
+    .add(
+      setRarityTiers(umi, {
+        candyMachine: candyMachine.publicKey,
+        tiers: [
+          { weight: 1, size: 1 },
+          { weight: 10, size: 2 },
+        ],
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When we mint one item.
+  await mintFromCandyMachine(umi, candyMachine.publicKey, collectionMint);
+
+  // Then one item was taken from the rarity tiers.
+  let candyMachineAccount = await fetchCandyMachine(
+    umi,
+    candyMachine.publicKey
+  );
+  const remaining = candyMachineAccount.rarityTiers.reduce(
+    (total, tier) => total + tier.remaining,
+    0
+  );
+  t.is(remaining, 2);
+  t.is(candyMachineAccount.items.filter((item) => item.minted).length, 1);
+
+  // And when we mint the remaining items.
+  await mintFromCandyMachine(umi, candyMachine.publicKey, collectionMint);
+  await mintFromCandyMachine(umi, candyMachine.publicKey, collectionMint);
+
+  // Then all items were minted.
+  candyMachineAccount = await fetchCandyMachine(umi, candyMachine.publicKey);
+  t.like(candyMachineAccount, <CandyMachine>{
+    itemsRedeemed: 3n,
+    rarityTiers: [
+      { weight: 1, size: 1, remaining: 0 },
+      { weight: 10, size: 2, remaining: 0 },
+    ],
+  });
+  t.true(candyMachineAccount.items.every((item) => item.minted));
+});
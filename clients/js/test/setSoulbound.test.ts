@@ -0,0 +1,147 @@
+import {
+  fetchToken,
+  findAssociatedTokenPda,
+  setComputeUnitLimit,
+  TokenState,
+} from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  PublicKey,
+  Signer,
+  transactionBuilder,
+  Umi,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import { mintFromCandyMachineV2, releaseSoulbound, setSoulbound } from '../src';
+import { createCollectionNft, createUmi, createV2 } from './_setup';
+
+const configLines = [
+  { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+  { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+];
+
+const mintFromCandyMachine = (
+  umi: Umi,
+  candyMachine: PublicKey,
+  collectionMint: PublicKey,
+  nftMint: Signer,
+  nftOwner: PublicKey = umi.identity.publicKey
+) =>
+  transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600000 }))
+    .add(
+      mintFromCandyMachineV2(umi, {
+        candyMachine,
+        mintAuthority: umi.identity,
+        nftOwner,
+        nftMint,
+        collectionMint,
+        collectionUpdateAuthority: umi.identity.publicKey,
+      })
+    )
+    .sendAndConfirm(umi);
+
+test('it mints soulbound items locked in the token account of the owner', async (t) => {
+  // Given a loaded candy machine minting soulbound items.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const candyMachine = await createV2(umi, { collectionMint, configLines });
+  await transactionBuilder()
+    .add(
+      setSoulbound(umi, { candyMachine: candyMachine.publicKey, enabled: true })
+    )
+    .sendAndConfirm(umi);
+
+  // When we mint an item from it.
+  const mint = generateSigner(umi);
+  await mintFromCandyMachine(umi, candyMachine.publicKey, collectionMint, mint);
+
+  // Then the item is frozen in the token account of the owner.
+  const token = findAssociatedTokenPda(umi, {
+    mint: mint.publicKey,
+    owner: umi.identity.publicKey,
+  });
+  const tokenAccount = await fetchToken(umi, token);
+  t.is(tokenAccount.state, TokenState.Frozen);
+});
+
+test('it cannot mint soulbound items when the owner is not a signer', async (t) => {
+  // Given a loaded candy machine minting soulbound items.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const candyMachine = await createV2(umi, { collectionMint, configLines });
+  await transactionBuilder()
+    .add(
+      setSoulbound(umi, { candyMachine: candyMachine.publicKey, enabled: true })
+    )
+    .sendAndConfirm(umi);
+
+  // When we try to mint an item to an owner that does not sign.
+  const promise = mintFromCandyMachine(
+    umi,
+    candyMachine.publicKey,
+    collectionMint,
+    generateSigner(umi),
+    generateSigner(umi).publicKey
+  );
+
+  // Then we expect an error from the program.
+  await t.throwsAsync(promise, { message: /SoulboundOwnerNotSigner/ });
+});
+
+test('it cannot change the soulbound setting after the mint has started', async (t) => {
+  // Given a loaded candy machine that minted an item.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const candyMachine = await createV2(umi, { collectionMint, configLines });
+  await mintFromCandyMachine(
+    umi,
+    candyMachine.publicKey,
+    collectionMint,
+    generateSigner(umi)
+  );
+
+  // When we try to enable soulbound items.
+  const promise = transactionBuilder()
+    .add(
+      setSoulbound(umi, { candyMachine: candyMachine.publicKey, enabled: true })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error from the program.
+  await t.throwsAsync(promise, { message: /NoChangingSoulboundDuringMint/ });
+});
+
+test('the authority can unlock a soulbound item', async (t) => {
+  // Given a soulbound item minted from a candy machine.
+  const umi = await createUmi();
+  const collectionMint = (await createCollectionNft(umi)).publicKey;
+  const candyMachine = await createV2(umi, { collectionMint, configLines });
+  await transactionBuilder()
+    .add(
+      setSoulbound(umi, { candyMachine: candyMachine.publicKey, enabled: true })
+    )
+    .sendAndConfirm(umi);
+  const mint = generateSigner(umi);
+  await mintFromCandyMachine(umi, candyMachine.publicKey, collectionMint, mint);
+
+  // When the authority releases the item without burning it.
+  await transactionBuilder()
+    .add(
+      releaseSoulbound(umi, {
+        candyMachine: candyMachine.publicKey,
+        tokenOwner: umi.identity.publicKey,
+        nftMint: mint.publicKey,
+        burn: false,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the item is no longer frozen.
+  const token = findAssociatedTokenPda(umi, {
+    mint: mint.publicKey,
+    owner: umi.identity.publicKey,
+  });
+  const tokenAccount = await fetchToken(umi, token);
+  t.is(tokenAccount.state, TokenState.Initialized);
+});
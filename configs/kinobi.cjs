@@ -110,6 +110,20 @@ kinobi.update(
         candyMachineSeed,
       ],
     },
+    mintRequest: {
+      seeds: [
+        k.stringConstantSeed("mint_request"),
+        candyMachineSeed,
+        k.publicKeySeed("owner", "The owner of the NFT to be minted"),
+      ],
+    },
+    mintReceipt: {
+      seeds: [
+        k.stringConstantSeed("mint_receipt"),
+        candyMachineSeed,
+        k.publicKeySeed("mint", "The address of the minted NFT (or asset)"),
+      ],
+    },
   })
 );
 
@@ -130,8 +144,8 @@ kinobi.update(
       transformer: (node) => {
         return k.structFieldTypeNode({
           ...node,
-          child: k.linkTypeNode("tokenStandard", {
-            importFrom: "mplTokenMetadata",
+          child: k.linkTypeNode("candyMachineTokenStandard", {
+            importFrom: "hooked",
           }),
         });
       },
@@ -217,6 +231,16 @@ const defaultsToMetadataDelegateRecordPda = (
       delegate: k.accountDefault(delegate),
     },
   });
+const defaultsToMintRequestPda = (
+  candyMachine = "candyMachine",
+  owner = "owner"
+) =>
+  k.pdaDefault("mintRequest", {
+    seeds: {
+      candyMachine: k.accountDefault(candyMachine),
+      owner: k.accountDefault(owner),
+    },
+  });
 const defaultsToSplAssociatedTokenProgram = () =>
   k.programDefault(
     "splAssociatedToken",
@@ -321,6 +345,10 @@ kinobi.update(
       },
     },
     "mplCandyMachineCore.initializeV2": { name: "initializeCandyMachineV2" },
+    "mplCandyMachineCore.initializeV3": { name: "initializeCandyMachineV3" },
+    "mplCandyMachineCore.initializeWithoutCollection": {
+      name: "initializeCandyMachineWithoutCollection",
+    },
     "mplCandyMachineCore.mint": {
       name: "mintFromCandyMachine",
       accounts: {
@@ -343,6 +371,58 @@ kinobi.update(
         splAtaProgram: { defaultsTo: defaultsToSplAssociatedTokenProgram() },
       },
     },
+    "mplCandyMachineCore.revealMint": {
+      accounts: {
+        mintRequest: {
+          defaultsTo: defaultsToMintRequestPda("candyMachine", "nftOwner"),
+        },
+        nftMint: { isSigner: "either" },
+        nftMintAuthority: { defaultsTo: k.identityDefault() },
+        token: {
+          defaultsTo: defaultsToAssociatedTokenPda("nftMint", "nftOwner"),
+        },
+        sysvarInstructions: {
+          defaultsTo: k.publicKeyDefault(
+            "Sysvar1nstructions1111111111111111111111111"
+          ),
+        },
+        splAtaProgram: { defaultsTo: defaultsToSplAssociatedTokenProgram() },
+      },
+    },
+    "mplCandyMachineCore.mintV3": {
+      name: "mintFromCandyMachineV3",
+      accounts: {
+        nftMint: { isSigner: "either" },
+        nftMintAuthority: { defaultsTo: k.identityDefault() },
+        token: {
+          defaultsTo: defaultsToAssociatedTokenPda("nftMint", "nftOwner"),
+        },
+        sysvarInstructions: {
+          defaultsTo: k.publicKeyDefault(
+            "Sysvar1nstructions1111111111111111111111111"
+          ),
+        },
+        splAtaProgram: { defaultsTo: defaultsToSplAssociatedTokenProgram() },
+      },
+    },
+    "mplCandyMachineCore.mintBatch": {
+      name: "mintBatchFromCandyMachine",
+      accounts: {
+        sysvarInstructions: {
+          defaultsTo: k.publicKeyDefault(
+            "Sysvar1nstructions1111111111111111111111111"
+          ),
+        },
+        splAtaProgram: { defaultsTo: defaultsToSplAssociatedTokenProgram() },
+      },
+    },
+    "mplCandyMachineCore.releaseSoulbound": {
+      accounts: {
+        token: {
+          defaultsTo: defaultsToAssociatedTokenPda("nftMint", "tokenOwner"),
+        },
+      },
+    },
     "mplCandyGuard.mint": {
       internal: true,
       args: {
@@ -383,6 +463,30 @@ kinobi.update(
         splAtaProgram: { defaultsTo: defaultsToSplAssociatedTokenProgram() },
       },
     },
+    "mplCandyGuard.mintV3": {
+      internal: true,
+      args: {
+        label: { name: "group" },
+      },
+      accounts: {
+        candyGuard: { defaultsTo: defaultsToCandyGuardPda("candyMachine") },
+        nftMint: { isSigner: "either" },
+        nftMintAuthority: { defaultsTo: k.identityDefault() },
+        minter: { defaultsTo: k.identityDefault() },
+        token: {
+          defaultsTo: defaultsToAssociatedTokenPda("nftMint", "minter"),
+        },
+        collectionDelegateRecord: {
+          defaultsTo: defaultsToMetadataDelegateRecordPda(
+            "collection",
+            "collectionMint",
+            "collectionUpdateAuthority",
+            "candyMachineAuthorityPda"
+          ),
+        },
+        splAtaProgram: { defaultsTo: defaultsToSplAssociatedTokenProgram() },
+      },
+    },
     "mplCandyGuard.route": {
       internal: true,
       args: {
@@ -418,6 +522,9 @@ kinobi.update(
   new k.UnwrapTypeDefinedLinksVisitor([
     "initializeCandyMachine.candyMachineData",
     "initializeCandyMachineV2.candyMachineData",
+    "initializeCandyMachineV3.candyMachineData",
+    "initializeCandyMachineWithoutCollection.candyMachineData",
+    "mintFromCandyMachineV3.args",
   ])
 );
 kinobi.update(new k.FlattenInstructionArgsStructVisitor());
@@ -434,6 +541,15 @@ kinobi.update(
   new k.SetStructDefaultValuesVisitor({
     initializeCandyMachineInstructionData: defaultInitialCandyMachineData,
     initializeCandyMachineV2InstructionData: defaultInitialCandyMachineData,
+    initializeCandyMachineV3InstructionData: defaultInitialCandyMachineData,
+    initializeCandyMachineWithoutCollectionInstructionData:
+      defaultInitialCandyMachineData,
+    revealMintInstructionData: { configLine: k.vNone() },
+    mintFromCandyMachineV3InstructionData: {
+      index: k.vNone(),
+      configLine: k.vNone(),
+      group: k.vNone(),
+    },
   })
 );
 
@@ -445,6 +561,10 @@ kinobi.update(
     "initializeCandyMachineInstructionData.sellerFeeBasisPoints": percentAmount,
     "initializeCandyMachineV2InstructionData.sellerFeeBasisPoints":
       percentAmount,
+    "initializeCandyMachineV3InstructionData.sellerFeeBasisPoints":
+      percentAmount,
+    "initializeCandyMachineWithoutCollectionInstructionData.sellerFeeBasisPoints":
+      percentAmount,
     "startDate.date": { kind: "DateTime" },
     "endDate.date": { kind: "DateTime" },
     "botTax.lamports": { kind: "SolAmount" },
@@ -157,4 +157,13 @@ pub enum CandyGuardError {
 
     #[msg("Invalid account version")]
     InvalidAccountVersion,
+
+    #[msg("Missing requested item index")]
+    MissingRequestedItem,
+
+    #[msg("Requested item is outside of the allowed range")]
+    RequestedItemOutOfRange,
+
+    #[msg("Requesting a specific item requires the item range guard")]
+    ItemRangeRequired,
 }
@@ -212,13 +212,19 @@ impl Condition for FreezeSolPayment {
         let candy_machine_info = ctx.accounts.candy_machine.to_account_info();
         let account_data = candy_machine_info.data.borrow_mut();
 
-        let collection_metadata =
-            Metadata::try_from(&ctx.accounts.collection_metadata.to_account_info())?;
+        // candy machines without a collection do not have a collection metadata
+        let collection_metadata = if ctx.accounts.candy_machine.has_collection() {
+            Some(Metadata::try_from(
+                &ctx.accounts.collection_metadata.to_account_info(),
+            )?)
+        } else {
+            None
+        };
 
         let rule_set = ctx
             .accounts
             .candy_machine
-            .get_rule_set(&account_data, &collection_metadata)?;
+            .get_rule_set(&account_data, collection_metadata.as_ref())?;
 
         if let Some(rule_set) = rule_set {
             let mint_rule_set = try_get_account_info(ctx.accounts.remaining, index + 2)?;
@@ -341,16 +347,22 @@ impl FreezeEscrow {
         self.authority = authority;
     }
 
-    pub fn is_thaw_allowed(&self, candy_machine: &CandyMachine, current_timestamp: i64) -> bool {
-        if candy_machine.items_redeemed >= candy_machine.data.items_available {
-            return true;
+    pub fn is_thaw_allowed(
+        &self,
+        candy_machine: &Account<CandyMachine>,
+        current_timestamp: i64,
+    ) -> Result<bool> {
+        // removed config lines do not count as redeemed, so the remaining items
+        // are used to determine whether the candy machine is sold out
+        if candy_machine.get_items_remaining(&candy_machine.to_account_info().data.borrow())? == 0 {
+            return Ok(true);
         } else if let Some(first_mint_time) = self.first_mint_time {
             if current_timestamp >= first_mint_time + self.freeze_period {
-                return true;
+                return Ok(true);
             }
         }
 
-        false
+        Ok(false)
     }
 }
 
@@ -610,7 +622,7 @@ pub fn thaw_nft<'info>(
     // thaw is automatically enabled if the candy machine account is closed, so
     // only check if we have one
     if let Some(ref candy_machine) = route_context.candy_machine {
-        if !freeze_escrow.is_thaw_allowed(candy_machine, current_timestamp) {
+        if !freeze_escrow.is_thaw_allowed(candy_machine, current_timestamp)? {
             return err!(CandyGuardError::ThawNotEnabled);
         }
     }
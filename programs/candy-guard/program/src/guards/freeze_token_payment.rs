@@ -277,13 +277,19 @@ impl Condition for FreezeTokenPayment {
         let candy_machine_info = ctx.accounts.candy_machine.to_account_info();
         let account_data = candy_machine_info.data.borrow_mut();
 
-        let collection_metadata =
-            Metadata::try_from(&ctx.accounts.collection_metadata.to_account_info())?;
+        // candy machines without a collection do not have a collection metadata
+        let collection_metadata = if ctx.accounts.candy_machine.has_collection() {
+            Some(Metadata::try_from(
+                &ctx.accounts.collection_metadata.to_account_info(),
+            )?)
+        } else {
+            None
+        };
 
         let rule_set = ctx
             .accounts
             .candy_machine
-            .get_rule_set(&account_data, &collection_metadata)?;
+            .get_rule_set(&account_data, collection_metadata.as_ref())?;
 
         if let Some(rule_set) = rule_set {
             let mint_rule_set = try_get_account_info(ctx.accounts.remaining, index + 4)?;
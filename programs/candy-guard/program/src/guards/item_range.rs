@@ -0,0 +1,46 @@
+use crate::state::GuardType;
+
+use super::*;
+
+/// Guard that restricts the mint to items requested within a range of indices.
+///
+/// The mint must request a specific item (`index` argument of the `mint_v3`
+/// instruction). Combined with groups, this guard allows setting a different price or
+/// access rules for each range of items.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ItemRange {
+    /// First index of the range (inclusive).
+    pub start: u32,
+    /// Last index of the range (inclusive).
+    pub end: u32,
+}
+
+impl Guard for ItemRange {
+    fn size() -> usize {
+        4   // start
+        + 4 // end
+    }
+
+    fn mask() -> u64 {
+        GuardType::as_mask(GuardType::ItemRange)
+    }
+}
+
+impl Condition for ItemRange {
+    fn validate<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        let index = ctx
+            .requested_index
+            .ok_or(CandyGuardError::MissingRequestedItem)?;
+
+        if index < self.start || index > self.end {
+            return err!(CandyGuardError::RequestedItemOutOfRange);
+        }
+
+        Ok(())
+    }
+}
@@ -16,6 +16,7 @@ pub use end_date::EndDate;
 pub use freeze_sol_payment::{FreezeEscrow, FreezeInstruction, FreezeSolPayment};
 pub use freeze_token_payment::FreezeTokenPayment;
 pub use gatekeeper::Gatekeeper;
+pub use item_range::ItemRange;
 pub use mint_limit::{MintCounter, MintLimit};
 pub use nft_burn::NftBurn;
 pub use nft_gate::NftGate;
@@ -38,6 +39,7 @@ mod end_date;
 mod freeze_sol_payment;
 mod freeze_token_payment;
 mod gatekeeper;
+mod item_range;
 mod mint_limit;
 mod nft_burn;
 mod nft_gate;
@@ -170,6 +172,12 @@ pub struct EvaluationContext<'b, 'c, 'info> {
 
     /// Convenience mapping of remaining account indices.
     pub indices: BTreeMap<&'info str, usize>,
+
+    /// Index of the item requested by the minter (if any).
+    pub requested_index: Option<u32>,
+
+    /// Config line and merkle proof supplied by the minter (if any).
+    pub config_line: Option<mpl_candy_machine_core::ConfigLineProof>,
 }
 
 /// Utility function to try to get the account from the remaining accounts
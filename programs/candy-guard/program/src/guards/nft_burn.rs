@@ -96,7 +96,7 @@ impl Condition for NftBurn {
         let nft_mint_account = try_get_account_info(ctx.accounts.remaining, index + 3)?;
         let nft_mint_collection_metadata = try_get_account_info(ctx.accounts.remaining, index + 4)?;
 
-        if matches!(ctx.accounts.candy_machine.version, AccountVersion::V2) {
+        if !matches!(ctx.accounts.candy_machine.version, AccountVersion::V1) {
             let metadata: Metadata = Metadata::try_from(nft_metadata)?;
             let mut burn_cpi = BurnV1CpiBuilder::new(&ctx.accounts.token_metadata_program);
             burn_cpi
@@ -145,7 +145,7 @@ impl Condition for NftPayment {
         let destination_ata = try_get_account_info(ctx.accounts.remaining, index + 4)?;
         let spl_ata_program = try_get_account_info(ctx.accounts.remaining, index + 5)?;
 
-        if matches!(ctx.accounts.candy_machine.version, AccountVersion::V2) {
+        if !matches!(ctx.accounts.candy_machine.version, AccountVersion::V1) {
             let mut transfer_cpi = TransferV1CpiBuilder::new(&ctx.accounts.token_metadata_program);
             transfer_cpi
                 .token(nft_account)
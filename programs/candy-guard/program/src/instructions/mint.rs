@@ -49,6 +49,16 @@ pub fn mint<'info>(
         remaining: ctx.remaining_accounts,
         authorization_rules_program: None,
         authorization_rules: None,
+        mpl_core_program: None,
+        merkle_tree: None,
+        tree_config: None,
+        bubblegum_signer: None,
+        bubblegum_program: None,
+        log_wrapper: None,
+        compression_program: None,
+        spl_token_2022_program: None,
+        mint_request: None,
+        mint_receipt: None,
     };
 
     // evaluation context for this transaction
@@ -57,6 +67,8 @@ pub fn mint<'info>(
         account_cursor: 0,
         args_cursor: 0,
         indices: BTreeMap::new(),
+        requested_index: None,
+        config_line: None,
     };
 
     process_mint(&mut ctx, mint_args, label)
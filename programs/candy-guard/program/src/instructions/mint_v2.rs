@@ -1,7 +1,10 @@
 use std::collections::BTreeMap;
 
-use anchor_lang::{prelude::*, solana_program::sysvar, Discriminator};
-use mpl_candy_machine_core::CandyMachine;
+use anchor_lang::{prelude::*, solana_program::sysvar, InstructionData};
+use mpl_candy_machine_core::{
+    constants::{TOKEN_STANDARD_CORE, TOKEN_STANDARD_TOKEN_2022},
+    CandyMachine, MintArgs,
+};
 use solana_program::{instruction::Instruction, program::invoke_signed};
 
 use crate::{
@@ -64,6 +67,16 @@ pub fn mint_v2<'info>(
             .authorization_rules
             .as_ref()
             .map(|authorization_rules| authorization_rules.to_account_info()),
+        mpl_core_program: None,
+        merkle_tree: None,
+        tree_config: None,
+        bubblegum_signer: None,
+        bubblegum_program: None,
+        log_wrapper: None,
+        compression_program: None,
+        spl_token_2022_program: None,
+        mint_request: None,
+        mint_receipt: None,
     };
 
     // evaluation context for this transaction
@@ -72,6 +85,8 @@ pub fn mint_v2<'info>(
         account_cursor: 0,
         args_cursor: 0,
         indices: BTreeMap::new(),
+        requested_index: None,
+        config_line: None,
     };
 
     process_mint(&mut ctx, mint_args, label)
@@ -85,7 +100,7 @@ pub fn process_mint(
     let account_info = ctx.accounts.candy_guard.to_account_info();
     let account_data = account_info.data.borrow();
     // loads the active guard set
-    let guard_set = match CandyGuardData::active_set(&account_data[DATA_OFFSET..], label) {
+    let guard_set = match CandyGuardData::active_set(&account_data[DATA_OFFSET..], label.clone()) {
         Ok(guard_set) => guard_set,
         Err(error) => {
             // load the default guard set to look for the bot_tax since errors only occur
@@ -99,7 +114,7 @@ pub fn process_mint(
 
     // validates the required transaction data
 
-    if let Err(error) = validate(ctx) {
+    if let Err(error) = validate(ctx, &guard_set) {
         return process_error(ctx, &guard_set, error);
     }
 
@@ -118,7 +133,7 @@ pub fn process_mint(
         condition.pre_actions(ctx, &guard_set, &mint_args)?;
     }
 
-    cpi_mint(ctx)?;
+    cpi_mint(ctx, label)?;
 
     for condition in &conditions {
         condition.post_actions(ctx, &guard_set, &mint_args)?;
@@ -138,14 +153,33 @@ fn process_error(ctx: &EvaluationContext, guard_set: &GuardSet, error: Error) ->
 }
 
 /// Performs a validation of the transaction before executing the guards.
-fn validate(ctx: &EvaluationContext) -> Result<()> {
+fn validate(ctx: &EvaluationContext, guard_set: &GuardSet) -> Result<()> {
+    // a specific item can only be requested when the range of items is restricted
+    if ctx.requested_index.is_some() && guard_set.item_range.is_none() {
+        return err!(CandyGuardError::ItemRangeRequired);
+    }
+
     if !cmp_pubkeys(
         &ctx.accounts.collection_mint.key(),
         &ctx.accounts.candy_machine.collection_mint,
     ) {
         return err!(CandyGuardError::CollectionKeyMismatch);
     }
-    if !cmp_pubkeys(
+    // candy machines without a collection do not use the collection accounts
+    if !ctx.accounts.candy_machine.has_collection() {
+        return Ok(());
+    }
+    // Core collections do not have a metadata account
+    if ctx.accounts.candy_machine.token_standard == TOKEN_STANDARD_CORE {
+        if !cmp_pubkeys(ctx.accounts.collection_mint.owner, &mpl_core::ID) {
+            return err!(CandyGuardError::IncorrectOwner);
+        }
+    } else if ctx.accounts.candy_machine.token_standard == TOKEN_STANDARD_TOKEN_2022 {
+        // Token-2022 collections do not have a metadata account
+        if !cmp_pubkeys(ctx.accounts.collection_mint.owner, &spl_token_2022::ID) {
+            return err!(CandyGuardError::IncorrectOwner);
+        }
+    } else if !cmp_pubkeys(
         ctx.accounts.collection_metadata.owner,
         &mpl_token_metadata::ID,
     ) {
@@ -156,11 +190,15 @@ fn validate(ctx: &EvaluationContext) -> Result<()> {
 }
 
 /// Send a mint transaction to the candy machine.
-fn cpi_mint(ctx: &EvaluationContext) -> Result<()> {
+///
+/// The group label is recorded on the mint receipt when a mint receipt account is present.
+/// Remaining accounts not used by the guards (e.g., config line page accounts or master
+/// edition accounts) are forwarded to the candy machine.
+fn cpi_mint(ctx: &EvaluationContext, label: Option<String>) -> Result<()> {
     let candy_guard = &ctx.accounts.candy_guard;
 
     // candy machine mint instruction accounts
-    let mint_accounts = Box::new(mpl_candy_machine_core::cpi::accounts::MintV2 {
+    let mint_accounts = Box::new(mpl_candy_machine_core::cpi::accounts::MintV3 {
         candy_machine: ctx.accounts.candy_machine.to_account_info(),
         authority_pda: ctx.accounts.candy_machine_authority_pda.clone(),
         mint_authority: candy_guard.to_account_info(),
@@ -185,21 +223,58 @@ fn cpi_mint(ctx: &EvaluationContext) -> Result<()> {
         recent_slothashes: ctx.accounts.recent_slothashes.clone(),
         authorization_rules_program: ctx.accounts.authorization_rules_program.clone(),
         authorization_rules: ctx.accounts.authorization_rules.clone(),
+        mpl_core_program: ctx.accounts.mpl_core_program.clone(),
+        merkle_tree: ctx.accounts.merkle_tree.clone(),
+        tree_config: ctx.accounts.tree_config.clone(),
+        bubblegum_signer: ctx.accounts.bubblegum_signer.clone(),
+        bubblegum_program: ctx.accounts.bubblegum_program.clone(),
+        log_wrapper: ctx.accounts.log_wrapper.clone(),
+        compression_program: ctx.accounts.compression_program.clone(),
+        spl_token_2022_program: ctx.accounts.spl_token_2022_program.clone(),
+        mint_request: ctx.accounts.mint_request.clone(),
+        mint_receipt: ctx.accounts.mint_receipt.clone(),
     });
 
-    let mint_infos = mint_accounts.to_account_infos();
+    let mut mint_infos = mint_accounts.to_account_infos();
     let mut mint_metas = mint_accounts.to_account_metas(None);
 
     mint_metas.iter_mut().for_each(|account_meta| {
         if account_meta.pubkey == ctx.accounts.nft_mint.key() {
             account_meta.is_signer = ctx.accounts.nft_mint.is_signer;
+        } else if account_meta.pubkey == ctx.accounts.minter.key() {
+            // the minter signs to approve the delegate of soulbound items
+            account_meta.is_signer = ctx.accounts.minter.is_signer;
+        } else if account_meta.pubkey == ctx.accounts.collection_mint.key() {
+            // Core and Token-2022 collections are updated when a new item is minted
+            account_meta.is_writable = ctx.accounts.collection_mint.is_writable;
         }
     });
 
+    // accounts not used by the guards (config line pages or master edition accounts)
+    // are forwarded to the candy machine
+    for account in ctx.accounts.remaining.iter().skip(ctx.account_cursor) {
+        mint_metas.push(if account.is_writable {
+            AccountMeta::new(account.key(), false)
+        } else {
+            AccountMeta::new_readonly(account.key(), false)
+        });
+        mint_infos.push(account.clone());
+    }
+
+    let data = mpl_candy_machine_core::instruction::MintV3 {
+        args: MintArgs {
+            index: ctx.requested_index,
+            config_line: ctx.config_line.clone(),
+            // the group label is recorded on the mint receipt (if any)
+            group: label,
+        },
+    }
+    .data();
+
     let mint_ix = Instruction {
         program_id: mpl_candy_machine_core::ID,
         accounts: mint_metas,
-        data: mpl_candy_machine_core::instruction::MintV2::DISCRIMINATOR.to_vec(),
+        data,
     };
 
     // PDA signer for the transaction
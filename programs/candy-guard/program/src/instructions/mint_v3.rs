@@ -0,0 +1,321 @@
+use std::collections::BTreeMap;
+
+use anchor_lang::{prelude::*, solana_program::sysvar};
+use mpl_candy_machine_core::{CandyMachine, ConfigLineProof};
+
+use crate::{
+    guards::EvaluationContext,
+    state::{CandyGuard, SEED},
+};
+
+use super::{mint_v2::process_mint, AssociatedToken, MintAccounts, Token};
+
+pub fn mint_v3<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintV3<'info>>,
+    index: Option<u32>,
+    config_line: Option<ConfigLineProof>,
+    mint_args: Vec<u8>,
+    label: Option<String>,
+) -> Result<()> {
+    let accounts = MintAccounts {
+        candy_guard: &ctx.accounts.candy_guard,
+        candy_machine: &ctx.accounts.candy_machine,
+        candy_machine_authority_pda: ctx.accounts.candy_machine_authority_pda.to_account_info(),
+        _candy_machine_program: ctx.accounts.candy_machine_program.to_account_info(),
+        collection_delegate_record: ctx.accounts.collection_delegate_record.to_account_info(),
+        collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+        collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+        collection_mint: ctx.accounts.collection_mint.to_account_info(),
+        collection_update_authority: ctx.accounts.collection_update_authority.to_account_info(),
+        nft_master_edition: ctx.accounts.nft_master_edition.to_account_info(),
+        nft_metadata: ctx.accounts.nft_metadata.to_account_info(),
+        nft_mint: ctx.accounts.nft_mint.to_account_info(),
+        nft_mint_authority: ctx.accounts.nft_mint_authority.to_account_info(),
+        payer: ctx.accounts.payer.to_account_info(),
+        minter: ctx.accounts.minter.to_account_info(),
+        recent_slothashes: ctx.accounts.recent_slothashes.to_account_info(),
+        spl_ata_program: ctx
+            .accounts
+            .spl_ata_program
+            .as_ref()
+            .map(|spl_ata_program| spl_ata_program.to_account_info()),
+        spl_token_program: ctx.accounts.spl_token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+        token: ctx
+            .accounts
+            .token
+            .as_ref()
+            .map(|token| token.to_account_info()),
+        token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+        token_record: ctx
+            .accounts
+            .token_record
+            .as_ref()
+            .map(|token_record| token_record.to_account_info()),
+        remaining: ctx.remaining_accounts,
+        authorization_rules_program: ctx
+            .accounts
+            .authorization_rules_program
+            .as_ref()
+            .map(|authorization_rules_program| authorization_rules_program.to_account_info()),
+        authorization_rules: ctx
+            .accounts
+            .authorization_rules
+            .as_ref()
+            .map(|authorization_rules| authorization_rules.to_account_info()),
+        mpl_core_program: ctx
+            .accounts
+            .mpl_core_program
+            .as_ref()
+            .map(|mpl_core_program| mpl_core_program.to_account_info()),
+        merkle_tree: ctx
+            .accounts
+            .merkle_tree
+            .as_ref()
+            .map(|merkle_tree| merkle_tree.to_account_info()),
+        tree_config: ctx
+            .accounts
+            .tree_config
+            .as_ref()
+            .map(|tree_config| tree_config.to_account_info()),
+        bubblegum_signer: ctx
+            .accounts
+            .bubblegum_signer
+            .as_ref()
+            .map(|bubblegum_signer| bubblegum_signer.to_account_info()),
+        bubblegum_program: ctx
+            .accounts
+            .bubblegum_program
+            .as_ref()
+            .map(|bubblegum_program| bubblegum_program.to_account_info()),
+        log_wrapper: ctx
+            .accounts
+            .log_wrapper
+            .as_ref()
+            .map(|log_wrapper| log_wrapper.to_account_info()),
+        compression_program: ctx
+            .accounts
+            .compression_program
+            .as_ref()
+            .map(|compression_program| compression_program.to_account_info()),
+        spl_token_2022_program: ctx
+            .accounts
+            .spl_token_2022_program
+            .as_ref()
+            .map(|spl_token_2022_program| spl_token_2022_program.to_account_info()),
+        mint_request: ctx
+            .accounts
+            .mint_request
+            .as_ref()
+            .map(|mint_request| mint_request.to_account_info()),
+        mint_receipt: ctx
+            .accounts
+            .mint_receipt
+            .as_ref()
+            .map(|mint_receipt| mint_receipt.to_account_info()),
+    };
+
+    // evaluation context for this transaction
+    let mut ctx = EvaluationContext {
+        accounts,
+        account_cursor: 0,
+        args_cursor: 0,
+        indices: BTreeMap::new(),
+        requested_index: index,
+        config_line,
+    };
+
+    process_mint(&mut ctx, mint_args, label)
+}
+
+/// Mint an NFT with the mint arguments of the candy machine.
+#[derive(Accounts)]
+pub struct MintV3<'info> {
+    /// Candy Guard account.
+    #[account(seeds = [SEED, candy_guard.base.key().as_ref()], bump = candy_guard.bump)]
+    candy_guard: Account<'info, CandyGuard>,
+
+    /// Candy Machine program account.
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(address = mpl_candy_machine_core::id())]
+    candy_machine_program: AccountInfo<'info>,
+
+    /// Candy machine account.
+    #[account(mut, constraint = candy_guard.key() == candy_machine.mint_authority)]
+    candy_machine: Box<Account<'info, CandyMachine>>,
+
+    /// Candy Machine authority account.
+    ///
+    /// CHECK: account constraints checked in CPI
+    #[account(mut)]
+    candy_machine_authority_pda: UncheckedAccount<'info>,
+
+    /// Payer for the mint (SOL) fees.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// Minter account for validation and non-SOL fees.
+    #[account(mut)]
+    minter: Signer<'info>,
+
+    /// Mint account of the NFT. The account will be initialized if necessary.
+    ///
+    /// Must be a signer if:
+    ///   * the nft_mint account does not exist.
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    nft_mint: UncheckedAccount<'info>,
+
+    /// Mint authority of the NFT before the authority gets transfer to the master edition account.
+    ///
+    /// If nft_mint account exists:
+    ///   * it must match the mint authority of nft_mint.
+    nft_mint_authority: Signer<'info>,
+
+    /// Metadata account of the NFT. This account must be uninitialized.
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    nft_metadata: UncheckedAccount<'info>,
+
+    /// Master edition account of the NFT. The account will be initialized if necessary.
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    nft_master_edition: UncheckedAccount<'info>,
+
+    /// Destination token account (required for pNFT).
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    token: Option<UncheckedAccount<'info>>,
+
+    /// Token record (required for pNFT).
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    token_record: Option<UncheckedAccount<'info>>,
+
+    /// Collection authority or metadata delegate record.
+    ///
+    /// CHECK: account checked in CPI
+    collection_delegate_record: UncheckedAccount<'info>,
+
+    /// Mint account of the collection NFT.
+    ///
+    /// CHECK: account checked in CPI
+    collection_mint: UncheckedAccount<'info>,
+
+    /// Metadata account of the collection NFT.
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    collection_metadata: UncheckedAccount<'info>,
+
+    /// Master edition account of the collection NFT.
+    ///
+    /// CHECK: account checked in CPI
+    collection_master_edition: UncheckedAccount<'info>,
+
+    /// Update authority of the collection NFT.
+    ///
+    /// CHECK: account checked in CPI
+    collection_update_authority: UncheckedAccount<'info>,
+
+    /// Token Metadata program.
+    ///
+    /// CHECK: account checked in CPI
+    #[account(address = mpl_token_metadata::ID)]
+    token_metadata_program: UncheckedAccount<'info>,
+
+    /// SPL Token program.
+    spl_token_program: Program<'info, Token>,
+
+    /// SPL Associated Token program.
+    spl_ata_program: Option<Program<'info, AssociatedToken>>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+
+    /// Instructions sysvar account.
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(address = sysvar::instructions::id())]
+    sysvar_instructions: UncheckedAccount<'info>,
+
+    /// SlotHashes sysvar cluster data.
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(address = sysvar::slot_hashes::id())]
+    recent_slothashes: UncheckedAccount<'info>,
+
+    /// Token Authorization Rules program.
+    ///
+    /// CHECK: account checked in CPI
+    #[account(address = mpl_candy_machine_core::constants::MPL_TOKEN_AUTH_RULES_PROGRAM)]
+    authorization_rules_program: Option<UncheckedAccount<'info>>,
+
+    /// Token Authorization rules account for the collection metadata (if any).
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(owner = mpl_candy_machine_core::constants::MPL_TOKEN_AUTH_RULES_PROGRAM)]
+    authorization_rules: Option<UncheckedAccount<'info>>,
+
+    /// MPL Core program (required for Core assets).
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(address = mpl_core::ID)]
+    mpl_core_program: Option<UncheckedAccount<'info>>,
+
+    /// Merkle tree account (required for compressed NFTs).
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    merkle_tree: Option<UncheckedAccount<'info>>,
+
+    /// Tree config account of the merkle tree (required for compressed NFTs).
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    tree_config: Option<UncheckedAccount<'info>>,
+
+    /// Bubblegum collection signer (required for compressed NFTs).
+    ///
+    /// CHECK: account checked in CPI
+    bubblegum_signer: Option<UncheckedAccount<'info>>,
+
+    /// Bubblegum program (required for compressed NFTs).
+    ///
+    /// CHECK: account checked in CPI
+    bubblegum_program: Option<UncheckedAccount<'info>>,
+
+    /// SPL Noop program (required for compressed NFTs).
+    ///
+    /// CHECK: account checked in CPI
+    log_wrapper: Option<UncheckedAccount<'info>>,
+
+    /// SPL Account Compression program (required for compressed NFTs).
+    ///
+    /// CHECK: account checked in CPI
+    compression_program: Option<UncheckedAccount<'info>>,
+
+    /// SPL Token-2022 program (required for Token-2022 NFTs).
+    ///
+    /// CHECK: account checked in CPI
+    spl_token_2022_program: Option<UncheckedAccount<'info>>,
+
+    /// Mint request account (required when commit-reveal is enabled).
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    mint_request: Option<UncheckedAccount<'info>>,
+
+    /// Mint receipt account (required when mint receipts are enabled).
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    mint_receipt: Option<UncheckedAccount<'info>>,
+}
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 pub use initialize::*;
 pub use mint::*;
 pub use mint_v2::*;
+pub use mint_v3::*;
 use mpl_candy_machine_core::CandyMachine;
 pub use route::*;
 pub use set_authority::*;
@@ -15,6 +16,7 @@ use crate::state::CandyGuard;
 pub mod initialize;
 pub mod mint;
 pub mod mint_v2;
+pub mod mint_v3;
 pub mod route;
 pub mod set_authority;
 pub mod unwrap;
@@ -50,6 +52,16 @@ pub(crate) struct MintAccounts<'b, 'c, 'info> {
     pub(crate) remaining: &'c [AccountInfo<'info>],
     pub(crate) authorization_rules_program: Option<AccountInfo<'info>>,
     pub(crate) authorization_rules: Option<AccountInfo<'info>>,
+    pub(crate) mpl_core_program: Option<AccountInfo<'info>>,
+    pub(crate) merkle_tree: Option<AccountInfo<'info>>,
+    pub(crate) tree_config: Option<AccountInfo<'info>>,
+    pub(crate) bubblegum_signer: Option<AccountInfo<'info>>,
+    pub(crate) bubblegum_program: Option<AccountInfo<'info>>,
+    pub(crate) log_wrapper: Option<AccountInfo<'info>>,
+    pub(crate) compression_program: Option<AccountInfo<'info>>,
+    pub(crate) spl_token_2022_program: Option<AccountInfo<'info>>,
+    pub(crate) mint_request: Option<AccountInfo<'info>>,
+    pub(crate) mint_receipt: Option<AccountInfo<'info>>,
 }
 
 #[derive(Debug, Clone)]
@@ -1,6 +1,7 @@
 #![allow(clippy::result_large_err)]
 
 use anchor_lang::prelude::*;
+use mpl_candy_machine_core::ConfigLineProof;
 
 use instructions::*;
 
@@ -39,6 +40,20 @@ pub mod candy_guard {
         instructions::mint_v2(ctx, mint_args, label)
     }
 
+    /// Mint an NFT from a candy machine wrapped in the candy guard, with the mint
+    /// arguments of the candy machine: the `index` of a specific item to mint and the
+    /// `config_line` with its merkle proof. Requesting an item requires the item range
+    /// guard on the active guard set.
+    pub fn mint_v3<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintV3<'info>>,
+        index: Option<u32>,
+        config_line: Option<ConfigLineProof>,
+        mint_args: Vec<u8>,
+        label: Option<String>,
+    ) -> Result<()> {
+        instructions::mint_v3(ctx, index, config_line, mint_args, label)
+    }
+
     /// Route the transaction to a guard instruction.
     pub fn route<'info>(
         ctx: Context<'_, '_, '_, 'info, Route<'info>>,
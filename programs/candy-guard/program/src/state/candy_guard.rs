@@ -55,6 +55,7 @@ pub struct CandyGuard {
     // 19) program gate
     // 20) allocation
     // 21) token2022 payment
+    // 22) item range
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -116,6 +117,8 @@ pub struct GuardSet {
     pub allocation: Option<Allocation>,
     /// Token2022 payment guard (set the price for the mint in spl-token-2022 amount).
     pub token2022_payment: Option<Token2022Payment>,
+    /// Item range guard (restricts the items that can be requested).
+    pub item_range: Option<ItemRange>,
 }
 
 /// Available guard types.
@@ -142,6 +145,7 @@ pub enum GuardType {
     ProgramGate,
     Allocation,
     Token2022Payment,
+    ItemRange,
 }
 
 impl GuardType {
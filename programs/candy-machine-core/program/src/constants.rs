@@ -6,6 +6,8 @@ use solana_program::{pubkey, pubkey::Pubkey};
 pub const MPL_TOKEN_AUTH_RULES_PROGRAM: Pubkey =
     pubkey!("auth9SigNpDKz4sJJ1DfCTuZrZNSAgh9sFD3rboVmgg");
 
+pub const CANDY_GUARD_PROGRAM: Pubkey = pubkey!("Guard1JwRhJkVH6XZhzoYxeBVQe872VH6QggF4BWmS9g");
+
 // Empty value used for string padding.
 pub const NULL_STRING: &str = "\0";
 
@@ -21,6 +23,16 @@ pub const EMPTY_STR: &str = "";
 // Seed used to derive the authority PDA address.
 pub const AUTHORITY_SEED: &str = "candy_machine";
 
+// Seed used to derive the mint request PDA address.
+pub const MINT_REQUEST_SEED: &str = "mint_request";
+
+// Seed used to derive the mint receipt PDA address.
+pub const MINT_RECEIPT_SEED: &str = "mint_receipt";
+
+// Maximum length of the group label stored on a mint receipt (same as the maximum
+// length of a candy guard group label).
+pub const MAX_RECEIPT_GROUP_LENGTH: usize = 6;
+
 // Determine the start of the account hidden section.
 pub const HIDDEN_SECTION: usize = 8           // discriminator
     + 8                                       // features
@@ -45,9 +57,128 @@ pub const HIDDEN_SECTION: usize = 8           // discriminator
     + 4 + MAX_URI_LENGTH                      // u32 + max uri length
     + 32; // hash
 
+// Serialized value of the V3 account version.
+pub const ACCOUNT_VERSION_V3: u8 = 2;
+
+// Size of the fields of a V3 candy machine account before the candy machine data.
+pub const HEADER_V3: usize = 8 // discriminator
+    + 1                        // version
+    + 1                        // token standard
+    + 6                        // features
+    + 32                       // authority
+    + 32                       // mint authority
+    + 32                       // collection mint
+    + 8; // items redeemed
+
 // Size of the rule set pubkey.
 pub const RULE_SET_LENGTH: usize = 32;
 
+// Size of the config lines merkle root.
+pub const CONFIG_LINES_ROOT_LENGTH: usize = 32;
+
+// Token standard used to mint MPL Core assets. The value is outside the range of
+// Token Metadata's `TokenStandard` so both can be stored in the same field.
+pub const TOKEN_STANDARD_CORE: u8 = 100;
+
+// Token standard used to mint compressed NFTs through Bubblegum.
+pub const TOKEN_STANDARD_COMPRESSED: u8 = 101;
+
+// Token standard used to mint Token-2022 NFTs using the metadata pointer and group
+// member extensions.
+pub const TOKEN_STANDARD_TOKEN_2022: u8 = 102;
+
+// Token standard used to print editions of a master edition held by the authority PDA.
+pub const TOKEN_STANDARD_PRINT_EDITION: u8 = 103;
+
+// Token-2022 instruction tag of the group member pointer extension.
+pub const GROUP_MEMBER_POINTER_EXTENSION: u8 = 41;
+
+// Size of the group member pointer extension (type + length + authority + member address).
+pub const GROUP_MEMBER_POINTER_LENGTH: usize = 2 + 2 + 32 + 32;
+
+// Size of the group member extension (type + length + mint + group + member number).
+pub const GROUP_MEMBER_LENGTH: usize = 2 + 2 + 32 + 32 + 8;
+
+// Position of the feature flags in the features array.
+pub const FEATURE_FLAGS_INDEX: usize = 0;
+
+// Feature flag to mint using a commit-reveal request.
+pub const COMMIT_REVEAL_FEATURE: u8 = 0b0000_0001;
+
+// Feature flag to allow minting a specific (minter-selected) item.
+pub const PICK_ITEM_FEATURE: u8 = 0b0000_0010;
+
+// Feature flag to verify config lines against a merkle root instead of storing them.
+pub const MERKLE_CONFIG_LINES_FEATURE: u8 = 0b0000_0100;
+
+// Feature flag to reveal hidden settings items on-chain.
+pub const ONCHAIN_REVEAL_FEATURE: u8 = 0b0000_1000;
+
+// Feature flag to create a receipt for each mint.
+pub const MINT_RECEIPT_FEATURE: u8 = 0b0001_0000;
+
+// Feature flag indicating that the config lines are finalized and can no longer change.
+pub const FINALIZED_FEATURE: u8 = 0b0010_0000;
+
+// Bits of the feature flags holding the URI encoding of the config lines.
+pub const URI_ENCODING_MASK: u8 = 0b1100_0000;
+
+// Position of the URI encoding bits in the feature flags.
+pub const URI_ENCODING_SHIFT: u8 = 6;
+
+// Position of the state flags (pause, config line pages, soulbound and items tracked) in
+// the features array.
+pub const STATE_FLAGS_INDEX: usize = 1;
+
+// State flag to pause minting.
+pub const PAUSED_FLAG: u8 = 0b0000_0001;
+
+// State flag indicating that config lines are stored on page accounts.
+pub const CONFIG_LINE_PAGES_FLAG: u8 = 0b0000_0010;
+
+// State flag to mint soulbound (locked) items.
+pub const SOULBOUND_FLAG: u8 = 0b0000_0100;
+
+// State flag indicating that the loaded bit-mask tracks the items taken (minted or
+// removed) once minting starts.
+pub const ITEMS_TRACKED_FLAG: u8 = 0b0000_1000;
+
+// Position of the (u16) number of pending mint requests in the features array.
+pub const PENDING_REQUESTS_INDEX: usize = 2;
+
+// Position of the (u16) sequence of the next mint request to be revealed in the
+// features array.
+pub const NEXT_REVEAL_INDEX: usize = 4;
+
+// Maximum number of rarity tiers.
+pub const MAX_RARITY_TIERS: usize = 16;
+
+// Size of each stored rarity tier (weight + size + remaining).
+pub const RARITY_TIER_LENGTH: usize = 4 + 4 + 4;
+
+// Maximum number of config line page accounts of a candy machine.
+pub const MAX_CONFIG_LINE_PAGES: usize = 16;
+
+// Size of the stored mint delegate (role + delegate + locked address).
+pub const MINT_DELEGATE_LENGTH: usize = 1 + 32 + 32;
+
+// Size of the stored number of removed config lines.
+pub const ITEMS_REMOVED_LENGTH: usize = 8;
+
+// Size of the stored number of items skipped by the random selection (picked items and
+// removed config lines still on the available mint indices).
+pub const ITEMS_SKIPPED_LENGTH: usize = 4;
+
+// Size of the stored number of tokens minted per item of fungible token standards.
+pub const FUNGIBLE_AMOUNT_LENGTH: usize = 8;
+
+// Number of accounts of each item of a batch mint (owner, mint, metadata, master edition,
+// token, token record and mint receipt).
+pub const BATCH_ITEM_ACCOUNTS: usize = 7;
+
+// Size of each (slot, hash) entry of the SlotHashes sysvar.
+pub const SLOT_HASH_ENTRY_LENGTH: usize = 8 + 32;
+
 pub const SET: u8 = 1;
 
 pub const UNSET: u8 = 0;
@@ -91,4 +91,211 @@ pub enum CandyError {
 
     #[msg("Invalid account version")]
     InvalidAccountVersion,
+
+    #[msg("Missing MPL Core program")]
+    MissingMplCoreProgram,
+
+    #[msg("Missing Bubblegum accounts")]
+    MissingBubblegumAccounts,
+
+    #[msg("Missing SPL Token-2022 program")]
+    MissingSplToken2022Program,
+
+    #[msg("Missing mint request account")]
+    MissingMintRequest,
+
+    #[msg("Invalid mint request account")]
+    InvalidMintRequest,
+
+    #[msg("Mint request cannot be revealed in the same slot")]
+    MintRequestNotReady,
+
+    #[msg("Mint request slot hash is no longer available")]
+    MintRequestExpired,
+
+    #[msg("Mint request can only be closed after it expires")]
+    MintRequestNotExpired,
+
+    #[msg("Invalid rarity tiers")]
+    InvalidRarityTiers,
+
+    #[msg("Can't change rarity tiers after items have begun to be minted")]
+    NoChangingRarityTiersDuringMint,
+
+    #[msg("Pick item mode is not enabled")]
+    PickItemDisabled,
+
+    #[msg("Pick item mode requires random config line selection")]
+    PickItemNotSupported,
+
+    #[msg("Requested item is not available")]
+    ItemNotAvailable,
+
+    #[msg("Config lines are verified using a merkle root")]
+    MerkleConfigLinesEnabled,
+
+    #[msg("Can't change the config lines root after items have begun to be minted")]
+    NoChangingConfigLinesRootDuringMint,
+
+    #[msg("Config lines root cannot be used with loaded config lines")]
+    ConfigLinesAlreadyLoaded,
+
+    #[msg("Missing config line proof")]
+    MissingConfigLineProof,
+
+    #[msg("Invalid config line proof")]
+    InvalidConfigLineProof,
+
+    #[msg("Missing hidden settings")]
+    MissingHiddenSettings,
+
+    #[msg("Hidden settings name or uri must include an index replacement pattern")]
+    HiddenSettingsMissingIndex,
+
+    #[msg("Can't change the on-chain reveal after items have begun to be minted")]
+    NoChangingOnchainRevealDuringMint,
+
+    #[msg("Can't change hidden settings after items have begun to be minted")]
+    CannotChangeHiddenSettings,
+
+    #[msg("Item is not a hidden item of the candy machine")]
+    InvalidRevealItem,
+
+    #[msg("Invalid number of accounts for a batch mint")]
+    InvalidBatchAccounts,
+
+    #[msg("Batch mint is not supported with commit-reveal, merkle config lines, soulbound items or a mint delegate")]
+    BatchMintNotSupported,
+
+    #[msg("Candy machine is paused")]
+    CandyMachinePaused,
+
+    #[msg("Config line is not loaded")]
+    ConfigLineNotLoaded,
+
+    #[msg("Missing mint receipt account")]
+    MissingMintReceipt,
+
+    #[msg("Invalid mint receipt account")]
+    InvalidMintReceipt,
+
+    #[msg("Mint receipt group label exceeds the maximum length")]
+    ReceiptGroupTooLong,
+
+    #[msg("Mint receipts can only be closed after all items are minted")]
+    CandyMachineNotSoldOut,
+
+    #[msg("Can't change config lines settings after items have begun to be minted")]
+    NoChangingConfigLinesDuringMint,
+
+    #[msg("Cannot decrease the length in config lines settings")]
+    CannotDecreaseLength,
+
+    #[msg("Cannot remove config lines that were already added")]
+    CannotRemoveLoadedConfigLines,
+
+    #[msg("Config line was already minted")]
+    ConfigLineAlreadyMinted,
+
+    #[msg("Config lines are finalized and can no longer change")]
+    ConfigLinesFinalized,
+
+    #[msg("Config line URI does not match the URI encoding")]
+    InvalidUriEncoding,
+
+    #[msg("Cannot change the URI length of config lines using a URI encoding")]
+    CannotResizeEncodedUris,
+
+    #[msg("Cannot change the size of the mint indices")]
+    CannotChangeMintIndexSize,
+
+    #[msg("Candy machine data exceeds the space of the account")]
+    CandyMachineDataTooLarge,
+
+    #[msg("Missing config line page account")]
+    MissingConfigLinePage,
+
+    #[msg("Invalid config line page account")]
+    InvalidConfigLinePage,
+
+    #[msg("Config lines must be added to a single page")]
+    ConfigLinesCrossPages,
+
+    #[msg("Operation is not supported when config lines are stored on page accounts")]
+    ConfigLinePagesNotSupported,
+
+    #[msg("Missing master edition accounts")]
+    MissingMasterEditionAccounts,
+
+    #[msg("Mint account does not match the token type of the config line")]
+    FungibleMintMismatch,
+
+    #[msg("Token standard requires a collection")]
+    CollectionRequired,
+
+    #[msg("Soulbound items are only supported for NFTs and Programmable NFTs")]
+    SoulboundNotSupported,
+
+    #[msg("Can't change the soulbound setting once mint has started")]
+    NoChangingSoulboundDuringMint,
+
+    #[msg("NFT owner must sign to mint soulbound items")]
+    SoulboundOwnerNotSigner,
+
+    #[msg("Item was not minted by the candy machine")]
+    InvalidSoulboundItem,
+
+    #[msg("Mint delegates are only supported for Programmable NFTs")]
+    MintDelegateNotSupported,
+
+    #[msg("Soulbound items cannot have a mint delegate")]
+    SoulboundMintDelegateConflict,
+
+    #[msg("Missing mint delegate account")]
+    MissingMintDelegate,
+
+    #[msg("NFT owner must sign to approve the mint delegate")]
+    MintDelegateOwnerNotSigner,
+
+    #[msg("Mint requests must be revealed in the order they were created")]
+    MintRequestOutOfOrder,
+
+    #[msg("Candy machine has pending mint requests")]
+    PendingMintRequests,
+
+    #[msg("On-chain reveal is not enabled")]
+    OnchainRevealNotEnabled,
+
+    #[msg("Missing collection metadata account")]
+    MissingCollectionMetadata,
+
+    #[msg("Master edition mint is not set")]
+    MasterEditionNotSet,
+
+    #[msg("Master edition accounts do not match the master edition mint")]
+    MasterEditionMismatch,
+
+    #[msg("Can't change the master edition after items have begun to be minted")]
+    NoChangingMasterEditionDuringMint,
+
+    #[msg("Fungible amount must be greater than zero")]
+    InvalidFungibleAmount,
+
+    #[msg("Fungible tokens require the item to be known when minting (sequential config lines, hidden settings or a requested index)")]
+    FungibleRandomSelection,
+
+    #[msg("Commit-reveal mints cannot be combined with soulbound items or a mint delegate")]
+    CommitRevealConflict,
+
+    #[msg("Requesting a specific item requires a candy guard as the mint authority")]
+    PickItemRequiresCandyGuard,
+
+    #[msg("Pick item mode cannot be combined with rarity tiers")]
+    PickItemRarityTiersConflict,
+
+    #[msg("Account size can only increase by 10240 bytes per instruction")]
+    AccountGrowthTooLarge,
+
+    #[msg("Minted items are not tracked by the candy machine")]
+    ItemsNotTracked,
 }
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::CandyMachineData;
+
+/// Emitted when an item is minted.
+#[event]
+pub struct MintEvent {
+    /// Candy machine account.
+    pub candy_machine: Pubkey,
+    /// Mint (or asset) account of the item.
+    pub mint: Pubkey,
+    /// Owner of the item.
+    pub owner: Pubkey,
+    /// Index of the config line minted.
+    pub index: u32,
+    /// Number of items redeemed after the mint.
+    pub items_redeemed: u64,
+    /// Name of the item.
+    pub name: String,
+    /// URI of the item.
+    pub uri: String,
+}
+
+/// Emitted when config lines are added to the candy machine.
+#[event]
+pub struct AddConfigLinesEvent {
+    /// Candy machine account.
+    pub candy_machine: Pubkey,
+    /// Index of the first config line added.
+    pub index: u32,
+    /// Number of config lines added.
+    pub count: u32,
+}
+
+/// Emitted when the config lines of the candy machine are finalized.
+#[event]
+pub struct FinalizeEvent {
+    /// Candy machine account.
+    pub candy_machine: Pubkey,
+    /// Commitment of the config lines.
+    pub commitment: [u8; 32],
+}
+
+/// Emitted when the candy machine data is updated.
+#[event]
+pub struct UpdateEvent {
+    /// Candy machine account.
+    pub candy_machine: Pubkey,
+    /// Updated candy machine data.
+    pub data: CandyMachineData,
+}
+
+/// Emitted when the collection of the candy machine is set.
+#[event]
+pub struct SetCollectionEvent {
+    /// Candy machine account.
+    pub candy_machine: Pubkey,
+    /// Previous collection mint.
+    pub old_collection_mint: Pubkey,
+    /// New collection mint.
+    pub new_collection_mint: Pubkey,
+}
+
+/// Emitted when the token standard of the candy machine is set.
+#[event]
+pub struct SetTokenStandardEvent {
+    /// Candy machine account.
+    pub candy_machine: Pubkey,
+    /// Previous token standard.
+    pub old_token_standard: u8,
+    /// New token standard.
+    pub new_token_standard: u8,
+}
+
+/// Emitted when the candy machine account is closed.
+#[event]
+pub struct WithdrawEvent {
+    /// Candy machine account.
+    pub candy_machine: Pubkey,
+    /// Authority receiving the rent.
+    pub authority: Pubkey,
+    /// Amount of lamports withdrawn.
+    pub lamports: u64,
+}
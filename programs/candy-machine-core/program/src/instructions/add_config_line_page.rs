@@ -0,0 +1,140 @@
+use anchor_lang::{prelude::*, Discriminator};
+use mpl_utils::resize_or_reallocate_account_raw;
+use solana_program::pubkey::PUBKEY_BYTES;
+
+use crate::{
+    constants::{FINALIZED_FEATURE, MAX_CONFIG_LINE_PAGES, MERKLE_CONFIG_LINES_FEATURE},
+    get_config_count, CandyError, CandyMachine, ConfigLinePage,
+};
+
+pub fn add_config_line_page(ctx: Context<AddConfigLinePage>) -> Result<()> {
+    let candy_machine = &mut ctx.accounts.candy_machine;
+
+    // config lines can no longer change once finalized
+    if candy_machine.is_feature_enabled(FINALIZED_FEATURE) {
+        return err!(CandyError::ConfigLinesFinalized);
+    }
+
+    // hidden settings candies do not store config lines
+    if candy_machine.data.hidden_settings.is_some() {
+        return err!(CandyError::HiddenSettingsDoNotHaveConfigLines);
+    }
+
+    if candy_machine.data.config_line_settings.is_none() {
+        return err!(CandyError::MissingConfigLinesSettings);
+    }
+
+    // config lines are supplied at mint time when using a merkle root
+    if candy_machine.is_feature_enabled(MERKLE_CONFIG_LINES_FEATURE) {
+        return err!(CandyError::MerkleConfigLinesEnabled);
+    }
+
+    // there is nothing to store on a page when config lines are empty
+    let line_size = candy_machine.data.get_config_line_size();
+
+    if line_size == 0 {
+        return err!(CandyError::InvalidConfigLinePage);
+    }
+
+    let candy_machine_info = candy_machine.to_account_info();
+    let mut new_length = candy_machine_info.data_len();
+
+    // (1) the config lines section is removed from the candy machine account when the
+    // first page is added
+
+    if !candy_machine.has_config_line_pages() {
+        if get_config_count(&candy_machine_info.data.borrow())? > 0 {
+            return err!(CandyError::ConfigLinesAlreadyLoaded);
+        }
+
+        let old_space = candy_machine.get_space()?;
+        candy_machine.set_config_line_pages(true);
+        let lines_length = old_space - candy_machine.get_space()?;
+        let lines_start = candy_machine.get_hidden_section()? + 4;
+
+        // the loaded bit-mask, mint indices and the remaining bytes are moved together
+        let mut account_data = candy_machine_info.data.borrow_mut();
+        account_data.copy_within(lines_start + lines_length..new_length, lines_start);
+        account_data[new_length - lines_length..new_length].fill(0);
+
+        new_length -= lines_length;
+    }
+
+    // (2) links the page to the candy machine
+
+    let pages = candy_machine.get_config_line_pages(&candy_machine_info.data.borrow())?;
+    let page = pages.len();
+    let lines_per_page = candy_machine.get_config_lines_per_page();
+    let items_available = candy_machine.data.items_available as usize;
+
+    // pages are only added until they cover all items available
+    if page >= MAX_CONFIG_LINE_PAGES || page * lines_per_page >= items_available {
+        return err!(CandyError::InvalidConfigLinePage);
+    }
+
+    let page_lines = lines_per_page.min(items_available - page * lines_per_page);
+    let page_info = ctx.accounts.config_line_page.to_account_info();
+
+    if page_info.data_len() < ConfigLinePage::HEADER + page_lines * line_size {
+        return err!(CandyError::InvalidConfigLinePage);
+    }
+
+    let mut page_data = page_info.try_borrow_mut_data()?;
+    page_data[..8].copy_from_slice(&ConfigLinePage::discriminator());
+    page_data[8..ConfigLinePage::HEADER].copy_from_slice(candy_machine.key().as_ref());
+
+    let position = candy_machine.get_config_line_pages_position()?;
+    let required_length = position + 1 + (page + 1) * PUBKEY_BYTES;
+
+    if new_length != candy_machine_info.data_len() || new_length < required_length {
+        msg!(
+            "Resizing account from {} to {} bytes",
+            candy_machine_info.data_len(),
+            new_length.max(required_length)
+        );
+
+        resize_or_reallocate_account_raw(
+            &candy_machine_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            new_length.max(required_length),
+        )?;
+    }
+
+    let mut account_data = candy_machine_info.data.borrow_mut();
+    let page_position = position + 1 + page * PUBKEY_BYTES;
+
+    account_data[position] = (page + 1) as u8;
+    account_data[page_position..page_position + PUBKEY_BYTES]
+        .copy_from_slice(page_info.key.as_ref());
+
+    Ok(())
+}
+
+/// Adds a page account to store config lines of a candy machine.
+#[derive(Accounts)]
+pub struct AddConfigLinePage<'info> {
+    /// Candy Machine account.
+    #[account(mut, has_one = authority)]
+    candy_machine: Account<'info, CandyMachine>,
+
+    /// Candy Machine authority.
+    authority: Signer<'info>,
+
+    /// Config line page account (must be pre-allocated but zero content).
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(
+        zero,
+        rent_exempt = skip,
+        constraint = config_line_page.to_account_info().owner == __program_id
+    )]
+    config_line_page: UncheckedAccount<'info>,
+
+    /// Payer of the transaction.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+}
@@ -1,10 +1,12 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::HIDDEN_SECTION,
+    constants::{FINALIZED_FEATURE, MERKLE_CONFIG_LINES_FEATURE},
+    events::AddConfigLinesEvent,
     get_config_count,
-    state::{CandyMachine, ConfigLine},
-    utils::fixed_length_string,
+    instructions::mint_v2::{get_config_line_page, is_item_available},
+    state::{CandyMachine, ConfigLine, ConfigLinePage, UriEncoding},
+    utils::{decode_uri, fixed_length_string},
     CandyError,
 };
 
@@ -14,6 +16,11 @@ pub fn add_config_lines(
     config_lines: Vec<ConfigLine>,
 ) -> Result<()> {
     let candy_machine = &mut ctx.accounts.candy_machine;
+
+    // config lines can no longer change once finalized
+    if candy_machine.is_feature_enabled(FINALIZED_FEATURE) {
+        return err!(CandyError::ConfigLinesFinalized);
+    }
     let account_info = candy_machine.to_account_info();
     // mutable reference to the account data (config lines are written in the
     // 'hidden' section of the data array)
@@ -38,39 +45,94 @@ pub fn add_config_lines(
         return err!(CandyError::HiddenSettingsDoNotHaveConfigLines);
     }
 
+    // config lines are supplied at mint time when using a merkle root
+    if candy_machine.is_feature_enabled(MERKLE_CONFIG_LINES_FEATURE) {
+        return err!(CandyError::MerkleConfigLinesEnabled);
+    }
+
     let config_line = if let Some(config_line) = &candy_machine.data.config_line_settings {
         config_line
     } else {
         return err!(CandyError::MissingConfigLinesSettings);
     };
 
+    // once the mint starts, only config lines not yet minted can be replaced
+    if candy_machine.has_started(&data)? {
+        for position in index..total {
+            if !is_item_available(candy_machine, &data, position)? {
+                return err!(CandyError::ConfigLineAlreadyMinted);
+            }
+        }
+    }
+
     let name_length = config_line.name_length as usize;
     let uri_length = config_line.uri_length as usize;
     let config_line_length = name_length + uri_length;
+    let uri_encoding = candy_machine.uri_encoding();
+    let hidden_section = candy_machine.get_hidden_section()?;
 
     // both name and uri can be empty when are using a replacement variable; there is
     // still a need to call the add_config_lines so their indices are written on the
     // account for the random index generation
     if config_line_length > 0 {
-        let mut position = HIDDEN_SECTION + 4 + (index as usize) * config_line_length;
+        let mut page_data;
+
+        // config lines are written on the page account of the range when using pages
+        let (line_data, mut position): (&mut [u8], usize) = if candy_machine.has_config_line_pages()
+        {
+            let lines_per_page = candy_machine.get_config_lines_per_page();
+            let page = index as usize / lines_per_page;
+
+            if (total as usize - 1) / lines_per_page != page {
+                return err!(CandyError::ConfigLinesCrossPages);
+            }
+
+            page_data = get_config_line_page(candy_machine, &data, ctx.remaining_accounts, page)?
+                .try_borrow_mut_data()?;
+            let position =
+                ConfigLinePage::HEADER + (index as usize % lines_per_page) * config_line_length;
+
+            // the last page might not have space for all lines of a page when the
+            // number of items available increases after it was added
+            if page_data.len() < position + config_lines.len() * config_line_length {
+                return err!(CandyError::InvalidConfigLinePage);
+            }
+
+            (&mut page_data, position)
+        } else {
+            (
+                &mut data,
+                hidden_section + 4 + (index as usize) * config_line_length,
+            )
+        };
 
         for line in &config_lines {
             if name_length > 0 {
                 let name = fixed_length_string(line.name.clone(), name_length)?;
                 let name_bytes = name.as_bytes();
 
-                let name_slice: &mut [u8] = &mut data[position..position + name_length];
+                let name_slice: &mut [u8] = &mut line_data[position..position + name_length];
                 name_slice.copy_from_slice(name_bytes);
 
                 position += name_length;
             }
 
             if uri_length > 0 {
-                let uri = fixed_length_string(line.uri.clone(), uri_length)?;
-                let uri_bytes = uri.as_bytes();
+                let uri_slice: &mut [u8] = &mut line_data[position..position + uri_length];
 
-                let uri_slice: &mut [u8] = &mut data[position..position + uri_length];
-                uri_slice.copy_from_slice(uri_bytes);
+                if uri_encoding == UriEncoding::Utf8 {
+                    let uri = fixed_length_string(line.uri.clone(), uri_length)?;
+                    uri_slice.copy_from_slice(uri.as_bytes());
+                } else {
+                    // encoded URIs are stored as raw bytes, which must fill the URI length
+                    let uri_bytes = decode_uri(uri_encoding, &line.uri)?;
+
+                    if uri_bytes.len() != uri_length {
+                        return err!(CandyError::InvalidUriEncoding);
+                    }
+
+                    uri_slice.copy_from_slice(&uri_bytes);
+                }
 
                 position += uri_length;
             }
@@ -83,8 +145,10 @@ pub fn add_config_lines(
     // line is added for the first time (when updating a config line, the index is not added again)
 
     // bit-mask
-    let bit_mask_start =
-        HIDDEN_SECTION + 4 + (candy_machine.data.items_available as usize) * config_line_length;
+    let bit_mask_start = hidden_section
+        + 4
+        + (candy_machine.data.items_available as usize)
+            * candy_machine.get_stored_config_line_size();
     // (unordered) indices for the mint
     let indices_start = bit_mask_start
         + (candy_machine
@@ -93,6 +157,7 @@ pub fn add_config_lines(
             .checked_div(8)
             .ok_or(CandyError::NumericalOverflowError)?
             + 1) as usize;
+    let index_size = candy_machine.get_mint_index_size();
 
     // holds the total number of config lines
     let mut count = get_config_count(&data)?;
@@ -125,9 +190,9 @@ pub fn add_config_lines(
 
         if current_value != data[byte_position] {
             // add the new index to the mint indices vec
-            let index_position = indices_start + position * 4;
-            data[index_position..index_position + 4]
-                .copy_from_slice(&u32::to_le_bytes(position as u32));
+            let index_position = indices_start + position * index_size;
+            data[index_position..index_position + index_size]
+                .copy_from_slice(&u32::to_le_bytes(position as u32)[..index_size]);
 
             count = count
                 .checked_add(1)
@@ -142,12 +207,26 @@ pub fn add_config_lines(
     }
 
     // updates the config lines count
-    data[HIDDEN_SECTION..HIDDEN_SECTION + 4].copy_from_slice(&(count as u32).to_le_bytes());
+    data[hidden_section..hidden_section + 4].copy_from_slice(&(count as u32).to_le_bytes());
+
+    // once all config lines are loaded, the bit-mask keeps track of the items taken
+    if count as u64 == candy_machine.data.items_available && !candy_machine.has_started(&data)? {
+        candy_machine.set_items_tracked(true);
+    }
+
+    emit!(AddConfigLinesEvent {
+        candy_machine: candy_machine.key(),
+        index,
+        count: config_lines.len() as u32,
+    });
 
     Ok(())
 }
 
 /// Add multiple config lines to a candy machine.
+///
+/// When config lines are stored on page accounts, the page account of the config lines
+/// is provided as a remaining account.
 #[derive(Accounts)]
 pub struct AddConfigLines<'info> {
     /// Candy Machine account.
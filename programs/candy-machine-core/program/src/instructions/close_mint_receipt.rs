@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{CandyError, CandyMachine, MintReceipt};
+
+pub fn close_mint_receipt(ctx: Context<CloseMintReceipt>) -> Result<()> {
+    let candy_machine = &ctx.accounts.candy_machine;
+
+    if candy_machine.get_items_remaining(&candy_machine.to_account_info().data.borrow())? > 0 {
+        return err!(CandyError::CandyMachineNotSoldOut);
+    }
+
+    Ok(())
+}
+
+/// Closes a mint receipt.
+#[derive(Accounts)]
+pub struct CloseMintReceipt<'info> {
+    /// Candy Machine account.
+    #[account(has_one = authority)]
+    candy_machine: Account<'info, CandyMachine>,
+
+    /// Candy Machine authority.
+    authority: Signer<'info>,
+
+    /// Mint receipt account.
+    #[account(mut, close = mint_receipt_payer, has_one = candy_machine)]
+    mint_receipt: Account<'info, MintReceipt>,
+
+    /// Payer of the mint receipt, which receives the rent of the receipt account.
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(mut, address = mint_receipt.payer)]
+    mint_receipt_payer: UncheckedAccount<'info>,
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use solana_program::sysvar;
+
+use crate::{utils::*, CandyError, CandyMachine, MintRequest};
+
+pub fn close_mint_request(ctx: Context<CloseMintRequest>) -> Result<()> {
+    let candy_machine = &mut ctx.accounts.candy_machine;
+
+    // a request can only be closed when it can no longer be revealed
+    match get_slot_hash_after(
+        &ctx.accounts.recent_slothashes.data.borrow(),
+        ctx.accounts.mint_request.slot,
+    ) {
+        Err(error) if error == CandyError::MintRequestExpired.into() => (),
+        _ => return err!(CandyError::MintRequestNotExpired),
+    }
+
+    // requests are closed in the same order they are revealed; since an expired request
+    // blocks the requests created after it, anyone can close it
+    if ctx.accounts.mint_request.sequence != candy_machine.next_reveal_sequence() {
+        return err!(CandyError::MintRequestOutOfOrder);
+    }
+
+    // releases the mint reserved by the request
+
+    candy_machine.items_redeemed = candy_machine
+        .items_redeemed
+        .checked_sub(1)
+        .ok_or(CandyError::NumericalOverflowError)?;
+
+    candy_machine.advance_reveal_sequence()?;
+
+    Ok(())
+}
+
+/// Closes an expired mint request.
+#[derive(Accounts)]
+pub struct CloseMintRequest<'info> {
+    /// Candy Machine account.
+    #[account(mut)]
+    candy_machine: Account<'info, CandyMachine>,
+
+    /// Mint request account.
+    #[account(mut, close = mint_request_payer, has_one = candy_machine)]
+    mint_request: Account<'info, MintRequest>,
+
+    /// Payer of the mint request, which receives the rent of the request account.
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(mut, address = mint_request.payer)]
+    mint_request_payer: UncheckedAccount<'info>,
+
+    /// SlotHashes sysvar cluster data.
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(address = sysvar::slot_hashes::id())]
+    recent_slothashes: UncheckedAccount<'info>,
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use mpl_utils::resize_or_reallocate_account_raw;
+
+use crate::{
+    constants::{CONFIG_LINES_ROOT_LENGTH, FINALIZED_FEATURE, MERKLE_CONFIG_LINES_FEATURE},
+    events::FinalizeEvent,
+    get_config_count, get_config_lines_commitment,
+    instructions::mint_v2::get_config_line_page,
+    CandyError, CandyMachine, ConfigLinePage,
+};
+
+pub fn finalize<'info>(ctx: Context<'_, '_, '_, 'info, Finalize<'info>>) -> Result<()> {
+    let candy_machine = &mut ctx.accounts.candy_machine;
+
+    if candy_machine.is_feature_enabled(FINALIZED_FEATURE) {
+        return err!(CandyError::ConfigLinesFinalized);
+    }
+
+    let candy_machine_info = candy_machine.to_account_info();
+
+    // the merkle root is already the commitment of the config lines
+    let commitment = if candy_machine.is_feature_enabled(MERKLE_CONFIG_LINES_FEATURE) {
+        get_config_lines_commitment(candy_machine, &candy_machine_info.data.borrow(), &[])?
+    } else {
+        // the commitment must include all config lines
+        let config_count = get_config_count(&candy_machine_info.data.borrow())? as u64;
+
+        if config_count != candy_machine.data.items_available {
+            return err!(CandyError::NotFullyLoaded);
+        }
+
+        let commitment = if candy_machine.has_config_line_pages() {
+            get_paged_commitment(candy_machine, ctx.remaining_accounts)?
+        } else {
+            get_config_lines_commitment(candy_machine, &candy_machine_info.data.borrow(), &[])?
+        };
+
+        // the commitment is stored in the (unused) space of the merkle root
+        let position = candy_machine.get_config_lines_root_position()?;
+        let required_length = position + CONFIG_LINES_ROOT_LENGTH;
+
+        if candy_machine_info.data_len() < required_length {
+            msg!("Allocating space to store the config lines commitment");
+
+            resize_or_reallocate_account_raw(
+                &candy_machine_info,
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                required_length,
+            )?;
+        }
+
+        candy_machine_info.data.borrow_mut()[position..required_length]
+            .copy_from_slice(&commitment);
+
+        commitment
+    };
+
+    candy_machine.set_feature(FINALIZED_FEATURE, true);
+
+    emit!(FinalizeEvent {
+        candy_machine: candy_machine.key(),
+        commitment,
+    });
+
+    Ok(())
+}
+
+/// Returns the commitment of config lines stored on page accounts.
+///
+/// The page accounts of all config lines must be provided, in any order.
+fn get_paged_commitment(
+    candy_machine: &Account<'_, CandyMachine>,
+    config_line_pages: &[AccountInfo],
+) -> Result<[u8; 32]> {
+    let candy_machine_info = candy_machine.to_account_info();
+    let account_data = candy_machine_info.data.borrow();
+
+    let items_available = candy_machine.data.items_available as usize;
+    let lines_per_page = candy_machine.get_config_lines_per_page();
+    let line_size = candy_machine.data.get_config_line_size();
+    let pages = candy_machine.get_config_line_pages(&account_data)?.len();
+
+    if pages * lines_per_page < items_available {
+        return err!(CandyError::NotFullyLoaded);
+    }
+
+    let page_data = (0..pages)
+        .map(|page| {
+            get_config_line_page(candy_machine, &account_data, config_line_pages, page)
+                .map(|page_info| page_info.data.borrow())
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let page_lines = page_data
+        .iter()
+        .enumerate()
+        .map(|(page, data)| {
+            let lines = lines_per_page.min(items_available - page * lines_per_page);
+            &data[ConfigLinePage::HEADER..ConfigLinePage::HEADER + lines * line_size]
+        })
+        .collect::<Vec<&[u8]>>();
+
+    get_config_lines_commitment(candy_machine, &account_data, &page_lines)
+}
+
+/// Finalizes the config lines of the candy machine.
+#[derive(Accounts)]
+pub struct Finalize<'info> {
+    /// Candy Machine account.
+    #[account(mut, has_one = authority)]
+    candy_machine: Account<'info, CandyMachine>,
+
+    /// Candy Machine authority.
+    authority: Signer<'info>,
+
+    /// Payer of the transaction.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+}
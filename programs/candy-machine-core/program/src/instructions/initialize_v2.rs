@@ -3,17 +3,21 @@ use mpl_token_metadata::{types::TokenStandard, MAX_SYMBOL_LENGTH};
 use mpl_utils::resize_or_reallocate_account_raw;
 
 use crate::{
-    approve_metadata_delegate, assert_token_standard,
+    approve_core_delegate, approve_metadata_delegate, approve_token_group_authority,
+    assert_token_standard,
     constants::{
         AUTHORITY_SEED, HIDDEN_SECTION, MPL_TOKEN_AUTH_RULES_PROGRAM, RULE_SET_LENGTH, SET,
+        TOKEN_STANDARD_CORE, TOKEN_STANDARD_TOKEN_2022,
     },
+    get_program_account, set_group_authority,
     state::{CandyMachine, CandyMachineData},
     utils::fixed_length_string,
-    AccountVersion, ApproveMetadataDelegateHelperAccounts,
+    AccountVersion, ApproveCoreDelegateHelperAccounts, ApproveMetadataDelegateHelperAccounts,
+    ApproveTokenGroupAuthorityHelperAccounts, CandyError,
 };
 
-pub fn initialize_v2(
-    ctx: Context<InitializeV2>,
+pub fn initialize_v2<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitializeV2<'info>>,
     data: CandyMachineData,
     token_standard: u8,
 ) -> Result<()> {
@@ -76,6 +80,49 @@ pub fn initialize_v2(
         }
     }
 
+    if token_standard == TOKEN_STANDARD_CORE {
+        // approves the update delegate so the candy machine can create assets in
+        // the Core collection
+        let delegate_accounts = ApproveCoreDelegateHelperAccounts {
+            payer: ctx.accounts.payer.to_account_info(),
+            authority_pda: ctx.accounts.authority_pda.to_account_info(),
+            collection: ctx.accounts.collection_mint.to_account_info(),
+            collection_update_authority: ctx.accounts.collection_update_authority.to_account_info(),
+            mpl_core_program: get_program_account(ctx.remaining_accounts, &mpl_core::ID)
+                .ok_or(CandyError::MissingMplCoreProgram)?,
+            system_program: ctx.accounts.system_program.to_account_info(),
+        };
+
+        return approve_core_delegate(delegate_accounts);
+    }
+
+    if token_standard == TOKEN_STANDARD_TOKEN_2022 {
+        drop(account_data);
+
+        // transfers the group update authority so the candy machine can add minted
+        // NFTs to the Token-2022 collection
+        let group_accounts = ApproveTokenGroupAuthorityHelperAccounts {
+            authority_pda: ctx.accounts.authority_pda.to_account_info(),
+            collection: ctx.accounts.collection_mint.to_account_info(),
+            collection_update_authority: ctx.accounts.collection_update_authority.to_account_info(),
+            spl_token_2022_program: get_program_account(
+                ctx.remaining_accounts,
+                &spl_token_2022::ID,
+            )
+            .ok_or(CandyError::MissingSplToken2022Program)?,
+        };
+
+        approve_token_group_authority(group_accounts)?;
+
+        return set_group_authority(
+            &candy_machine,
+            &ctx.accounts.candy_machine.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.collection_update_authority.key,
+        );
+    }
+
     // approves the metadata delegate so the candy machine can verify minted NFTs
     let delegate_accounts = ApproveMetadataDelegateHelperAccounts {
         token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
@@ -0,0 +1,120 @@
+use anchor_lang::{prelude::*, Discriminator};
+use mpl_token_metadata::{types::TokenStandard, MAX_SYMBOL_LENGTH};
+use mpl_utils::resize_or_reallocate_account_raw;
+
+use crate::{
+    assert_token_standard,
+    constants::{MPL_TOKEN_AUTH_RULES_PROGRAM, RULE_SET_LENGTH, SET},
+    is_collection_required,
+    state::{CandyMachine, CandyMachineData},
+    utils::fixed_length_string,
+    AccountVersion, CandyError,
+};
+
+pub fn initialize_without_collection(
+    ctx: Context<InitializeWithoutCollection>,
+    data: CandyMachineData,
+    token_standard: u8,
+) -> Result<()> {
+    // make sure we got a valid token standard
+    assert_token_standard(token_standard)?;
+
+    // Core assets, compressed and Token-2022 NFTs are always minted into a collection
+    if is_collection_required(token_standard) {
+        return err!(CandyError::CollectionRequired);
+    }
+
+    let hidden_section = data.get_hidden_section_v3()?;
+    let required_length = data.get_space_for_candy_v3()?;
+
+    if token_standard == TokenStandard::ProgrammableNonFungible as u8
+        && ctx.accounts.candy_machine.data_len() < (required_length + RULE_SET_LENGTH + 1)
+    {
+        msg!("Allocating space to store the rule set");
+
+        resize_or_reallocate_account_raw(
+            &ctx.accounts.candy_machine.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            required_length + (1 + RULE_SET_LENGTH),
+        )?;
+    }
+
+    let candy_machine_account = &mut ctx.accounts.candy_machine;
+
+    let mut candy_machine = CandyMachine {
+        data,
+        version: AccountVersion::V3,
+        token_standard,
+        features: [0u8; 6],
+        authority: ctx.accounts.authority.key(),
+        mint_authority: ctx.accounts.authority.key(),
+        // the default address indicates that there is no collection
+        collection_mint: Pubkey::default(),
+        items_redeemed: 0,
+    };
+
+    candy_machine.data.symbol = fixed_length_string(candy_machine.data.symbol, MAX_SYMBOL_LENGTH)?;
+    // validates the config lines settings
+    candy_machine.data.validate()?;
+
+    let mut struct_data = CandyMachine::discriminator().try_to_vec().unwrap();
+    struct_data.append(&mut candy_machine.try_to_vec().unwrap());
+
+    let mut account_data = candy_machine_account.data.borrow_mut();
+    account_data[0..struct_data.len()].copy_from_slice(&struct_data);
+
+    if candy_machine.data.hidden_settings.is_none() {
+        // set the initial number of config lines
+        account_data[hidden_section..hidden_section + 4].copy_from_slice(&u32::MIN.to_le_bytes());
+    }
+
+    if token_standard == TokenStandard::ProgrammableNonFungible as u8 {
+        if let Some(rule_set_info) = &ctx.accounts.rule_set {
+            msg!("Storing rule set pubkey");
+
+            let rule_set = rule_set_info.key();
+            account_data[required_length] = SET;
+
+            let index = required_length + 1;
+            let mut storage = &mut account_data[index..index + RULE_SET_LENGTH];
+            rule_set.serialize(&mut storage)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Initializes a new candy machine without a collection.
+#[derive(Accounts)]
+#[instruction(data: CandyMachineData, token_standard: u8)]
+pub struct InitializeWithoutCollection<'info> {
+    /// Candy Machine account. The account space must be allocated to allow accounts larger
+    /// than 10kb.
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(
+        zero,
+        rent_exempt = skip,
+        constraint = candy_machine.to_account_info().owner == __program_id && candy_machine.to_account_info().data_len() >= data.get_space_for_candy_v3()?
+    )]
+    candy_machine: UncheckedAccount<'info>,
+
+    /// Candy Machine authority. This is the address that controls the upate of the candy machine.
+    ///
+    /// CHECK: authority can be any account and is not written to or read
+    authority: UncheckedAccount<'info>,
+
+    /// Payer of the transaction.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// Authorization rule set to be used by minted NFTs.
+    ///
+    /// CHECK: must be ownwed by mpl_token_auth_rules
+    #[account(owner = MPL_TOKEN_AUTH_RULES_PROGRAM)]
+    rule_set: Option<UncheckedAccount<'info>>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+}
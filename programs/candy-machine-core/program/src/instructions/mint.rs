@@ -26,18 +26,34 @@ pub fn mint<'info>(ctx: Context<'_, '_, '_, 'info, Mint<'info>>) -> Result<()> {
         nft_mint_authority: ctx.accounts.nft_mint_authority.to_account_info(),
         payer: ctx.accounts.payer.to_account_info(),
         recent_slothashes: ctx.accounts.recent_slothashes.to_account_info(),
+        authorization_rules_program: None,
+        authorization_rules: None,
         system_program: ctx.accounts.system_program.to_account_info(),
         sysvar_instructions: None,
         token: None,
         token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
         spl_token_program: ctx.accounts.token_program.to_account_info(),
         token_record: None,
+        mpl_core_program: None,
+        merkle_tree: None,
+        tree_config: None,
+        bubblegum_signer: None,
+        bubblegum_program: None,
+        log_wrapper: None,
+        compression_program: None,
+        spl_token_2022_program: None,
+        mint_request: None,
+        mint_receipt: None,
+        remaining_accounts: ctx.remaining_accounts.to_vec(),
     };
 
     process_mint(
         &mut ctx.accounts.candy_machine,
         accounts,
         ctx.bumps["authority_pda"],
+        None,
+        None,
+        None,
     )
 }
 
@@ -0,0 +1,268 @@
+use anchor_lang::prelude::*;
+use mpl_bubblegum::programs::{SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID};
+use solana_program::sysvar;
+
+use crate::{
+    constants::{AUTHORITY_SEED, COMMIT_REVEAL_FEATURE, MERKLE_CONFIG_LINES_FEATURE},
+    instructions::mint_v2::{process_mint, MintAccounts},
+    utils::*,
+    CandyError, CandyMachine,
+};
+
+pub fn mint_batch<'info>(ctx: Context<'_, '_, '_, 'info, MintBatch<'info>>) -> Result<()> {
+    let batch = get_batch_items(ctx.remaining_accounts)?;
+
+    // items of a batch are selected and minted straight away, so there is no
+    // mint request or config line proof for them
+    if ctx
+        .accounts
+        .candy_machine
+        .is_feature_enabled(COMMIT_REVEAL_FEATURE)
+        || ctx
+            .accounts
+            .candy_machine
+            .is_feature_enabled(MERKLE_CONFIG_LINES_FEATURE)
+    {
+        return err!(CandyError::BatchMintNotSupported);
+    }
+
+    let candy_machine_info = ctx.accounts.candy_machine.to_account_info();
+    let mint_delegate = ctx
+        .accounts
+        .candy_machine
+        .get_mint_delegate(&candy_machine_info.data.borrow())?;
+
+    // locking or delegating an item requires the signature of its owner, which is not
+    // available for the items of a batch
+    if ctx.accounts.candy_machine.is_soulbound() || mint_delegate.is_some() {
+        return err!(CandyError::BatchMintNotSupported);
+    }
+
+    // remaining accounts hold the accounts of the items, so there is no space for the
+    // config line page accounts
+    if ctx.accounts.candy_machine.has_config_line_pages() {
+        return err!(CandyError::ConfigLinePagesNotSupported);
+    }
+
+    let bump = ctx.bumps["authority_pda"];
+
+    for item_accounts in batch {
+        let accounts = MintAccounts {
+            spl_ata_program: ctx
+                .accounts
+                .spl_ata_program
+                .as_ref()
+                .map(|spl_ata_program| spl_ata_program.to_account_info()),
+            authority_pda: ctx.accounts.authority_pda.to_account_info(),
+            collection_delegate_record: ctx.accounts.collection_delegate_record.to_account_info(),
+            collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+            collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+            collection_mint: ctx.accounts.collection_mint.to_account_info(),
+            collection_update_authority: ctx.accounts.collection_update_authority.to_account_info(),
+            nft_owner: item_accounts[0].clone(),
+            nft_mint: item_accounts[1].clone(),
+            nft_mint_authority: ctx.accounts.authority.to_account_info(),
+            nft_metadata: item_accounts[2].clone(),
+            nft_master_edition: item_accounts[3].clone(),
+            payer: ctx.accounts.payer.to_account_info(),
+            recent_slothashes: ctx.accounts.recent_slothashes.to_account_info(),
+            authorization_rules_program: None,
+            authorization_rules: None,
+            system_program: ctx.accounts.system_program.to_account_info(),
+            sysvar_instructions: Some(ctx.accounts.sysvar_instructions.to_account_info()),
+            token: optional_item_account(&item_accounts[4]),
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+            spl_token_program: ctx.accounts.spl_token_program.to_account_info(),
+            token_record: optional_item_account(&item_accounts[5]),
+            mpl_core_program: ctx
+                .accounts
+                .mpl_core_program
+                .as_ref()
+                .map(|mpl_core_program| mpl_core_program.to_account_info()),
+            merkle_tree: ctx
+                .accounts
+                .merkle_tree
+                .as_ref()
+                .map(|merkle_tree| merkle_tree.to_account_info()),
+            tree_config: ctx
+                .accounts
+                .tree_config
+                .as_ref()
+                .map(|tree_config| tree_config.to_account_info()),
+            bubblegum_signer: ctx
+                .accounts
+                .bubblegum_signer
+                .as_ref()
+                .map(|bubblegum_signer| bubblegum_signer.to_account_info()),
+            bubblegum_program: ctx
+                .accounts
+                .bubblegum_program
+                .as_ref()
+                .map(|bubblegum_program| bubblegum_program.to_account_info()),
+            log_wrapper: ctx
+                .accounts
+                .log_wrapper
+                .as_ref()
+                .map(|log_wrapper| log_wrapper.to_account_info()),
+            compression_program: ctx
+                .accounts
+                .compression_program
+                .as_ref()
+                .map(|compression_program| compression_program.to_account_info()),
+            spl_token_2022_program: ctx
+                .accounts
+                .spl_token_2022_program
+                .as_ref()
+                .map(|spl_token_2022_program| spl_token_2022_program.to_account_info()),
+            mint_request: None,
+            mint_receipt: optional_item_account(&item_accounts[6]),
+            remaining_accounts: Vec::new(),
+        };
+
+        process_mint(
+            &mut ctx.accounts.candy_machine,
+            accounts,
+            bump,
+            None,
+            None,
+            None,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Optional accounts of an item follow the same convention as optional accounts of an
+/// instruction: the program id is used when the account is not present.
+fn optional_item_account<'info>(account: &AccountInfo<'info>) -> Option<AccountInfo<'info>> {
+    if cmp_pubkeys(account.key, &crate::ID) {
+        None
+    } else {
+        Some(account.clone())
+    }
+}
+
+/// Mints a batch of NFTs from the candy machine.
+///
+/// The accounts of each item are provided as remaining accounts.
+#[derive(Accounts)]
+pub struct MintBatch<'info> {
+    /// Candy machine account.
+    #[account(mut, has_one = authority)]
+    candy_machine: Box<Account<'info, CandyMachine>>,
+
+    /// Candy machine authority account. This is the account that holds a delegate
+    /// to verify an item into the collection.
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(mut, seeds = [AUTHORITY_SEED.as_bytes(), candy_machine.key().as_ref()], bump)]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Candy machine authority (batch mint only allowed for the authority). This is also
+    /// the mint authority of the NFTs.
+    authority: Signer<'info>,
+
+    /// Payer for the transaction and account allocation (rent).
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// Collection authority or metadata delegate record.
+    ///
+    /// CHECK: account checked in CPI
+    collection_delegate_record: UncheckedAccount<'info>,
+
+    /// Mint account of the collection NFT.
+    ///
+    /// CHECK: account checked in CPI
+    collection_mint: UncheckedAccount<'info>,
+
+    /// Metadata account of the collection NFT.
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    collection_metadata: UncheckedAccount<'info>,
+
+    /// Master edition account of the collection NFT.
+    ///
+    /// CHECK: account checked in CPI
+    collection_master_edition: UncheckedAccount<'info>,
+
+    /// Update authority of the collection NFT.
+    ///
+    /// CHECK: account checked in CPI
+    collection_update_authority: UncheckedAccount<'info>,
+
+    /// Token Metadata program.
+    ///
+    /// CHECK: account checked in CPI
+    #[account(address = mpl_token_metadata::ID)]
+    token_metadata_program: UncheckedAccount<'info>,
+
+    /// SPL Token program.
+    spl_token_program: Program<'info, Token>,
+
+    /// SPL Associated Token program.
+    spl_ata_program: Option<Program<'info, AssociatedToken>>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+
+    /// Instructions sysvar account.
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(address = sysvar::instructions::id())]
+    sysvar_instructions: UncheckedAccount<'info>,
+
+    /// SlotHashes sysvar cluster data.
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(address = sysvar::slot_hashes::id())]
+    recent_slothashes: UncheckedAccount<'info>,
+
+    /// MPL Core program (required for Core assets).
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(address = mpl_core::ID)]
+    mpl_core_program: Option<UncheckedAccount<'info>>,
+
+    /// Merkle tree account (required for compressed NFTs).
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    merkle_tree: Option<UncheckedAccount<'info>>,
+
+    /// Tree config account of the merkle tree (required for compressed NFTs).
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    tree_config: Option<UncheckedAccount<'info>>,
+
+    /// Bubblegum collection signer (required for compressed NFTs).
+    ///
+    /// CHECK: account checked in CPI
+    bubblegum_signer: Option<UncheckedAccount<'info>>,
+
+    /// Bubblegum program (required for compressed NFTs).
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(address = mpl_bubblegum::ID)]
+    bubblegum_program: Option<UncheckedAccount<'info>>,
+
+    /// SPL Noop program (required for compressed NFTs).
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(address = SPL_NOOP_ID)]
+    log_wrapper: Option<UncheckedAccount<'info>>,
+
+    /// SPL Account Compression program (required for compressed NFTs).
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    compression_program: Option<UncheckedAccount<'info>>,
+
+    /// SPL Token-2022 program (required for Token-2022 NFTs).
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(address = spl_token_2022::ID)]
+    spl_token_2022_program: Option<UncheckedAccount<'info>>,
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use mpl_bubblegum::{
+    instructions::MintToCollectionV1CpiBuilder,
+    types::{
+        Collection as BubblegumCollection, Creator as BubblegumCreator, MetadataArgs,
+        TokenProgramVersion, TokenStandard as BubblegumTokenStandard,
+    },
+};
+
+use crate::{constants::AUTHORITY_SEED, CandyError, CandyMachine, ConfigLine};
+
+use super::mint_v2::MintAccounts;
+
+/// Mints a compressed NFT into a merkle tree delegated to the authority PDA.
+///
+/// The tree creator must set the authority PDA as the tree delegate, which allows
+/// the candy machine to mint to the tree and verify the collection of the new leaf.
+pub(super) fn mint_compressed(
+    candy_machine: &mut Box<Account<'_, CandyMachine>>,
+    accounts: MintAccounts,
+    bump: u8,
+    config_line: ConfigLine,
+    creators: Vec<mpl_token_metadata::types::Creator>,
+) -> Result<()> {
+    let candy_machine_key = candy_machine.key();
+    let authority_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        candy_machine_key.as_ref(),
+        &[bump],
+    ];
+
+    let (
+        Some(merkle_tree),
+        Some(tree_config),
+        Some(bubblegum_signer),
+        Some(bubblegum_program),
+        Some(log_wrapper),
+        Some(compression_program),
+    ) = (
+        accounts.merkle_tree.as_ref(),
+        accounts.tree_config.as_ref(),
+        accounts.bubblegum_signer.as_ref(),
+        accounts.bubblegum_program.as_ref(),
+        accounts.log_wrapper.as_ref(),
+        accounts.compression_program.as_ref(),
+    )
+    else {
+        return err!(CandyError::MissingBubblegumAccounts);
+    };
+
+    MintToCollectionV1CpiBuilder::new(bubblegum_program)
+        .tree_config(tree_config)
+        .leaf_owner(&accounts.nft_owner)
+        .leaf_delegate(&accounts.nft_owner)
+        .merkle_tree(merkle_tree)
+        .payer(&accounts.payer)
+        .tree_creator_or_delegate(&accounts.authority_pda)
+        .collection_authority(&accounts.authority_pda)
+        .collection_authority_record_pda(Some(&accounts.collection_delegate_record))
+        .collection_mint(&accounts.collection_mint)
+        .collection_metadata(&accounts.collection_metadata)
+        .collection_edition(&accounts.collection_master_edition)
+        .bubblegum_signer(bubblegum_signer)
+        .log_wrapper(log_wrapper)
+        .compression_program(compression_program)
+        .token_metadata_program(&accounts.token_metadata_program)
+        .system_program(&accounts.system_program)
+        .metadata(MetadataArgs {
+            name: config_line.name,
+            symbol: candy_machine.data.symbol.to_string(),
+            uri: config_line.uri,
+            seller_fee_basis_points: candy_machine.data.seller_fee_basis_points,
+            primary_sale_happened: true,
+            is_mutable: candy_machine.data.is_mutable,
+            edition_nonce: None,
+            token_standard: Some(BubblegumTokenStandard::NonFungible),
+            collection: Some(BubblegumCollection {
+                verified: false,
+                key: candy_machine.collection_mint,
+            }),
+            uses: None,
+            token_program_version: TokenProgramVersion::Original,
+            creators: creators
+                .into_iter()
+                .map(|c| BubblegumCreator {
+                    address: c.address,
+                    verified: c.verified,
+                    share: c.share,
+                })
+                .collect(),
+        })
+        .invoke_signed(&[&authority_seeds])
+        .map_err(|error| error.into())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use mpl_core::types::{
+    Creator as CoreCreator, Plugin, PluginAuthority, PluginAuthorityPair, Royalties, RuleSet,
+};
+
+use crate::{constants::AUTHORITY_SEED, CandyError, CandyMachine, ConfigLine};
+
+use super::mint_v2::MintAccounts;
+
+/// Creates a new MPL Core asset in the candy machine collection.
+pub(super) fn create_core_asset(
+    candy_machine: &mut Box<Account<'_, CandyMachine>>,
+    accounts: MintAccounts,
+    bump: u8,
+    config_line: ConfigLine,
+) -> Result<()> {
+    let candy_machine_key = candy_machine.key();
+    let authority_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        candy_machine_key.as_ref(),
+        &[bump],
+    ];
+
+    let mpl_core_program_info = accounts
+        .mpl_core_program
+        .as_ref()
+        .ok_or(CandyError::MissingMplCoreProgram)?;
+
+    // Core assets do not have a creators array, royalties are set through a
+    // plugin on the asset
+    let plugins = if candy_machine.data.creators.is_empty() {
+        vec![]
+    } else {
+        vec![PluginAuthorityPair {
+            plugin: Plugin::Royalties(Royalties {
+                basis_points: candy_machine.data.seller_fee_basis_points,
+                creators: candy_machine
+                    .data
+                    .creators
+                    .iter()
+                    .map(|c| CoreCreator {
+                        address: c.address,
+                        percentage: c.percentage_share,
+                    })
+                    .collect(),
+                rule_set: RuleSet::None,
+            }),
+            authority: Some(PluginAuthority::UpdateAuthority),
+        }]
+    };
+
+    // the authority PDA signs as the update delegate of the collection
+
+    mpl_core::instructions::CreateV1CpiBuilder::new(mpl_core_program_info)
+        .asset(&accounts.nft_mint)
+        .collection(Some(&accounts.collection_mint))
+        .authority(Some(&accounts.authority_pda))
+        .payer(&accounts.payer)
+        .owner(Some(&accounts.nft_owner))
+        .system_program(&accounts.system_program)
+        .name(config_line.name)
+        .uri(config_line.uri)
+        .plugins(plugins)
+        .invoke_signed(&[&authority_seeds])
+        .map_err(|error| error.into())
+}
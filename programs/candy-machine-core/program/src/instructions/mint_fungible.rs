@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use mpl_token_metadata::{
+    accounts::Metadata,
+    instructions::{CreateV1CpiBuilder, MintV1CpiBuilder, UpdateV1CpiBuilder},
+    types::TokenStandard,
+};
+
+use crate::{
+    constants::{AUTHORITY_SEED, NULL_STRING},
+    utils::*,
+    CandyError, CandyMachine, ConfigLine,
+};
+
+use super::mint_v2::{get_item_update_authority, MintAccounts};
+
+/// Mints fungible tokens of the token type of the config line.
+///
+/// The mint of the token type is created when the metadata account is empty; otherwise,
+/// the existing mint must match the name and uri of the config line and have the
+/// authority PDA as its mint authority. The number of tokens minted is the fungible
+/// amount of the candy machine.
+///
+/// Config lines sharing the same name and uri are items of the same token type, so the
+/// supply of a token type is the number of its config lines times the fungible amount;
+/// each mint takes one item, as for any other token standard.
+///
+/// The token type is not verified into the collection: a mint is shared by all items of
+/// its token type, so it can only be counted once in the size of the collection. Its
+/// update authority is set as for any other item instead.
+pub(super) fn mint_fungible(
+    candy_machine: &mut Box<Account<'_, CandyMachine>>,
+    accounts: MintAccounts,
+    bump: u8,
+    config_line: ConfigLine,
+    creators: Vec<mpl_token_metadata::types::Creator>,
+    collection_metadata: Option<Metadata>,
+) -> Result<()> {
+    let candy_machine_key = candy_machine.key();
+    let authority_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        candy_machine_key.as_ref(),
+        &[bump],
+    ];
+
+    let sysvar_instructions_info = accounts
+        .sysvar_instructions
+        .as_ref()
+        .ok_or(CandyError::MissingInstructionsSysvar)?;
+    let token_standard = if candy_machine.token_standard == TokenStandard::Fungible as u8 {
+        TokenStandard::Fungible
+    } else {
+        TokenStandard::FungibleAsset
+    };
+    let is_new_mint = accounts.nft_metadata.data_is_empty();
+
+    if is_new_mint {
+        // the authority PDA is the mint authority, so items of the same token type can
+        // be minted from the mint
+
+        CreateV1CpiBuilder::new(&accounts.token_metadata_program)
+            .metadata(&accounts.nft_metadata)
+            .mint(&accounts.nft_mint, accounts.nft_mint.is_signer)
+            .authority(&accounts.authority_pda)
+            .payer(&accounts.payer)
+            .update_authority(&accounts.authority_pda, true)
+            .token_standard(token_standard)
+            .name(config_line.name)
+            .uri(config_line.uri)
+            .symbol(candy_machine.data.symbol.to_string())
+            .seller_fee_basis_points(candy_machine.data.seller_fee_basis_points)
+            .is_mutable(candy_machine.data.is_mutable)
+            .creators(creators)
+            .decimals(0)
+            .system_program(&accounts.system_program)
+            .sysvar_instructions(sysvar_instructions_info)
+            .spl_token_program(&accounts.spl_token_program)
+            .invoke_signed(&[&authority_seeds])?;
+    } else {
+        if !cmp_pubkeys(accounts.nft_metadata.owner, &mpl_token_metadata::ID) {
+            return err!(CandyError::IncorrectOwner);
+        }
+
+        let metadata = Metadata::try_from(&accounts.nft_metadata)?;
+
+        if !cmp_pubkeys(&metadata.mint, accounts.nft_mint.key)
+            || metadata.token_standard != Some(token_standard)
+            || metadata.name.trim_end_matches(NULL_STRING) != config_line.name
+            || metadata.uri.trim_end_matches(NULL_STRING) != config_line.uri
+        {
+            return err!(CandyError::FungibleMintMismatch);
+        }
+    }
+
+    // mints the tokens of the item
+
+    let token_info = accounts
+        .token
+        .as_ref()
+        .ok_or(CandyError::MissingTokenAccount)?;
+    let spl_ata_program_info = accounts
+        .spl_ata_program
+        .as_ref()
+        .ok_or(CandyError::MissingSplAtaProgram)?;
+
+    MintV1CpiBuilder::new(&accounts.token_metadata_program)
+        .token(token_info)
+        // if we are initializing a new token account, we need to pass the
+        // token owner; otherwise, we pass `None`
+        .token_owner(if token_info.data_is_empty() {
+            Some(&accounts.nft_owner)
+        } else {
+            None
+        })
+        .metadata(&accounts.nft_metadata)
+        .mint(&accounts.nft_mint)
+        .payer(&accounts.payer)
+        .authority(&accounts.authority_pda)
+        .system_program(&accounts.system_program)
+        .sysvar_instructions(sysvar_instructions_info)
+        .spl_token_program(&accounts.spl_token_program)
+        .spl_ata_program(spl_ata_program_info)
+        .amount(candy_machine.get_fungible_amount(&candy_machine.to_account_info().data.borrow())?)
+        .invoke_signed(&[&authority_seeds])?;
+
+    if is_new_mint {
+        // changes the update authority and primary sale happened
+
+        UpdateV1CpiBuilder::new(&accounts.token_metadata_program)
+            .authority(&accounts.authority_pda)
+            .metadata(&accounts.nft_metadata)
+            .mint(&accounts.nft_mint)
+            .payer(&accounts.payer)
+            .system_program(&accounts.system_program)
+            .sysvar_instructions(sysvar_instructions_info)
+            .primary_sale_happened(true)
+            .new_update_authority(get_item_update_authority(
+                candy_machine,
+                &accounts,
+                collection_metadata.as_ref(),
+            ))
+            .invoke_signed(&[&authority_seeds])?;
+    }
+
+    Ok(())
+}
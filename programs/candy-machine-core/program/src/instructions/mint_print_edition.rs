@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use mpl_token_metadata::{
+    accounts::{MasterEdition, Metadata},
+    instructions::{PrintV1CpiBuilder, UpdateV1CpiBuilder, VerifyCollectionV1CpiBuilder},
+    types::Data,
+};
+
+use crate::{
+    constants::{AUTHORITY_SEED, NULL_STRING},
+    utils::*,
+    CandyError, CandyMachine, ConfigLine,
+};
+
+use super::mint_v2::MintAccounts;
+
+/// Returns the master edition accounts of the print edition mode, which are provided as
+/// remaining accounts: master metadata, master edition, master token account (owned by
+/// the authority PDA) and edition marker.
+///
+/// The master metadata and master edition accounts must be the accounts of the master
+/// edition mint set on the candy machine.
+pub(super) fn get_master_edition_accounts<'a, 'info>(
+    candy_machine: &Account<'_, CandyMachine>,
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<(
+    &'a AccountInfo<'info>,
+    &'a AccountInfo<'info>,
+    &'a AccountInfo<'info>,
+    &'a AccountInfo<'info>,
+)> {
+    let master_edition_mint = candy_machine
+        .get_master_edition_mint(&candy_machine.to_account_info().data.borrow())?
+        .ok_or(CandyError::MasterEditionNotSet)?;
+
+    match remaining_accounts {
+        [master_metadata, master_edition, master_token_account, edition_marker, ..] => {
+            if !cmp_pubkeys(
+                master_metadata.key,
+                &Metadata::find_pda(&master_edition_mint).0,
+            ) || !cmp_pubkeys(
+                master_edition.key,
+                &MasterEdition::find_pda(&master_edition_mint).0,
+            ) {
+                return err!(CandyError::MasterEditionMismatch);
+            }
+
+            Ok((
+                master_metadata,
+                master_edition,
+                master_token_account,
+                edition_marker,
+            ))
+        }
+        _ => err!(CandyError::MissingMasterEditionAccounts),
+    }
+}
+
+/// Returns the number of the next edition printed from a master edition.
+pub(super) fn get_next_edition(master_edition: &AccountInfo) -> Result<u64> {
+    if !cmp_pubkeys(master_edition.owner, &mpl_token_metadata::ID) {
+        return err!(CandyError::IncorrectOwner);
+    }
+
+    let master_edition = MasterEdition::try_from(master_edition)?;
+
+    master_edition
+        .supply
+        .checked_add(1)
+        .ok_or_else(|| error!(CandyError::NumericalOverflowError))
+}
+
+/// Prints a new edition of the master edition held by the authority PDA.
+///
+/// The authority PDA must also be the update authority of the master edition, since
+/// the name and uri of the print are updated with the edition number. When the candy
+/// machine has a collection, the master edition must belong to the collection and the
+/// print is verified into it.
+pub(super) fn print_edition(
+    candy_machine: &mut Box<Account<'_, CandyMachine>>,
+    accounts: MintAccounts,
+    bump: u8,
+    config_line: ConfigLine,
+    collection_metadata: Option<Metadata>,
+) -> Result<()> {
+    let candy_machine_key = candy_machine.key();
+    let authority_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        candy_machine_key.as_ref(),
+        &[bump],
+    ];
+
+    let (master_metadata, master_edition, master_token_account, edition_marker) =
+        get_master_edition_accounts(candy_machine, &accounts.remaining_accounts)?;
+    let edition = get_next_edition(master_edition)?;
+
+    // the print copies the collection of the master edition
+    if collection_metadata.is_some() {
+        let master = Metadata::try_from(master_metadata)?;
+
+        if !matches!(&master.collection, Some(collection) if collection.key == candy_machine.collection_mint)
+        {
+            return err!(CandyError::CollectionKeyMismatch);
+        }
+    }
+
+    let sysvar_instructions_info = accounts
+        .sysvar_instructions
+        .as_ref()
+        .ok_or(CandyError::MissingInstructionsSysvar)?;
+    let token_info = accounts
+        .token
+        .as_ref()
+        .ok_or(CandyError::MissingTokenAccount)?;
+    let spl_ata_program_info = accounts
+        .spl_ata_program
+        .as_ref()
+        .ok_or(CandyError::MissingSplAtaProgram)?;
+
+    PrintV1CpiBuilder::new(&accounts.token_metadata_program)
+        .edition_metadata(&accounts.nft_metadata)
+        .edition(&accounts.nft_master_edition)
+        .edition_mint(&accounts.nft_mint, accounts.nft_mint.is_signer)
+        .edition_token_account_owner(&accounts.nft_owner)
+        .edition_token_account(token_info)
+        .edition_mint_authority(&accounts.nft_mint_authority)
+        .edition_token_record(accounts.token_record.as_ref())
+        .master_edition(master_edition)
+        .edition_marker_pda(edition_marker)
+        .payer(&accounts.payer)
+        .master_token_account_owner(&accounts.authority_pda)
+        .master_token_account(master_token_account)
+        .master_metadata(master_metadata)
+        .update_authority(&accounts.authority_pda)
+        .spl_token_program(&accounts.spl_token_program)
+        .spl_ata_program(spl_ata_program_info)
+        .sysvar_instructions(sysvar_instructions_info)
+        .system_program(&accounts.system_program)
+        .edition_number(edition)
+        .invoke_signed(&[&authority_seeds])?;
+
+    // the print copies the data of the master edition, so the edition number is
+    // substituted by updating its name and uri
+
+    let metadata = Metadata::try_from(&accounts.nft_metadata)?;
+
+    UpdateV1CpiBuilder::new(&accounts.token_metadata_program)
+        .authority(&accounts.authority_pda)
+        .token(Some(token_info))
+        .metadata(&accounts.nft_metadata)
+        .edition(Some(&accounts.nft_master_edition))
+        .mint(&accounts.nft_mint)
+        .payer(&accounts.payer)
+        .system_program(&accounts.system_program)
+        .sysvar_instructions(sysvar_instructions_info)
+        .data(Data {
+            name: config_line.name,
+            symbol: metadata.symbol.trim_end_matches(NULL_STRING).to_string(),
+            uri: config_line.uri,
+            seller_fee_basis_points: metadata.seller_fee_basis_points,
+            creators: metadata.creators,
+        })
+        .invoke_signed(&[&authority_seeds])?;
+
+    // verify the print into the collection (if any), unless it copied a verified
+    // collection from the master edition
+
+    let verified = matches!(&metadata.collection, Some(collection) if collection.verified);
+
+    if collection_metadata.is_some() && !verified {
+        VerifyCollectionV1CpiBuilder::new(&accounts.token_metadata_program)
+            .authority(&accounts.authority_pda)
+            .delegate_record(Some(&accounts.collection_delegate_record))
+            .metadata(&accounts.nft_metadata)
+            .collection_mint(&accounts.collection_mint)
+            .collection_metadata(Some(&accounts.collection_metadata))
+            .collection_master_edition(Some(&accounts.collection_master_edition))
+            .system_program(&accounts.system_program)
+            .sysvar_instructions(sysvar_instructions_info)
+            .invoke_signed(&[&authority_seeds])?;
+    }
+
+    Ok(())
+}
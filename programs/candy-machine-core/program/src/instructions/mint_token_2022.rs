@@ -0,0 +1,224 @@
+use anchor_lang::prelude::*;
+use solana_program::{
+    program::{invoke, invoke_signed},
+    system_instruction,
+};
+use spl_associated_token_account::instruction::create_associated_token_account;
+use spl_token_2022::{
+    extension::{metadata_pointer, ExtensionType},
+    instruction::{initialize_mint2, mint_to, set_authority, AuthorityType},
+    state::Mint,
+};
+use spl_token_metadata_interface::state::TokenMetadata;
+
+use crate::{
+    constants::{AUTHORITY_SEED, GROUP_MEMBER_LENGTH, GROUP_MEMBER_POINTER_LENGTH, NULL_STRING},
+    utils::*,
+    CandyError, CandyMachine, ConfigLine,
+};
+
+use super::mint_v2::MintAccounts;
+
+/// Mints a Token-2022 NFT as a member of the collection group.
+///
+/// The name and URI of the item are stored in the token metadata extension of the mint
+/// account. The authority PDA must be the update authority of the collection group.
+pub(super) fn mint_token_2022(
+    candy_machine: &mut Box<Account<'_, CandyMachine>>,
+    accounts: MintAccounts,
+    bump: u8,
+    config_line: ConfigLine,
+) -> Result<()> {
+    let candy_machine_key = candy_machine.key();
+    let authority_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        candy_machine_key.as_ref(),
+        &[bump],
+    ];
+
+    let spl_token_2022_program_info = accounts
+        .spl_token_2022_program
+        .as_ref()
+        .ok_or(CandyError::MissingSplToken2022Program)?;
+    let token_info = accounts
+        .token
+        .as_ref()
+        .ok_or(CandyError::MissingTokenAccount)?;
+    let spl_ata_program_info = accounts
+        .spl_ata_program
+        .as_ref()
+        .ok_or(CandyError::MissingSplAtaProgram)?;
+
+    let nft_mint = accounts.nft_mint.key();
+    let authority_pda = accounts.authority_pda.key();
+    let update_authority = accounts.collection_update_authority.key();
+
+    let metadata = TokenMetadata {
+        update_authority: Some(update_authority).try_into()?,
+        mint: nft_mint,
+        name: config_line.name,
+        symbol: candy_machine
+            .data
+            .symbol
+            .trim_end_matches(NULL_STRING)
+            .to_string(),
+        uri: config_line.uri,
+        additional_metadata: vec![],
+    };
+
+    // the mint account is created with space for the pointer extensions only: the
+    // Token-2022 program reallocates the account when the (variable length) metadata and
+    // group member extensions are initialized, but it does not transfer lamports, so the
+    // rent must already include them
+
+    let mint_space =
+        ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::MetadataPointer])?
+            + GROUP_MEMBER_POINTER_LENGTH;
+    let rent_space = mint_space + metadata.tlv_size_of()? + GROUP_MEMBER_LENGTH;
+
+    invoke(
+        &system_instruction::create_account(
+            accounts.payer.key,
+            &nft_mint,
+            Rent::get()?.minimum_balance(rent_space),
+            mint_space as u64,
+            &spl_token_2022::ID,
+        ),
+        &[
+            accounts.payer.to_account_info(),
+            accounts.nft_mint.to_account_info(),
+            accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    // pointer extensions must be initialized before the mint
+
+    invoke(
+        &metadata_pointer::instruction::initialize(
+            &spl_token_2022::ID,
+            &nft_mint,
+            Some(update_authority),
+            Some(nft_mint),
+        )?,
+        &[
+            accounts.nft_mint.to_account_info(),
+            spl_token_2022_program_info.to_account_info(),
+        ],
+    )?;
+
+    invoke(
+        &initialize_group_member_pointer(&nft_mint, &update_authority, &nft_mint)?,
+        &[
+            accounts.nft_mint.to_account_info(),
+            spl_token_2022_program_info.to_account_info(),
+        ],
+    )?;
+
+    invoke(
+        &initialize_mint2(&spl_token_2022::ID, &nft_mint, &authority_pda, None, 0)?,
+        &[
+            accounts.nft_mint.to_account_info(),
+            spl_token_2022_program_info.to_account_info(),
+        ],
+    )?;
+
+    // writes the name and uri of the item to the metadata extension
+
+    invoke_signed(
+        &spl_token_metadata_interface::instruction::initialize(
+            &spl_token_2022::ID,
+            &nft_mint,
+            &update_authority,
+            &nft_mint,
+            &authority_pda,
+            metadata.name,
+            metadata.symbol,
+            metadata.uri,
+        ),
+        &[
+            accounts.nft_mint.to_account_info(),
+            accounts.collection_update_authority.to_account_info(),
+            accounts.authority_pda.to_account_info(),
+            spl_token_2022_program_info.to_account_info(),
+        ],
+        &[&authority_seeds],
+    )?;
+
+    // adds the item to the collection group
+
+    invoke_signed(
+        &initialize_token_group_member(
+            &spl_token_2022::ID,
+            &nft_mint,
+            &nft_mint,
+            &authority_pda,
+            &candy_machine.collection_mint,
+            &authority_pda,
+        ),
+        &[
+            accounts.nft_mint.to_account_info(),
+            accounts.authority_pda.to_account_info(),
+            accounts.collection_mint.to_account_info(),
+            spl_token_2022_program_info.to_account_info(),
+        ],
+        &[&authority_seeds],
+    )?;
+
+    // mints one token
+
+    invoke(
+        &create_associated_token_account(
+            accounts.payer.key,
+            accounts.nft_owner.key,
+            &nft_mint,
+            &spl_token_2022::ID,
+        ),
+        &[
+            accounts.payer.to_account_info(),
+            token_info.to_account_info(),
+            accounts.nft_owner.to_account_info(),
+            accounts.nft_mint.to_account_info(),
+            accounts.system_program.to_account_info(),
+            spl_token_2022_program_info.to_account_info(),
+            spl_ata_program_info.to_account_info(),
+        ],
+    )?;
+
+    invoke_signed(
+        &mint_to(
+            &spl_token_2022::ID,
+            &nft_mint,
+            token_info.key,
+            &authority_pda,
+            &[],
+            1,
+        )?,
+        &[
+            accounts.nft_mint.to_account_info(),
+            token_info.to_account_info(),
+            accounts.authority_pda.to_account_info(),
+            spl_token_2022_program_info.to_account_info(),
+        ],
+        &[&authority_seeds],
+    )?;
+
+    // removes the mint authority so no more tokens can be minted
+
+    invoke_signed(
+        &set_authority(
+            &spl_token_2022::ID,
+            &nft_mint,
+            None,
+            AuthorityType::MintTokens,
+            &authority_pda,
+            &[],
+        )?,
+        &[
+            accounts.nft_mint.to_account_info(),
+            accounts.authority_pda.to_account_info(),
+            spl_token_2022_program_info.to_account_info(),
+        ],
+        &[&authority_seeds],
+    )
+    .map_err(|error| error.into())
+}
@@ -4,20 +4,37 @@ use mpl_token_metadata::{
     accounts::Metadata,
     instructions::{
         CreateMasterEditionV3CpiBuilder, CreateMetadataAccountV3CpiBuilder, CreateV1CpiBuilder,
+        DelegateLockedTransferV1CpiBuilder, DelegateStakingV1CpiBuilder,
+        DelegateStandardV1CpiBuilder, DelegateUtilityV1CpiBuilder, LockV1CpiBuilder,
         MintV1CpiBuilder, SetAndVerifyCollectionCpiBuilder,
         SetAndVerifySizedCollectionItemCpiBuilder, UpdateMetadataAccountV2CpiBuilder,
         UpdateV1CpiBuilder, VerifyCollectionV1CpiBuilder,
     },
     types::{Collection, DataV2, PrintSupply, RuleSetToggle, TokenStandard},
 };
+use mpl_utils::create_or_allocate_account_raw;
 use solana_program::sysvar;
 
 use crate::{
     constants::{
-        AUTHORITY_SEED, EMPTY_STR, HIDDEN_SECTION, MPL_TOKEN_AUTH_RULES_PROGRAM, NULL_STRING,
+        AUTHORITY_SEED, COMMIT_REVEAL_FEATURE, EMPTY_STR, ITEMS_SKIPPED_LENGTH,
+        MAX_RECEIPT_GROUP_LENGTH, MERKLE_CONFIG_LINES_FEATURE, MINT_RECEIPT_FEATURE,
+        MINT_RECEIPT_SEED, MINT_REQUEST_SEED, MPL_TOKEN_AUTH_RULES_PROGRAM, NULL_STRING,
+        ONCHAIN_REVEAL_FEATURE, PICK_ITEM_FEATURE, RARITY_TIER_LENGTH, TOKEN_STANDARD_COMPRESSED,
+        TOKEN_STANDARD_CORE, TOKEN_STANDARD_PRINT_EDITION, TOKEN_STANDARD_TOKEN_2022,
     },
+    events::MintEvent,
     utils::*,
-    AccountVersion, CandyError, CandyMachine, ConfigLine,
+    AccountVersion, CandyError, CandyMachine, ConfigLine, ConfigLinePage, ConfigLineProof,
+    MintDelegate, MintDelegateRole, MintReceipt, MintRequest,
+};
+
+use super::{
+    mint_compressed::mint_compressed,
+    mint_core::create_core_asset,
+    mint_fungible::mint_fungible,
+    mint_print_edition::{get_master_edition_accounts, get_next_edition, print_edition},
+    mint_token_2022::mint_token_2022,
 };
 
 /// Accounts to mint an NFT.
@@ -42,6 +59,20 @@ pub(crate) struct MintAccounts<'info> {
     pub system_program: AccountInfo<'info>,
     pub sysvar_instructions: Option<AccountInfo<'info>>,
     pub recent_slothashes: AccountInfo<'info>,
+    pub authorization_rules_program: Option<AccountInfo<'info>>,
+    pub authorization_rules: Option<AccountInfo<'info>>,
+    pub mpl_core_program: Option<AccountInfo<'info>>,
+    pub merkle_tree: Option<AccountInfo<'info>>,
+    pub tree_config: Option<AccountInfo<'info>>,
+    pub bubblegum_signer: Option<AccountInfo<'info>>,
+    pub bubblegum_program: Option<AccountInfo<'info>>,
+    pub log_wrapper: Option<AccountInfo<'info>>,
+    pub compression_program: Option<AccountInfo<'info>>,
+    pub spl_token_2022_program: Option<AccountInfo<'info>>,
+    pub mint_request: Option<AccountInfo<'info>>,
+    pub mint_receipt: Option<AccountInfo<'info>>,
+    // config line page accounts or master edition accounts (print edition mode)
+    pub remaining_accounts: Vec<AccountInfo<'info>>,
 }
 
 pub fn mint_v2<'info>(ctx: Context<'_, '_, '_, 'info, MintV2<'info>>) -> Result<()> {
@@ -64,6 +95,16 @@ pub fn mint_v2<'info>(ctx: Context<'_, '_, '_, 'info, MintV2<'info>>) -> Result<
         nft_mint_authority: ctx.accounts.nft_mint_authority.to_account_info(),
         payer: ctx.accounts.payer.to_account_info(),
         recent_slothashes: ctx.accounts.recent_slothashes.to_account_info(),
+        authorization_rules_program: ctx
+            .accounts
+            .authorization_rules_program
+            .as_ref()
+            .map(|authorization_rules_program| authorization_rules_program.to_account_info()),
+        authorization_rules: ctx
+            .accounts
+            .authorization_rules
+            .as_ref()
+            .map(|authorization_rules| authorization_rules.to_account_info()),
         system_program: ctx.accounts.system_program.to_account_info(),
         sysvar_instructions: Some(ctx.accounts.sysvar_instructions.to_account_info()),
         token: ctx
@@ -78,34 +119,294 @@ pub fn mint_v2<'info>(ctx: Context<'_, '_, '_, 'info, MintV2<'info>>) -> Result<
             .token_record
             .as_ref()
             .map(|token_record| token_record.to_account_info()),
+        mpl_core_program: None,
+        merkle_tree: None,
+        tree_config: None,
+        bubblegum_signer: None,
+        bubblegum_program: None,
+        log_wrapper: None,
+        compression_program: None,
+        spl_token_2022_program: None,
+        mint_request: None,
+        mint_receipt: None,
+        remaining_accounts: ctx.remaining_accounts.to_vec(),
     };
 
     process_mint(
         &mut ctx.accounts.candy_machine,
         accounts,
         ctx.bumps["authority_pda"],
+        None,
+        None,
+        None,
     )
 }
 
 /// Mint a new NFT.
 ///
 /// The index minted depends on the configuration of the candy machine: it could be
-/// a psuedo-randomly selected one or sequential. In both cases, after minted a
-/// specific index, the candy machine does not allow to mint the same index again.
+/// a psuedo-randomly selected one, sequential or the one requested by the minter. In
+/// all cases, after minted a specific index, the candy machine does not allow to mint
+/// the same index again.
+///
+/// When config lines are verified using a merkle root, the config line of the selected
+/// index must be supplied together with its proof.
+///
+/// When mint receipts are enabled, the receipt of the mint records the `group` label.
+///
+/// When config lines are stored on page accounts, the page accounts are provided as
+/// remaining accounts. When printing editions, the master edition accounts are provided
+/// as remaining accounts instead.
 pub(crate) fn process_mint(
     candy_machine: &mut Box<Account<'_, CandyMachine>>,
     accounts: MintAccounts,
     bump: u8,
+    requested_index: Option<u32>,
+    config_line_proof: Option<&ConfigLineProof>,
+    group: Option<&str>,
 ) -> Result<()> {
-    if !accounts.nft_metadata.data_is_empty() {
-        return err!(CandyError::MetadataAccountMustBeEmpty);
+    // (1) validating the mint accounts
+
+    let collection_metadata = validate_mint_accounts(candy_machine, &accounts)?;
+
+    if candy_machine.is_paused() {
+        return err!(CandyError::CandyMachinePaused);
     }
 
     // are there items to be minted?
-    if candy_machine.items_redeemed >= candy_machine.data.items_available {
+    if candy_machine.get_items_remaining(&candy_machine.to_account_info().data.borrow())? == 0 {
         return err!(CandyError::CandyMachineEmpty);
     }
 
+    // the mint account of a fungible item is the mint of its token type, so the item must
+    // be known when the transaction is created
+    if requested_index.is_none()
+        && is_fungible_token_standard(candy_machine.token_standard)
+        && matches!(&candy_machine.data.config_line_settings, Some(settings) if !settings.is_sequential)
+    {
+        return err!(CandyError::FungibleRandomSelection);
+    }
+
+    if requested_index.is_none() && candy_machine.is_feature_enabled(COMMIT_REVEAL_FEATURE) {
+        // the item is selected when the request is revealed
+        return create_mint_request(candy_machine, &accounts);
+    }
+
+    // the available items must not change while requests are waiting to be revealed
+    if candy_machine.pending_requests() > 0 {
+        return err!(CandyError::PendingMintRequests);
+    }
+
+    // (2) selecting an item to mint
+
+    let (index, config_line) = if let Some(index) = requested_index {
+        select_requested_config_line(
+            candy_machine,
+            &accounts.remaining_accounts,
+            index,
+            config_line_proof,
+        )?
+    } else {
+        let recent_slothashes = &accounts.recent_slothashes;
+        let data = recent_slothashes.data.borrow();
+        let most_recent = array_ref![data, 12, 8];
+
+        let clock = Clock::get()?;
+        // seed for the random number is a combination of the slot_hash - timestamp and
+        // the mint address
+        let seed = get_item_seed(
+            u64::from_le_bytes(*most_recent).saturating_sub(clock.unix_timestamp as u64),
+            accounts.nft_mint.key,
+        );
+        // release the data borrow
+        drop(data);
+
+        select_config_line(
+            candy_machine,
+            &accounts.remaining_accounts,
+            seed,
+            config_line_proof,
+        )?
+    };
+
+    candy_machine.items_redeemed = candy_machine
+        .items_redeemed
+        .checked_add(1)
+        .ok_or(CandyError::NumericalOverflowError)?;
+
+    // (3) minting
+
+    if candy_machine.is_feature_enabled(MINT_RECEIPT_FEATURE) {
+        create_mint_receipt(candy_machine, &accounts, index, group)?;
+    }
+
+    let event = mint_event(candy_machine, &accounts, index, &config_line);
+
+    mint_config_line(
+        candy_machine,
+        accounts,
+        bump,
+        config_line,
+        collection_metadata,
+    )?;
+
+    emit!(event);
+
+    Ok(())
+}
+
+/// Mint the NFT of a mint request.
+///
+/// A mint was reserved when the request was created, but the item is only selected
+/// now using the seed. Requests are revealed in order and no other change to the
+/// available items is allowed while requests are pending, so the item selected for a
+/// seed cannot be influenced after the request is created.
+///
+/// Requests can be revealed while the candy machine is paused, since the mint was
+/// already reserved (and paid for) when the request was created.
+pub(crate) fn process_reveal(
+    candy_machine: &mut Box<Account<'_, CandyMachine>>,
+    accounts: MintAccounts,
+    bump: u8,
+    seed: u64,
+    config_line_proof: Option<&ConfigLineProof>,
+) -> Result<()> {
+    let collection_metadata = validate_mint_accounts(candy_machine, &accounts)?;
+
+    let (index, config_line) = select_config_line(
+        candy_machine,
+        &accounts.remaining_accounts,
+        seed,
+        config_line_proof,
+    )?;
+
+    candy_machine.advance_reveal_sequence()?;
+
+    if candy_machine.is_feature_enabled(MINT_RECEIPT_FEATURE) {
+        create_mint_receipt(candy_machine, &accounts, index, None)?;
+    }
+
+    let event = mint_event(candy_machine, &accounts, index, &config_line);
+
+    mint_config_line(
+        candy_machine,
+        accounts,
+        bump,
+        config_line,
+        collection_metadata,
+    )?;
+
+    emit!(event);
+
+    Ok(())
+}
+
+/// Creates the receipt of a mint.
+fn create_mint_receipt(
+    candy_machine: &Account<'_, CandyMachine>,
+    accounts: &MintAccounts,
+    index: u32,
+    group: Option<&str>,
+) -> Result<()> {
+    let mint_receipt_info = accounts
+        .mint_receipt
+        .as_ref()
+        .ok_or(CandyError::MissingMintReceipt)?;
+
+    if group.is_some_and(|group| group.len() > MAX_RECEIPT_GROUP_LENGTH) {
+        return err!(CandyError::ReceiptGroupTooLong);
+    }
+
+    let candy_machine_key = candy_machine.key();
+    let (mint_receipt_key, mint_receipt_bump) = Pubkey::find_program_address(
+        &[
+            MINT_RECEIPT_SEED.as_bytes(),
+            candy_machine_key.as_ref(),
+            accounts.nft_mint.key.as_ref(),
+        ],
+        &crate::ID,
+    );
+
+    if !cmp_pubkeys(mint_receipt_info.key, &mint_receipt_key) || !mint_receipt_info.data_is_empty()
+    {
+        return err!(CandyError::InvalidMintReceipt);
+    }
+
+    create_or_allocate_account_raw(
+        crate::ID,
+        mint_receipt_info,
+        &accounts.system_program,
+        &accounts.payer,
+        MintReceipt::LEN,
+        &[
+            MINT_RECEIPT_SEED.as_bytes(),
+            candy_machine_key.as_ref(),
+            accounts.nft_mint.key.as_ref(),
+            &[mint_receipt_bump],
+        ],
+    )?;
+
+    let clock = Clock::get()?;
+
+    let mint_receipt = MintReceipt {
+        candy_machine: candy_machine_key,
+        mint: accounts.nft_mint.key(),
+        payer: accounts.payer.key(),
+        index,
+        items_redeemed: candy_machine.items_redeemed,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+        group: group.map(|group| group.to_string()),
+        bump: mint_receipt_bump,
+    };
+
+    let mut account_data = mint_receipt_info.try_borrow_mut_data()?;
+    mint_receipt.try_serialize(&mut account_data.as_mut())?;
+
+    Ok(())
+}
+
+/// Returns the event of a mint.
+fn mint_event(
+    candy_machine: &Account<'_, CandyMachine>,
+    accounts: &MintAccounts,
+    index: u32,
+    config_line: &ConfigLine,
+) -> MintEvent {
+    MintEvent {
+        candy_machine: candy_machine.key(),
+        mint: accounts.nft_mint.key(),
+        owner: accounts.nft_owner.key(),
+        index,
+        items_redeemed: candy_machine.items_redeemed,
+        name: config_line.name.clone(),
+        uri: config_line.uri.clone(),
+    }
+}
+
+/// Validates the accounts of a mint and returns the collection metadata (if any).
+fn validate_mint_accounts(
+    candy_machine: &Account<'_, CandyMachine>,
+    accounts: &MintAccounts,
+) -> Result<Option<Metadata>> {
+    let is_core = candy_machine.token_standard == TOKEN_STANDARD_CORE;
+    let is_compressed = candy_machine.token_standard == TOKEN_STANDARD_COMPRESSED;
+    let is_token_2022 = candy_machine.token_standard == TOKEN_STANDARD_TOKEN_2022;
+
+    // Core assets, compressed and Token-2022 NFTs do not use a metadata account
+    let uses_metadata = !(is_core || is_compressed || is_token_2022);
+    // fungible tokens can be minted from an existing mint of the token type
+    let is_fungible = is_fungible_token_standard(candy_machine.token_standard);
+
+    if uses_metadata && !is_fungible && !accounts.nft_metadata.data_is_empty() {
+        return err!(CandyError::MetadataAccountMustBeEmpty);
+    }
+
+    // candy machines without a collection do not use the collection accounts
+    if !candy_machine.has_collection() {
+        return Ok(None);
+    }
+
     // check that we got the correct collection mint
     if !cmp_pubkeys(
         &accounts.collection_mint.key(),
@@ -114,46 +415,505 @@ pub(crate) fn process_mint(
         return err!(CandyError::CollectionKeyMismatch);
     }
 
-    // collection metadata must be owner by token metadata
-    if !cmp_pubkeys(accounts.collection_metadata.owner, &mpl_token_metadata::ID) {
-        return err!(CandyError::IncorrectOwner);
-    }
+    if is_core {
+        // Core collection must be owned by mpl core
+        if !is_core_collection(&accounts.collection_mint) {
+            return err!(CandyError::IncorrectOwner);
+        }
 
-    let collection_metadata_info = &accounts.collection_metadata;
-    let collection_metadata: Metadata =
-        Metadata::try_from(&collection_metadata_info.to_account_info())?;
-    // check that the update authority matches the collection update authority
-    if !cmp_pubkeys(
-        &collection_metadata.update_authority,
-        &accounts.collection_update_authority.key(),
-    ) {
-        return err!(CandyError::IncorrectCollectionAuthority);
+        Ok(None)
+    } else if is_token_2022 {
+        // Token-2022 collection must be owned by the Token-2022 program
+        if !is_token_2022_collection(&accounts.collection_mint) {
+            return err!(CandyError::IncorrectOwner);
+        }
+        // check that the update authority matches the collection update authority
+        if !cmp_pubkeys(
+            &get_token_2022_update_authority(&accounts.collection_mint)?,
+            &accounts.collection_update_authority.key(),
+        ) {
+            return err!(CandyError::IncorrectCollectionAuthority);
+        }
+
+        Ok(None)
+    } else {
+        // collection metadata must be owner by token metadata
+        if !cmp_pubkeys(accounts.collection_metadata.owner, &mpl_token_metadata::ID) {
+            return err!(CandyError::IncorrectOwner);
+        }
+
+        let collection_metadata_info = &accounts.collection_metadata;
+        let collection_metadata: Metadata =
+            Metadata::try_from(&collection_metadata_info.to_account_info())?;
+        // check that the update authority matches the collection update authority
+        if !cmp_pubkeys(
+            &collection_metadata.update_authority,
+            &accounts.collection_update_authority.key(),
+        ) {
+            return err!(CandyError::IncorrectCollectionAuthority);
+        }
+
+        Ok(Some(collection_metadata))
     }
+}
 
-    // (2) selecting an item to mint
+/// Creates a mint request, reserving an item for the NFT owner.
+///
+/// The request stores the current slot; the item is selected when the request is
+/// revealed using the hash of the next slot, which is not known at this point.
+fn create_mint_request(
+    candy_machine: &mut Box<Account<'_, CandyMachine>>,
+    accounts: &MintAccounts,
+) -> Result<()> {
+    let mint_request_info = accounts
+        .mint_request
+        .as_ref()
+        .ok_or(CandyError::MissingMintRequest)?;
 
-    let recent_slothashes = &accounts.recent_slothashes;
-    let data = recent_slothashes.data.borrow();
-    let most_recent = array_ref![data, 12, 8];
+    let candy_machine_key = candy_machine.key();
+    let (mint_request_key, mint_request_bump) = Pubkey::find_program_address(
+        &[
+            MINT_REQUEST_SEED.as_bytes(),
+            candy_machine_key.as_ref(),
+            accounts.nft_owner.key.as_ref(),
+        ],
+        &crate::ID,
+    );
+
+    if !cmp_pubkeys(mint_request_info.key, &mint_request_key) || !mint_request_info.data_is_empty()
+    {
+        return err!(CandyError::InvalidMintRequest);
+    }
 
-    let clock = Clock::get()?;
-    // seed for the random number is a combination of the slot_hash - timestamp
-    let seed = u64::from_le_bytes(*most_recent).saturating_sub(clock.unix_timestamp as u64);
+    create_or_allocate_account_raw(
+        crate::ID,
+        mint_request_info,
+        &accounts.system_program,
+        &accounts.payer,
+        MintRequest::LEN,
+        &[
+            MINT_REQUEST_SEED.as_bytes(),
+            candy_machine_key.as_ref(),
+            accounts.nft_owner.key.as_ref(),
+            &[mint_request_bump],
+        ],
+    )?;
+
+    let pending_requests = candy_machine
+        .pending_requests()
+        .checked_add(1)
+        .ok_or(CandyError::NumericalOverflowError)?;
 
-    let remainder: usize = seed
-        .checked_rem(candy_machine.data.items_available - candy_machine.items_redeemed)
-        .ok_or(CandyError::NumericalOverflowError)? as usize;
+    let mint_request = MintRequest {
+        candy_machine: candy_machine_key,
+        owner: accounts.nft_owner.key(),
+        payer: accounts.payer.key(),
+        slot: Clock::get()?.slot,
+        // the request is revealed after all pending requests
+        sequence: candy_machine
+            .next_reveal_sequence()
+            .wrapping_add(candy_machine.pending_requests()),
+        bump: mint_request_bump,
+    };
+
+    let mut account_data = mint_request_info.try_borrow_mut_data()?;
+    mint_request.try_serialize(&mut account_data.as_mut())?;
 
-    let config_line = get_config_line(candy_machine, remainder, candy_machine.items_redeemed)?;
+    // reserves the mint until the request is revealed
 
     candy_machine.items_redeemed = candy_machine
         .items_redeemed
         .checked_add(1)
         .ok_or(CandyError::NumericalOverflowError)?;
-    // release the data borrow
-    drop(data);
 
-    // (3) minting
+    candy_machine.set_pending_requests(pending_requests);
+
+    Ok(())
+}
+
+/// Selects the config line to mint using the specified seed.
+///
+/// Items reserved by pending mint requests are still in the available indices, so the
+/// selection only considers the number of items already minted (or removed).
+fn select_config_line(
+    candy_machine: &Account<'_, CandyMachine>,
+    remaining_accounts: &[AccountInfo],
+    seed: u64,
+    config_line_proof: Option<&ConfigLineProof>,
+) -> Result<(u32, ConfigLine)> {
+    let items_minted =
+        candy_machine.get_items_taken(&candy_machine.to_account_info().data.borrow())?;
+
+    let index = match &candy_machine.data.config_line_settings {
+        Some(settings) if candy_machine.data.hidden_settings.is_none() => {
+            check_fully_loaded(candy_machine)?;
+
+            if settings.is_sequential {
+                select_sequential_index(candy_machine, items_minted)?
+            } else {
+                select_random_index(candy_machine, seed, items_minted)?
+            }
+        }
+        // hidden settings use the mint number
+        _ => items_minted as usize,
+    };
+
+    get_config_line(
+        candy_machine,
+        remaining_accounts,
+        index,
+        items_minted,
+        config_line_proof,
+    )
+}
+
+/// Validates that all config lines were added to the candy machine.
+///
+/// The merkle root includes all config lines of the candy machine, so there is nothing to
+/// validate when config lines are verified using a merkle root.
+fn check_fully_loaded(candy_machine: &Account<'_, CandyMachine>) -> Result<()> {
+    let config_count = get_config_count(&candy_machine.to_account_info().data.borrow())? as u64;
+
+    if !candy_machine.is_feature_enabled(MERKLE_CONFIG_LINES_FEATURE)
+        && config_count != candy_machine.data.items_available
+    {
+        return err!(CandyError::NotFullyLoaded);
+    }
+
+    Ok(())
+}
+
+/// Selects the next item of a sequential candy machine.
+///
+/// Items are minted in order, skipping removed config lines. Every item before the
+/// number of items minted was taken, so the next item is the first one available from
+/// that position.
+fn select_sequential_index(
+    candy_machine: &Account<'_, CandyMachine>,
+    items_minted: u64,
+) -> Result<usize> {
+    let account_info = candy_machine.to_account_info();
+    let mut account_data = account_info.data.borrow_mut();
+    let items_removed = candy_machine.get_items_removed(&account_data)?;
+
+    let mut index = items_minted
+        .checked_sub(items_removed)
+        .ok_or(CandyError::NumericalOverflowError)? as usize;
+
+    while (index as u64) < candy_machine.data.items_available
+        && is_item_taken(candy_machine, &account_data, index as u32)?
+    {
+        index += 1;
+    }
+
+    if index as u64 >= candy_machine.data.items_available {
+        return err!(CandyError::CandyMachineEmpty);
+    }
+
+    set_item_taken(candy_machine, &mut account_data, index as u32)?;
+
+    Ok(index)
+}
+
+/// Selects a random item using the specified seed, removing it from the available mint
+/// indices.
+///
+/// Picked items and removed config lines stay on the available mint indices until they
+/// are selected, so they are skipped and another item is selected using a seed derived
+/// from the previous one.
+fn select_random_index(
+    candy_machine: &Account<'_, CandyMachine>,
+    seed: u64,
+    items_minted: u64,
+) -> Result<usize> {
+    let account_info = candy_machine.to_account_info();
+    let mut seed = seed;
+
+    loop {
+        let (position, last_position) = match select_weighted_index(candy_machine, seed)? {
+            Some(positions) => positions,
+            None => {
+                let items_skipped = candy_machine.get_items_skipped(&account_info.data.borrow())?;
+                let items_left = (candy_machine.data.items_available - items_minted)
+                    .checked_add(items_skipped as u64)
+                    .ok_or(CandyError::NumericalOverflowError)?;
+
+                if items_left == 0 {
+                    return err!(CandyError::CandyMachineEmpty);
+                }
+
+                ((seed % items_left) as usize, items_left as usize - 1)
+            }
+        };
+
+        let mut account_data = account_info.data.borrow_mut();
+        let index = take_mint_index(candy_machine, &mut account_data, position, last_position)?;
+        let items_skipped = candy_machine.get_items_skipped(&account_data)?;
+
+        if items_skipped > 0 && is_item_taken(candy_machine, &account_data, index)? {
+            // the item was picked or removed, so it only needed to be taken out of the
+            // available mint indices
+            set_items_skipped(candy_machine, &mut account_data, items_skipped - 1)?;
+            seed = get_item_seed(seed, &candy_machine.key());
+            continue;
+        }
+
+        set_item_taken(candy_machine, &mut account_data, index)?;
+
+        return Ok(index as usize);
+    }
+}
+
+/// Selects a random index using the weights of the rarity tiers (if any).
+///
+/// The indices of each tier are stored contiguously on the mint indices array, with
+/// the available ones at the start of the tier range. The probability of selecting a
+/// tier is proportional to its weight multiplied by the number of available items, so
+/// the odds shift as items are minted. Returns the selected index and the index of the
+/// last available item of the tier.
+fn select_weighted_index(
+    candy_machine: &Account<'_, CandyMachine>,
+    seed: u64,
+) -> Result<Option<(usize, usize)>> {
+    match &candy_machine.data.config_line_settings {
+        Some(settings) if !settings.is_sequential => (),
+        _ => return Ok(None),
+    }
+
+    let position = candy_machine.get_rarity_tiers_position()?;
+    let account_info = candy_machine.to_account_info();
+    let mut account_data = account_info.data.borrow_mut();
+
+    if account_data.len() <= position || account_data[position] == 0 {
+        return Ok(None);
+    }
+
+    let tiers = account_data[position] as usize;
+    let mut total_weight = 0u64;
+
+    for tier in 0..tiers {
+        let offset = position + 1 + tier * RARITY_TIER_LENGTH;
+        let weight = u32::from_le_bytes(*array_ref![account_data, offset, 4]) as u64;
+        let remaining = u32::from_le_bytes(*array_ref![account_data, offset + 8, 4]) as u64;
+
+        total_weight = weight
+            .checked_mul(remaining)
+            .and_then(|tier_weight| total_weight.checked_add(tier_weight))
+            .ok_or(CandyError::NumericalOverflowError)?;
+    }
+
+    let mut value = seed
+        .checked_rem(total_weight)
+        .ok_or(CandyError::NumericalOverflowError)?;
+    let mut start = 0usize;
+
+    for tier in 0..tiers {
+        let offset = position + 1 + tier * RARITY_TIER_LENGTH;
+        let weight = u32::from_le_bytes(*array_ref![account_data, offset, 4]) as u64;
+        let size = u32::from_le_bytes(*array_ref![account_data, offset + 4, 4]) as usize;
+        let remaining = u32::from_le_bytes(*array_ref![account_data, offset + 8, 4]);
+        let tier_weight = weight * remaining as u64;
+
+        if value < tier_weight {
+            // the same value determines the item within the tier
+            let index = start + (value / weight) as usize;
+            let last_index = start + remaining as usize - 1;
+
+            account_data[offset + 8..offset + 12].copy_from_slice(&(remaining - 1).to_le_bytes());
+
+            return Ok(Some((index, last_index)));
+        }
+
+        value -= tier_weight;
+        start += size;
+    }
+
+    err!(CandyError::InvalidRarityTiers)
+}
+
+/// Selects the config line requested by the minter.
+///
+/// The requested config line must not have been minted (or removed). The item is marked
+/// as taken, but it stays on the available mint indices until it is skipped by the
+/// random selection.
+fn select_requested_config_line(
+    candy_machine: &Account<'_, CandyMachine>,
+    remaining_accounts: &[AccountInfo],
+    index: u32,
+    config_line_proof: Option<&ConfigLineProof>,
+) -> Result<(u32, ConfigLine)> {
+    if !candy_machine.is_feature_enabled(PICK_ITEM_FEATURE) {
+        return err!(CandyError::PickItemDisabled);
+    }
+
+    match &candy_machine.data.config_line_settings {
+        Some(settings) if !settings.is_sequential => (),
+        _ => return err!(CandyError::PickItemNotSupported),
+    }
+
+    if index as u64 >= candy_machine.data.items_available {
+        return err!(CandyError::ItemNotAvailable);
+    }
+
+    check_fully_loaded(candy_machine)?;
+
+    let account_info = candy_machine.to_account_info();
+    let items_minted = {
+        let mut account_data = account_info.data.borrow_mut();
+
+        if !is_item_available(candy_machine, &account_data, index)? {
+            return err!(CandyError::ItemNotAvailable);
+        }
+
+        let items_skipped = candy_machine
+            .get_items_skipped(&account_data)?
+            .checked_add(1)
+            .ok_or(CandyError::NumericalOverflowError)?;
+
+        set_item_taken(candy_machine, &mut account_data, index)?;
+        set_items_skipped(candy_machine, &mut account_data, items_skipped)?;
+
+        candy_machine.get_items_taken(&account_data)?
+    };
+
+    get_config_line(
+        candy_machine,
+        remaining_accounts,
+        index as usize,
+        items_minted,
+        config_line_proof,
+    )
+}
+
+/// Removes the value at the specified position of the available mint indices, returning
+/// it.
+///
+/// The value at the last available position replaces the removed one (swap-remove), which
+/// removes the value in constant time O(1) no matter how big the indices array is.
+fn take_mint_index(
+    candy_machine: &Account<'_, CandyMachine>,
+    account_data: &mut [u8],
+    position: usize,
+    last_position: usize,
+) -> Result<u32> {
+    let indices_start = get_mint_indices_start(candy_machine)?;
+    let index_size = candy_machine.get_mint_index_size();
+    let lazy_indices = candy_machine.is_feature_enabled(MERKLE_CONFIG_LINES_FEATURE);
+
+    let value = read_mint_index(
+        account_data,
+        indices_start,
+        index_size,
+        position,
+        lazy_indices,
+    );
+    let last_value = read_mint_index(
+        account_data,
+        indices_start,
+        index_size,
+        last_position,
+        lazy_indices,
+    );
+    write_mint_index(
+        account_data,
+        indices_start,
+        index_size,
+        position,
+        last_value,
+        lazy_indices,
+    );
+
+    Ok(value)
+}
+
+/// Returns whether an item was not minted or removed.
+pub(crate) fn is_item_available(
+    candy_machine: &Account<'_, CandyMachine>,
+    account_data: &[u8],
+    index: u32,
+) -> Result<bool> {
+    Ok(!is_item_taken(candy_machine, account_data, index)?)
+}
+
+/// Returns whether an item was taken, i.e., it was minted or removed.
+///
+/// Once minting starts, the loaded bit-mask keeps track of the items taken: config lines
+/// are loaded before minting, so the bit of an item is unset when it is taken. When using
+/// a merkle root, config lines are never loaded and the bit is set instead.
+fn is_item_taken(
+    candy_machine: &Account<'_, CandyMachine>,
+    account_data: &[u8],
+    index: u32,
+) -> Result<bool> {
+    // items minted before the loaded bit-mask kept track of the items taken can only be
+    // known for sequential candy machines
+    if !candy_machine.is_items_tracked() {
+        return match &candy_machine.data.config_line_settings {
+            Some(settings) if settings.is_sequential => {
+                Ok((index as u64) < candy_machine.get_items_taken(account_data)?)
+            }
+            _ => err!(CandyError::ItemsNotTracked),
+        };
+    }
+
+    let mask = 1u8 << (7 - index % 8);
+    let loaded = account_data[get_bit_mask_start(candy_machine)? + index as usize / 8] & mask > 0;
+
+    Ok(loaded == candy_machine.is_feature_enabled(MERKLE_CONFIG_LINES_FEATURE))
+}
+
+/// Marks an item as taken (minted or removed) on the loaded bit-mask.
+pub(crate) fn set_item_taken(
+    candy_machine: &Account<'_, CandyMachine>,
+    account_data: &mut [u8],
+    index: u32,
+) -> Result<()> {
+    if !candy_machine.is_items_tracked() {
+        return Ok(());
+    }
+
+    let mask = 1u8 << (7 - index % 8);
+    let position = get_bit_mask_start(candy_machine)? + index as usize / 8;
+
+    if candy_machine.is_feature_enabled(MERKLE_CONFIG_LINES_FEATURE) {
+        account_data[position] |= mask;
+    } else {
+        account_data[position] &= !mask;
+    }
+
+    Ok(())
+}
+
+/// Stores the number of items skipped by the random selection.
+pub(crate) fn set_items_skipped(
+    candy_machine: &Account<'_, CandyMachine>,
+    account_data: &mut [u8],
+    items_skipped: u32,
+) -> Result<()> {
+    let position = candy_machine.get_items_skipped_position()?;
+
+    account_data
+        .get_mut(position..position + ITEMS_SKIPPED_LENGTH)
+        .ok_or(CandyError::ItemsNotTracked)?
+        .copy_from_slice(&items_skipped.to_le_bytes());
+
+    Ok(())
+}
+
+/// Mints the NFT of the selected config line.
+fn mint_config_line(
+    candy_machine: &mut Box<Account<'_, CandyMachine>>,
+    accounts: MintAccounts,
+    bump: u8,
+    config_line: ConfigLine,
+    collection_metadata: Option<Metadata>,
+) -> Result<()> {
+    // Core assets and Token-2022 NFTs do not have a collection metadata account
+    if candy_machine.token_standard == TOKEN_STANDARD_CORE {
+        return create_core_asset(candy_machine, accounts, bump, config_line);
+    } else if candy_machine.token_standard == TOKEN_STANDARD_TOKEN_2022 {
+        return mint_token_2022(candy_machine, accounts, bump, config_line);
+    }
 
     let mut creators: Vec<mpl_token_metadata::types::Creator> =
         vec![mpl_token_metadata::types::Creator {
@@ -170,6 +930,31 @@ pub(crate) fn process_mint(
         });
     }
 
+    if candy_machine.token_standard == TOKEN_STANDARD_COMPRESSED {
+        return mint_compressed(candy_machine, accounts, bump, config_line, creators);
+    }
+
+    if candy_machine.token_standard == TOKEN_STANDARD_PRINT_EDITION {
+        return print_edition(
+            candy_machine,
+            accounts,
+            bump,
+            config_line,
+            collection_metadata,
+        );
+    }
+
+    if is_fungible_token_standard(candy_machine.token_standard) {
+        return mint_fungible(
+            candy_machine,
+            accounts,
+            bump,
+            config_line,
+            creators,
+            collection_metadata,
+        );
+    }
+
     match candy_machine.version {
         AccountVersion::V1 => create(
             candy_machine,
@@ -177,9 +962,10 @@ pub(crate) fn process_mint(
             bump,
             config_line,
             creators,
-            collection_metadata,
+            // V1 accounts always have a collection
+            collection_metadata.ok_or(CandyError::CollectionRequired)?,
         ),
-        AccountVersion::V2 => create_and_mint(
+        AccountVersion::V2 | AccountVersion::V3 => create_and_mint(
             candy_machine,
             accounts,
             bump,
@@ -190,19 +976,52 @@ pub(crate) fn process_mint(
     }
 }
 
-/// Selects and returns the information of a config line.
+/// Returns the information of the selected config line.
+///
+/// When using hidden settings, the mint number is used instead of the index.
+///
+/// When config lines are verified using a merkle root, the config line is not read from
+/// the account; the supplied config line is used after its proof is verified. When config
+/// lines are stored on page accounts, the config line is read from its page account.
 ///
-/// The selection could be either sequential or random.
+/// When printing editions, the hidden settings are used with the number of the edition
+/// printed instead of the mint number.
+///
+/// Returns the index of the config line together with its information.
 pub fn get_config_line(
     candy_machine: &Account<'_, CandyMachine>,
+    remaining_accounts: &[AccountInfo],
     index: usize,
     mint_number: u64,
-) -> Result<ConfigLine> {
+    config_line_proof: Option<&ConfigLineProof>,
+) -> Result<(u32, ConfigLine)> {
+    if candy_machine.token_standard == TOKEN_STANDARD_PRINT_EDITION {
+        let hs = candy_machine
+            .data
+            .hidden_settings
+            .as_ref()
+            .ok_or(CandyError::MissingHiddenSettings)?;
+        let (_, master_edition, _, _) =
+            get_master_edition_accounts(candy_machine, remaining_accounts)?;
+        let edition = get_next_edition(master_edition)? as usize;
+
+        return Ok((
+            mint_number as u32,
+            ConfigLine {
+                name: replace_patterns(hs.name.clone(), edition),
+                uri: replace_patterns(hs.uri.clone(), edition),
+            },
+        ));
+    }
+
     if let Some(hs) = &candy_machine.data.hidden_settings {
-        return Ok(ConfigLine {
-            name: replace_patterns(hs.name.clone(), mint_number as usize),
-            uri: replace_patterns(hs.uri.clone(), mint_number as usize),
-        });
+        return Ok((
+            mint_number as u32,
+            ConfigLine {
+                name: replace_patterns(hs.name.clone(), mint_number as usize),
+                uri: replace_patterns(hs.uri.clone(), mint_number as usize),
+            },
+        ));
     }
     let settings = if let Some(settings) = &candy_machine.data.config_line_settings {
         settings
@@ -211,49 +1030,76 @@ pub fn get_config_line(
     };
 
     let account_info = candy_machine.to_account_info();
-    let mut account_data = account_info.data.borrow_mut();
+    let account_data = account_info.data.borrow();
 
-    // validates that all config lines were added to the candy machine
-    let config_count = get_config_count(&account_data)? as u64;
-    if config_count != candy_machine.data.items_available {
-        return err!(CandyError::NotFullyLoaded);
+    // the merkle root includes all config lines of the candy machine
+    if candy_machine.is_feature_enabled(MERKLE_CONFIG_LINES_FEATURE) {
+        let config_line = config_line_proof.ok_or(CandyError::MissingConfigLineProof)?;
+        let root_position = candy_machine.get_config_lines_root_position()?;
+        let root = array_ref![account_data, root_position, 32];
+        let leaf = hash_config_line(index as u32, &config_line.name, &config_line.uri);
+
+        if !verify_merkle_proof(&config_line.proof, root, &leaf) {
+            return err!(CandyError::InvalidConfigLineProof);
+        }
+
+        return Ok((
+            index as u32,
+            ConfigLine {
+                name: replace_patterns(settings.prefix_name.clone(), index) + &config_line.name,
+                uri: replace_patterns(settings.prefix_uri.clone(), index) + &config_line.uri,
+            },
+        ));
     }
 
-    // (1) determine the mint index (index is a random index on the available indices array)
+    Ok((
+        index as u32,
+        read_config_line(candy_machine, &account_data, remaining_accounts, index)?,
+    ))
+}
 
-    let value_to_use = if settings.is_sequential {
-        mint_number as usize
+/// Returns the information of a config line stored on the account data (or on its page
+/// account), including the name and uri prefixes.
+pub(crate) fn read_config_line(
+    candy_machine: &Account<'_, CandyMachine>,
+    account_data: &[u8],
+    config_line_pages: &[AccountInfo],
+    index: usize,
+) -> Result<ConfigLine> {
+    let settings = if let Some(settings) = &candy_machine.data.config_line_settings {
+        settings
     } else {
-        let items_available = candy_machine.data.items_available;
-        let indices_start = HIDDEN_SECTION
-            + 4
-            + (items_available as usize) * candy_machine.data.get_config_line_size()
-            + (items_available
-                .checked_div(8)
-                .ok_or(CandyError::NumericalOverflowError)?
-                + 1) as usize;
-        // calculates the mint index and retrieves the value at that position
-        let mint_index = indices_start + index * 4;
-        let value_to_use = u32::from_le_bytes(*array_ref![account_data, mint_index, 4]) as usize;
-        // calculates the last available index and retrieves the value at that position
-        let last_index = indices_start + ((items_available - mint_number - 1) * 4) as usize;
-        let last_value = u32::from_le_bytes(*array_ref![account_data, last_index, 4]);
-        // swap-remove: this guarantees that we remove the used mint index from the available array
-        // in a constant time O(1) no matter how big the indices array is
-        account_data[mint_index..mint_index + 4].copy_from_slice(&u32::to_le_bytes(last_value));
-
-        value_to_use
+        return err!(CandyError::MissingConfigLinesSettings);
     };
 
-    // (2) retrieve the config line at the mint_index position
+    let line_size = candy_machine.data.get_config_line_size();
+    let page_data;
 
-    let mut position =
-        HIDDEN_SECTION + 4 + value_to_use * candy_machine.data.get_config_line_size();
+    let (line_data, mut position): (&[u8], usize) = if candy_machine.has_config_line_pages() {
+        let lines_per_page = candy_machine.get_config_lines_per_page();
+        let page = get_config_line_page(
+            candy_machine,
+            account_data,
+            config_line_pages,
+            index / lines_per_page,
+        )?;
+        page_data = page.data.borrow();
+
+        (
+            &page_data,
+            ConfigLinePage::HEADER + (index % lines_per_page) * line_size,
+        )
+    } else {
+        (
+            account_data,
+            candy_machine.get_hidden_section()? + 4 + index * line_size,
+        )
+    };
     let name_length = settings.name_length as usize;
     let uri_length = settings.uri_length as usize;
 
     let name = if name_length > 0 {
-        let name_slice: &mut [u8] = &mut account_data[position..position + name_length];
+        let name_slice: &[u8] = &line_data[position..position + name_length];
         let name = String::from_utf8(name_slice.to_vec())
             .map_err(|_| CandyError::CouldNotRetrieveConfigLineData)?;
         name.trim_end_matches(NULL_STRING).to_string()
@@ -263,16 +1109,14 @@ pub fn get_config_line(
 
     position += name_length;
     let uri = if uri_length > 0 {
-        let uri_slice: &mut [u8] = &mut account_data[position..position + uri_length];
-        let uri = String::from_utf8(uri_slice.to_vec())
-            .map_err(|_| CandyError::CouldNotRetrieveConfigLineData)?;
-        uri.trim_end_matches(NULL_STRING).to_string()
+        let uri_slice: &[u8] = &line_data[position..position + uri_length];
+        encode_uri(candy_machine.uri_encoding(), uri_slice)?
     } else {
         EMPTY_STR.to_string()
     };
 
-    let complete_name = replace_patterns(settings.prefix_name.clone(), value_to_use) + &name;
-    let complete_uri = replace_patterns(settings.prefix_uri.clone(), value_to_use) + &uri;
+    let complete_name = replace_patterns(settings.prefix_name.clone(), index) + &name;
+    let complete_uri = replace_patterns(settings.prefix_uri.clone(), index) + &uri;
 
     Ok(ConfigLine {
         name: complete_name,
@@ -280,6 +1124,88 @@ pub fn get_config_line(
     })
 }
 
+/// Returns the page account storing the specified page of config lines.
+///
+/// Page accounts are looked up by address on the accounts provided, so they can be in
+/// any order.
+pub(crate) fn get_config_line_page<'a, 'info>(
+    candy_machine: &Account<'_, CandyMachine>,
+    account_data: &[u8],
+    config_line_pages: &'a [AccountInfo<'info>],
+    page: usize,
+) -> Result<&'a AccountInfo<'info>> {
+    let address = candy_machine
+        .get_config_line_pages(account_data)?
+        .get(page)
+        .copied()
+        .ok_or(CandyError::ConfigLineNotLoaded)?;
+
+    config_line_pages
+        .iter()
+        .find(|account| cmp_pubkeys(account.key, &address))
+        .ok_or_else(|| error!(CandyError::MissingConfigLinePage))
+}
+
+/// Returns the position of the mint indices array on the account data.
+fn get_mint_indices_start(candy_machine: &Account<'_, CandyMachine>) -> Result<usize> {
+    Ok(get_bit_mask_start(candy_machine)?
+        + (candy_machine
+            .data
+            .items_available
+            .checked_div(8)
+            .ok_or(CandyError::NumericalOverflowError)?
+            + 1) as usize)
+}
+
+/// Returns the position of the loaded bit-mask on the account data.
+fn get_bit_mask_start(candy_machine: &Account<'_, CandyMachine>) -> Result<usize> {
+    Ok(candy_machine.get_hidden_section()?
+        + 4
+        + (candy_machine.data.items_available as usize)
+            * candy_machine.get_stored_config_line_size())
+}
+
+/// Returns the value of the mint indices array at the specified position.
+///
+/// The mint indices are not loaded when config lines are verified using a merkle root.
+/// In this case, values are stored with an offset of one, and an empty value represents
+/// the initial value of the position.
+fn read_mint_index(
+    data: &[u8],
+    indices_start: usize,
+    index_size: usize,
+    position: usize,
+    lazy: bool,
+) -> u32 {
+    let offset = indices_start + position * index_size;
+    let value = if index_size == 2 {
+        u16::from_le_bytes(*array_ref![data, offset, 2]) as u32
+    } else {
+        u32::from_le_bytes(*array_ref![data, offset, 4])
+    };
+
+    match value {
+        _ if !lazy => value,
+        0 => position as u32,
+        _ => value - 1,
+    }
+}
+
+/// Stores a value on the mint indices array at the specified position.
+fn write_mint_index(
+    data: &mut [u8],
+    indices_start: usize,
+    index_size: usize,
+    position: usize,
+    value: u32,
+    lazy: bool,
+) {
+    let value = if lazy { value + 1 } else { value };
+    let offset = indices_start + position * index_size;
+
+    data[offset..offset + index_size].copy_from_slice(&value.to_le_bytes()[..index_size]);
+}
+
 /// Creates the metadata accounts and mint a new token.
 fn create_and_mint(
     candy_machine: &mut Box<Account<'_, CandyMachine>>,
@@ -287,7 +1213,7 @@ fn create_and_mint(
     bump: u8,
     config_line: ConfigLine,
     creators: Vec<mpl_token_metadata::types::Creator>,
-    collection_metadata: Metadata,
+    collection_metadata: Option<Metadata>,
 ) -> Result<()> {
     let candy_machine_key = candy_machine.key();
     let authority_seeds = [
@@ -303,7 +1229,8 @@ fn create_and_mint(
 
     // create metadata accounts
 
-    CreateV1CpiBuilder::new(&accounts.token_metadata_program)
+    let mut create_cpi = CreateV1CpiBuilder::new(&accounts.token_metadata_program);
+    create_cpi
         .metadata(&accounts.nft_metadata)
         .mint(&accounts.nft_mint, accounts.nft_mint.is_signer)
         .authority(&accounts.nft_mint_authority)
@@ -323,10 +1250,6 @@ fn create_and_mint(
         .seller_fee_basis_points(candy_machine.data.seller_fee_basis_points)
         .is_mutable(candy_machine.data.is_mutable)
         .creators(creators)
-        .collection(Collection {
-            verified: false,
-            key: candy_machine.collection_mint,
-        })
         .decimals(0)
         .print_supply(if candy_machine.data.max_supply == 0 {
             PrintSupply::Zero
@@ -335,8 +1258,16 @@ fn create_and_mint(
         })
         .system_program(&accounts.system_program)
         .sysvar_instructions(sysvar_instructions_info)
-        .spl_token_program(&accounts.spl_token_program)
-        .invoke_signed(&[&authority_seeds])?;
+        .spl_token_program(&accounts.spl_token_program);
+
+    if candy_machine.has_collection() {
+        create_cpi.collection(Collection {
+            verified: false,
+            key: candy_machine.collection_mint,
+        });
+    }
+
+    create_cpi.invoke_signed(&[&authority_seeds])?;
 
     // mints one token
 
@@ -384,6 +1315,8 @@ fn create_and_mint(
 
     // changes the update authority, primary sale happened, authorization rules
 
+    let update_authority =
+        get_item_update_authority(candy_machine, &accounts, collection_metadata.as_ref());
     let mut update_cpi = UpdateV1CpiBuilder::new(&accounts.token_metadata_program);
     update_cpi
         .authority(&accounts.authority_pda)
@@ -395,7 +1328,7 @@ fn create_and_mint(
         .system_program(&accounts.system_program)
         .sysvar_instructions(sysvar_instructions_info)
         .primary_sale_happened(true)
-        .new_update_authority(collection_metadata.update_authority);
+        .new_update_authority(update_authority);
 
     if candy_machine.token_standard == TokenStandard::ProgrammableNonFungible as u8 {
         let candy_machine_info = candy_machine.to_account_info();
@@ -405,7 +1338,7 @@ fn create_and_mint(
         //   1. check if there is a rule set stored on the account; otherwise
         //   2. use the rule set from the collection metadata
         let candy_machine_rule_set =
-            candy_machine.get_rule_set(&account_data, &collection_metadata)?;
+            candy_machine.get_rule_set(&account_data, collection_metadata.as_ref())?;
 
         if let Some(rule_set) = candy_machine_rule_set {
             update_cpi.rule_set(RuleSetToggle::Set(rule_set));
@@ -414,18 +1347,199 @@ fn create_and_mint(
 
     update_cpi.invoke_signed(&[&authority_seeds])?;
 
-    // verify the minted nft into the collection
+    // verify the minted nft into the collection (if any)
+
+    if collection_metadata.is_some() {
+        VerifyCollectionV1CpiBuilder::new(&accounts.token_metadata_program)
+            .authority(&accounts.authority_pda)
+            .delegate_record(Some(&accounts.collection_delegate_record))
+            .metadata(&accounts.nft_metadata)
+            .collection_mint(&accounts.collection_mint)
+            .collection_metadata(Some(&accounts.collection_metadata))
+            .collection_master_edition(Some(&accounts.collection_master_edition))
+            .system_program(&accounts.system_program)
+            .sysvar_instructions(sysvar_instructions_info)
+            .invoke_signed(&[&authority_seeds])?;
+    }
+
+    if candy_machine.is_soulbound() {
+        lock_item(candy_machine, &accounts, &authority_seeds)?;
+    } else if candy_machine.token_standard == TokenStandard::ProgrammableNonFungible as u8 {
+        let candy_machine_info = candy_machine.to_account_info();
+        let mint_delegate = candy_machine.get_mint_delegate(&candy_machine_info.data.borrow())?;
+
+        if let Some(mint_delegate) = mint_delegate {
+            approve_mint_delegate(&accounts, &mint_delegate)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Approves the mint delegate of the candy machine on the token record of a newly
+/// minted Programmable NFT.
+///
+/// The delegate account is looked up on the remaining accounts and the NFT owner must
+/// sign to approve the delegate.
+fn approve_mint_delegate(accounts: &MintAccounts, mint_delegate: &MintDelegate) -> Result<()> {
+    if !accounts.nft_owner.is_signer {
+        return err!(CandyError::MintDelegateOwnerNotSigner);
+    }
+
+    let delegate_info = accounts
+        .remaining_accounts
+        .iter()
+        .find(|account| cmp_pubkeys(account.key, &mint_delegate.delegate))
+        .ok_or(CandyError::MissingMintDelegate)?;
+    let sysvar_instructions_info = accounts
+        .sysvar_instructions
+        .as_ref()
+        .ok_or(CandyError::MissingInstructionsSysvar)?;
+    let token_info = accounts
+        .token
+        .as_ref()
+        .ok_or(CandyError::MissingTokenAccount)?;
+    let token_record_info = accounts
+        .token_record
+        .as_ref()
+        .ok_or(CandyError::MissingTokenRecord)?;
+
+    match mint_delegate.role {
+        MintDelegateRole::Staking => {
+            DelegateStakingV1CpiBuilder::new(&accounts.token_metadata_program)
+                .delegate(delegate_info)
+                .metadata(&accounts.nft_metadata)
+                .master_edition(Some(&accounts.nft_master_edition))
+                .token_record(Some(token_record_info))
+                .mint(&accounts.nft_mint)
+                .token(token_info)
+                .authority(&accounts.nft_owner)
+                .payer(&accounts.payer)
+                .system_program(&accounts.system_program)
+                .sysvar_instructions(sysvar_instructions_info)
+                .spl_token_program(Some(&accounts.spl_token_program))
+                .authorization_rules_program(accounts.authorization_rules_program.as_ref())
+                .authorization_rules(accounts.authorization_rules.as_ref())
+                .amount(1)
+                .invoke()
+        }
+        MintDelegateRole::LockedTransfer => {
+            DelegateLockedTransferV1CpiBuilder::new(&accounts.token_metadata_program)
+                .delegate(delegate_info)
+                .metadata(&accounts.nft_metadata)
+                .master_edition(Some(&accounts.nft_master_edition))
+                .token_record(Some(token_record_info))
+                .mint(&accounts.nft_mint)
+                .token(token_info)
+                .authority(&accounts.nft_owner)
+                .payer(&accounts.payer)
+                .system_program(&accounts.system_program)
+                .sysvar_instructions(sysvar_instructions_info)
+                .spl_token_program(Some(&accounts.spl_token_program))
+                .authorization_rules_program(accounts.authorization_rules_program.as_ref())
+                .authorization_rules(accounts.authorization_rules.as_ref())
+                .amount(1)
+                .locked_address(mint_delegate.locked_address)
+                .invoke()
+        }
+        MintDelegateRole::Utility => {
+            DelegateUtilityV1CpiBuilder::new(&accounts.token_metadata_program)
+                .delegate(delegate_info)
+                .metadata(&accounts.nft_metadata)
+                .master_edition(Some(&accounts.nft_master_edition))
+                .token_record(Some(token_record_info))
+                .mint(&accounts.nft_mint)
+                .token(token_info)
+                .authority(&accounts.nft_owner)
+                .payer(&accounts.payer)
+                .system_program(&accounts.system_program)
+                .sysvar_instructions(sysvar_instructions_info)
+                .spl_token_program(Some(&accounts.spl_token_program))
+                .authorization_rules_program(accounts.authorization_rules_program.as_ref())
+                .authorization_rules(accounts.authorization_rules.as_ref())
+                .amount(1)
+                .invoke()
+        }
+    }
+    .map_err(|error| error.into())
+}
+
+/// Locks a newly minted item in the owner's token account, making it soulbound.
+///
+/// The authority PDA is approved as a delegate of the token (standard delegate for NFTs,
+/// utility delegate for Programmable NFTs) and locks it. Only the authority PDA can
+/// unlock (or burn) the item, so the NFT owner must sign to approve the delegate.
+fn lock_item(
+    candy_machine: &Account<'_, CandyMachine>,
+    accounts: &MintAccounts,
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    if !accounts.nft_owner.is_signer {
+        return err!(CandyError::SoulboundOwnerNotSigner);
+    }
 
-    VerifyCollectionV1CpiBuilder::new(&accounts.token_metadata_program)
+    let sysvar_instructions_info = accounts
+        .sysvar_instructions
+        .as_ref()
+        .ok_or(CandyError::MissingInstructionsSysvar)?;
+    let token_info = accounts
+        .token
+        .as_ref()
+        .ok_or(CandyError::MissingTokenAccount)?;
+
+    if candy_machine.token_standard == TokenStandard::ProgrammableNonFungible as u8 {
+        let token_record_info = accounts
+            .token_record
+            .as_ref()
+            .ok_or(CandyError::MissingTokenRecord)?;
+
+        DelegateUtilityV1CpiBuilder::new(&accounts.token_metadata_program)
+            .delegate(&accounts.authority_pda)
+            .metadata(&accounts.nft_metadata)
+            .master_edition(Some(&accounts.nft_master_edition))
+            .token_record(Some(token_record_info))
+            .mint(&accounts.nft_mint)
+            .token(token_info)
+            .authority(&accounts.nft_owner)
+            .payer(&accounts.payer)
+            .system_program(&accounts.system_program)
+            .sysvar_instructions(sysvar_instructions_info)
+            .spl_token_program(Some(&accounts.spl_token_program))
+            .authorization_rules_program(accounts.authorization_rules_program.as_ref())
+            .authorization_rules(accounts.authorization_rules.as_ref())
+            .amount(1)
+            .invoke()?;
+    } else {
+        DelegateStandardV1CpiBuilder::new(&accounts.token_metadata_program)
+            .delegate(&accounts.authority_pda)
+            .metadata(&accounts.nft_metadata)
+            .master_edition(Some(&accounts.nft_master_edition))
+            .mint(&accounts.nft_mint)
+            .token(token_info)
+            .authority(&accounts.nft_owner)
+            .payer(&accounts.payer)
+            .system_program(&accounts.system_program)
+            .sysvar_instructions(sysvar_instructions_info)
+            .spl_token_program(Some(&accounts.spl_token_program))
+            .amount(1)
+            .invoke()?;
+    }
+
+    LockV1CpiBuilder::new(&accounts.token_metadata_program)
         .authority(&accounts.authority_pda)
-        .delegate_record(Some(&accounts.collection_delegate_record))
+        .token_owner(Some(&accounts.nft_owner))
+        .token(token_info)
+        .mint(&accounts.nft_mint)
         .metadata(&accounts.nft_metadata)
-        .collection_mint(&accounts.collection_mint)
-        .collection_metadata(Some(&accounts.collection_metadata))
-        .collection_master_edition(Some(&accounts.collection_master_edition))
+        .edition(Some(&accounts.nft_master_edition))
+        .token_record(accounts.token_record.as_ref())
+        .payer(&accounts.payer)
         .system_program(&accounts.system_program)
         .sysvar_instructions(sysvar_instructions_info)
-        .invoke_signed(&[&authority_seeds])
+        .spl_token_program(Some(&accounts.spl_token_program))
+        .authorization_rules_program(accounts.authorization_rules_program.as_ref())
+        .authorization_rules(accounts.authorization_rules.as_ref())
+        .invoke_signed(&[authority_seeds])
         .map_err(|error| error.into())
 }
 
@@ -478,10 +1592,12 @@ fn create(
 
     // update metadata account
 
+    let update_authority =
+        get_item_update_authority(candy_machine, &accounts, Some(&collection_metadata));
     UpdateMetadataAccountV2CpiBuilder::new(&accounts.token_metadata_program)
         .metadata(&accounts.nft_metadata)
         .update_authority(&accounts.authority_pda)
-        .new_update_authority(collection_metadata.update_authority)
+        .new_update_authority(update_authority)
         .primary_sale_happened(true)
         .invoke_signed(&[&authority_seeds])?;
 
@@ -514,6 +1630,25 @@ fn create(
     }
 }
 
+/// Returns the update authority of a newly minted item.
+///
+/// Items of candy machines using the on-chain reveal keep the authority PDA as the update
+/// authority until they are revealed; otherwise, the collection update authority is used
+/// or, for candy machines without a collection, the candy machine authority.
+pub(super) fn get_item_update_authority(
+    candy_machine: &Account<'_, CandyMachine>,
+    accounts: &MintAccounts,
+    collection_metadata: Option<&Metadata>,
+) -> Pubkey {
+    if candy_machine.is_feature_enabled(ONCHAIN_REVEAL_FEATURE) {
+        accounts.authority_pda.key()
+    } else if let Some(collection_metadata) = collection_metadata {
+        collection_metadata.update_authority
+    } else {
+        candy_machine.authority
+    }
+}
+
 /// Mints a new NFT.
 #[derive(Accounts)]
 pub struct MintV2<'info> {
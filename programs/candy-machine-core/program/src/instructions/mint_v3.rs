@@ -0,0 +1,311 @@
+use anchor_lang::prelude::*;
+use mpl_bubblegum::programs::{SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID};
+use solana_program::sysvar;
+
+use super::mint_v2::{process_mint, MintAccounts};
+use crate::{
+    constants::{AUTHORITY_SEED, CANDY_GUARD_PROGRAM, MPL_TOKEN_AUTH_RULES_PROGRAM},
+    utils::*,
+    CandyError, CandyMachine, MintArgs,
+};
+
+pub fn mint_v3<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintV3<'info>>,
+    args: MintArgs,
+) -> Result<()> {
+    // requested items must be restricted by the guards (e.g., an item range), so the
+    // mint authority must be a candy guard
+    if args.index.is_some() && !cmp_pubkeys(ctx.accounts.mint_authority.owner, &CANDY_GUARD_PROGRAM)
+    {
+        return err!(CandyError::PickItemRequiresCandyGuard);
+    }
+
+    let accounts = MintAccounts {
+        spl_ata_program: ctx
+            .accounts
+            .spl_ata_program
+            .as_ref()
+            .map(|spl_ata_program| spl_ata_program.to_account_info()),
+        authority_pda: ctx.accounts.authority_pda.to_account_info(),
+        collection_delegate_record: ctx.accounts.collection_delegate_record.to_account_info(),
+        collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+        collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+        collection_mint: ctx.accounts.collection_mint.to_account_info(),
+        collection_update_authority: ctx.accounts.collection_update_authority.to_account_info(),
+        nft_owner: ctx.accounts.nft_owner.to_account_info(),
+        nft_master_edition: ctx.accounts.nft_master_edition.to_account_info(),
+        nft_metadata: ctx.accounts.nft_metadata.to_account_info(),
+        nft_mint: ctx.accounts.nft_mint.to_account_info(),
+        nft_mint_authority: ctx.accounts.nft_mint_authority.to_account_info(),
+        payer: ctx.accounts.payer.to_account_info(),
+        recent_slothashes: ctx.accounts.recent_slothashes.to_account_info(),
+        authorization_rules_program: ctx
+            .accounts
+            .authorization_rules_program
+            .as_ref()
+            .map(|authorization_rules_program| authorization_rules_program.to_account_info()),
+        authorization_rules: ctx
+            .accounts
+            .authorization_rules
+            .as_ref()
+            .map(|authorization_rules| authorization_rules.to_account_info()),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        sysvar_instructions: Some(ctx.accounts.sysvar_instructions.to_account_info()),
+        token: ctx
+            .accounts
+            .token
+            .as_ref()
+            .map(|token| token.to_account_info()),
+        token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+        spl_token_program: ctx.accounts.spl_token_program.to_account_info(),
+        token_record: ctx
+            .accounts
+            .token_record
+            .as_ref()
+            .map(|token_record| token_record.to_account_info()),
+        mpl_core_program: ctx
+            .accounts
+            .mpl_core_program
+            .as_ref()
+            .map(|mpl_core_program| mpl_core_program.to_account_info()),
+        merkle_tree: ctx
+            .accounts
+            .merkle_tree
+            .as_ref()
+            .map(|merkle_tree| merkle_tree.to_account_info()),
+        tree_config: ctx
+            .accounts
+            .tree_config
+            .as_ref()
+            .map(|tree_config| tree_config.to_account_info()),
+        bubblegum_signer: ctx
+            .accounts
+            .bubblegum_signer
+            .as_ref()
+            .map(|bubblegum_signer| bubblegum_signer.to_account_info()),
+        bubblegum_program: ctx
+            .accounts
+            .bubblegum_program
+            .as_ref()
+            .map(|bubblegum_program| bubblegum_program.to_account_info()),
+        log_wrapper: ctx
+            .accounts
+            .log_wrapper
+            .as_ref()
+            .map(|log_wrapper| log_wrapper.to_account_info()),
+        compression_program: ctx
+            .accounts
+            .compression_program
+            .as_ref()
+            .map(|compression_program| compression_program.to_account_info()),
+        spl_token_2022_program: ctx
+            .accounts
+            .spl_token_2022_program
+            .as_ref()
+            .map(|spl_token_2022_program| spl_token_2022_program.to_account_info()),
+        mint_request: ctx
+            .accounts
+            .mint_request
+            .as_ref()
+            .map(|mint_request| mint_request.to_account_info()),
+        mint_receipt: ctx
+            .accounts
+            .mint_receipt
+            .as_ref()
+            .map(|mint_receipt| mint_receipt.to_account_info()),
+        remaining_accounts: ctx.remaining_accounts.to_vec(),
+    };
+
+    process_mint(
+        &mut ctx.accounts.candy_machine,
+        accounts,
+        ctx.bumps["authority_pda"],
+        args.index,
+        args.config_line.as_ref(),
+        args.group.as_deref(),
+    )
+}
+
+/// Mints a new item with mint arguments.
+#[derive(Accounts)]
+pub struct MintV3<'info> {
+    /// Candy machine account.
+    #[account(mut, has_one = mint_authority)]
+    candy_machine: Box<Account<'info, CandyMachine>>,
+
+    /// Candy machine authority account. This is the account that holds a delegate
+    /// to verify an item into the collection.
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(mut, seeds = [AUTHORITY_SEED.as_bytes(), candy_machine.key().as_ref()], bump)]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Candy machine mint authority (mint only allowed for the mint_authority).
+    mint_authority: Signer<'info>,
+
+    /// Payer for the transaction and account allocation (rent).
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// NFT account owner.
+    ///
+    /// CHECK: account not written or read from
+    nft_owner: UncheckedAccount<'info>,
+
+    /// Mint account of the NFT. The account will be initialized if necessary.
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    nft_mint: UncheckedAccount<'info>,
+
+    /// Mint authority of the NFT. In most cases this will be the owner of the NFT.
+    nft_mint_authority: Signer<'info>,
+
+    /// Metadata account of the NFT. This account must be uninitialized.
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    nft_metadata: UncheckedAccount<'info>,
+
+    /// Master edition account of the NFT. The account will be initialized if necessary.
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    nft_master_edition: UncheckedAccount<'info>,
+
+    /// Destination token account (required for pNFT).
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    token: Option<UncheckedAccount<'info>>,
+
+    /// Token record (required for pNFT).
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    token_record: Option<UncheckedAccount<'info>>,
+
+    /// Collection authority or metadata delegate record.
+    ///
+    /// CHECK: account checked in CPI
+    collection_delegate_record: UncheckedAccount<'info>,
+
+    /// Mint account of the collection NFT.
+    ///
+    /// CHECK: account checked in CPI
+    collection_mint: UncheckedAccount<'info>,
+
+    /// Metadata account of the collection NFT.
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    collection_metadata: UncheckedAccount<'info>,
+
+    /// Master edition account of the collection NFT.
+    ///
+    /// CHECK: account checked in CPI
+    collection_master_edition: UncheckedAccount<'info>,
+
+    /// Update authority of the collection NFT.
+    ///
+    /// CHECK: account checked in CPI
+    collection_update_authority: UncheckedAccount<'info>,
+
+    /// Token Metadata program.
+    ///
+    /// CHECK: account checked in CPI
+    #[account(address = mpl_token_metadata::ID)]
+    token_metadata_program: UncheckedAccount<'info>,
+
+    /// SPL Token program.
+    spl_token_program: Program<'info, Token>,
+
+    /// SPL Associated Token program.
+    spl_ata_program: Option<Program<'info, AssociatedToken>>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+
+    /// Instructions sysvar account.
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(address = sysvar::instructions::id())]
+    sysvar_instructions: UncheckedAccount<'info>,
+
+    /// SlotHashes sysvar cluster data.
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(address = sysvar::slot_hashes::id())]
+    recent_slothashes: UncheckedAccount<'info>,
+
+    /// Token Authorization Rules program.
+    ///
+    /// CHECK: account checked in CPI
+    #[account(address = MPL_TOKEN_AUTH_RULES_PROGRAM)]
+    authorization_rules_program: Option<UncheckedAccount<'info>>,
+
+    /// Token Authorization rules account for the collection metadata (if any).
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(owner = MPL_TOKEN_AUTH_RULES_PROGRAM)]
+    authorization_rules: Option<UncheckedAccount<'info>>,
+
+    /// MPL Core program (required for Core assets).
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(address = mpl_core::ID)]
+    mpl_core_program: Option<UncheckedAccount<'info>>,
+
+    /// Merkle tree account (required for compressed NFTs).
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    merkle_tree: Option<UncheckedAccount<'info>>,
+
+    /// Tree config account of the merkle tree (required for compressed NFTs).
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    tree_config: Option<UncheckedAccount<'info>>,
+
+    /// Bubblegum collection signer (required for compressed NFTs).
+    ///
+    /// CHECK: account checked in CPI
+    bubblegum_signer: Option<UncheckedAccount<'info>>,
+
+    /// Bubblegum program (required for compressed NFTs).
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(address = mpl_bubblegum::ID)]
+    bubblegum_program: Option<UncheckedAccount<'info>>,
+
+    /// SPL Noop program (required for compressed NFTs).
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(address = SPL_NOOP_ID)]
+    log_wrapper: Option<UncheckedAccount<'info>>,
+
+    /// SPL Account Compression program (required for compressed NFTs).
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    compression_program: Option<UncheckedAccount<'info>>,
+
+    /// SPL Token-2022 program (required for Token-2022 NFTs).
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(address = spl_token_2022::ID)]
+    spl_token_2022_program: Option<UncheckedAccount<'info>>,
+
+    /// Mint request account (required when commit-reveal is enabled).
+    ///
+    /// CHECK: account checked in handler
+    #[account(mut)]
+    mint_request: Option<UncheckedAccount<'info>>,
+
+    /// Mint receipt account (required when mint receipts are enabled).
+    ///
+    /// CHECK: account checked in handler
+    #[account(mut)]
+    mint_receipt: Option<UncheckedAccount<'info>>,
+}
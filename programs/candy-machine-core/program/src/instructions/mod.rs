@@ -1,25 +1,85 @@
+pub mod add_config_line_page;
 pub mod add_config_lines;
+pub mod close_mint_receipt;
+pub mod close_mint_request;
+pub mod finalize;
 pub mod initialize;
 pub mod initialize_v2;
+pub mod initialize_v3;
+pub mod initialize_without_collection;
 pub mod mint;
+pub mod mint_batch;
 pub mod mint_v2;
+pub mod mint_v3;
+pub mod release_soulbound;
+pub mod remove_config_line;
+pub mod resize_config_lines;
+pub mod reveal_item;
+pub mod reveal_mint;
 pub mod set_authority;
 pub mod set_collection;
 pub mod set_collection_v2;
+pub mod set_commit_reveal;
+pub mod set_config_lines_root;
+pub mod set_fungible_amount;
+pub mod set_items_available;
+pub mod set_master_edition;
 pub mod set_mint_authority;
+pub mod set_mint_delegate;
+pub mod set_mint_receipt;
+pub mod set_onchain_reveal;
+pub mod set_paused;
+pub mod set_pick_item;
+pub mod set_rarity_tiers;
+pub mod set_soulbound;
 pub mod set_token_standard;
+pub mod set_uri_encoding;
 pub mod update;
+pub mod view;
 pub mod withdraw;
 
+mod mint_compressed;
+mod mint_core;
+mod mint_fungible;
+mod mint_print_edition;
+mod mint_token_2022;
+
+pub use add_config_line_page::*;
 pub use add_config_lines::*;
+pub use close_mint_receipt::*;
+pub use close_mint_request::*;
+pub use finalize::*;
 pub use initialize::*;
 pub use initialize_v2::*;
+pub use initialize_v3::*;
+pub use initialize_without_collection::*;
 pub use mint::*;
+pub use mint_batch::*;
 pub use mint_v2::*;
+pub use mint_v3::*;
+pub use release_soulbound::*;
+pub use remove_config_line::*;
+pub use resize_config_lines::*;
+pub use reveal_item::*;
+pub use reveal_mint::*;
 pub use set_authority::*;
 pub use set_collection::*;
 pub use set_collection_v2::*;
+pub use set_commit_reveal::*;
+pub use set_config_lines_root::*;
+pub use set_fungible_amount::*;
+pub use set_items_available::*;
+pub use set_master_edition::*;
 pub use set_mint_authority::*;
+pub use set_mint_delegate::*;
+pub use set_mint_receipt::*;
+pub use set_onchain_reveal::*;
+pub use set_paused::*;
+pub use set_pick_item::*;
+pub use set_rarity_tiers::*;
+pub use set_soulbound::*;
 pub use set_token_standard::*;
+pub use set_uri_encoding::*;
 pub use update::*;
+pub use view::*;
 pub use withdraw::*;
@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use mpl_token_metadata::{
+    accounts::Metadata,
+    instructions::{BurnV1CpiBuilder, UnlockV1CpiBuilder},
+};
+use solana_program::sysvar;
+
+use crate::{
+    constants::{AUTHORITY_SEED, MPL_TOKEN_AUTH_RULES_PROGRAM},
+    utils::{cmp_pubkeys, Token},
+    CandyError, CandyMachine,
+};
+
+pub fn release_soulbound(ctx: Context<ReleaseSoulbound>, burn: bool) -> Result<()> {
+    let accounts = ctx.accounts;
+    let candy_machine = &accounts.candy_machine;
+
+    // the item must have been minted by the candy machine (the authority PDA is a
+    // verified creator)
+
+    let metadata = Metadata::try_from(&accounts.nft_metadata.to_account_info())?;
+
+    if !cmp_pubkeys(&metadata.mint, accounts.nft_mint.key) {
+        return err!(CandyError::MintMismatch);
+    }
+
+    let is_candy_machine_item = match &metadata.creators {
+        Some(creators) if !creators.is_empty() => {
+            cmp_pubkeys(&creators[0].address, accounts.authority_pda.key) && creators[0].verified
+        }
+        _ => false,
+    };
+
+    if !is_candy_machine_item {
+        return err!(CandyError::InvalidSoulboundItem);
+    }
+
+    let candy_machine_key = candy_machine.key();
+    let authority_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        candy_machine_key.as_ref(),
+        &[ctx.bumps["authority_pda"]],
+    ];
+
+    // the authority PDA is the delegate that locked the item
+
+    UnlockV1CpiBuilder::new(&accounts.token_metadata_program)
+        .authority(&accounts.authority_pda)
+        .token_owner(Some(&accounts.token_owner))
+        .token(&accounts.token)
+        .mint(&accounts.nft_mint)
+        .metadata(&accounts.nft_metadata)
+        .edition(Some(&accounts.nft_master_edition))
+        .token_record(accounts.token_record.as_deref())
+        .payer(&accounts.payer)
+        .system_program(&accounts.system_program)
+        .sysvar_instructions(&accounts.sysvar_instructions)
+        .spl_token_program(Some(&accounts.spl_token_program))
+        .authorization_rules_program(accounts.authorization_rules_program.as_deref())
+        .authorization_rules(accounts.authorization_rules.as_deref())
+        .invoke_signed(&[&authority_seeds])?;
+
+    if burn {
+        BurnV1CpiBuilder::new(&accounts.token_metadata_program)
+            .authority(&accounts.authority_pda)
+            .collection_metadata(accounts.collection_metadata.as_deref())
+            .metadata(&accounts.nft_metadata)
+            .edition(Some(&accounts.nft_master_edition))
+            .mint(&accounts.nft_mint)
+            .token(&accounts.token)
+            .token_record(accounts.token_record.as_deref())
+            .system_program(&accounts.system_program)
+            .sysvar_instructions(&accounts.sysvar_instructions)
+            .spl_token_program(&accounts.spl_token_program)
+            .amount(1)
+            .invoke_signed(&[&authority_seeds])?;
+
+        msg!("Soulbound item burned");
+    } else {
+        msg!("Soulbound item unlocked");
+    }
+
+    Ok(())
+}
+
+/// Unlocks or burns a soulbound item.
+#[derive(Accounts)]
+pub struct ReleaseSoulbound<'info> {
+    /// Candy Machine account.
+    #[account(has_one = authority)]
+    candy_machine: Box<Account<'info, CandyMachine>>,
+
+    /// Candy Machine authority.
+    authority: Signer<'info>,
+
+    /// Authority PDA, the delegate that locked the item.
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(seeds = [AUTHORITY_SEED.as_bytes(), candy_machine.key().as_ref()], bump)]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Payer of the transaction.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// Owner of the item.
+    ///
+    /// CHECK: account checked in CPI
+    token_owner: UncheckedAccount<'info>,
+
+    /// Token account of the item.
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    token: UncheckedAccount<'info>,
+
+    /// Mint account of the item.
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    nft_mint: UncheckedAccount<'info>,
+
+    /// Metadata account of the item.
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut, owner = mpl_token_metadata::ID)]
+    nft_metadata: UncheckedAccount<'info>,
+
+    /// Master edition account of the item.
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    nft_master_edition: UncheckedAccount<'info>,
+
+    /// Token record of the item (required for Programmable NFTs).
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    token_record: Option<UncheckedAccount<'info>>,
+
+    /// Metadata account of the collection (required to burn items of a sized
+    /// collection).
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    collection_metadata: Option<UncheckedAccount<'info>>,
+
+    /// Token Metadata program.
+    ///
+    /// CHECK: account constraint checked in account trait
+    #[account(address = mpl_token_metadata::ID)]
+    token_metadata_program: UncheckedAccount<'info>,
+
+    /// SPL Token program.
+    spl_token_program: Program<'info, Token>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+
+    /// Instructions sysvar account.
+    ///
+    /// CHECK: account constraint checked in account trait
+    #[account(address = sysvar::instructions::id())]
+    sysvar_instructions: UncheckedAccount<'info>,
+
+    /// Token Authorization Rules program.
+    ///
+    /// CHECK: account constraint checked in account trait
+    #[account(address = MPL_TOKEN_AUTH_RULES_PROGRAM)]
+    authorization_rules_program: Option<UncheckedAccount<'info>>,
+
+    /// Token Authorization rules account of the item (if any).
+    ///
+    /// CHECK: account checked in CPI
+    #[account(owner = MPL_TOKEN_AUTH_RULES_PROGRAM)]
+    authorization_rules: Option<UncheckedAccount<'info>>,
+}
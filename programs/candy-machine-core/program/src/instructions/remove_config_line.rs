@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use mpl_utils::resize_or_reallocate_account_raw;
+
+use crate::{
+    constants::{
+        FINALIZED_FEATURE, ITEMS_REMOVED_LENGTH, ITEMS_SKIPPED_LENGTH, MERKLE_CONFIG_LINES_FEATURE,
+    },
+    get_config_count,
+    instructions::mint_v2::{is_item_available, set_item_taken, set_items_skipped},
+    CandyError, CandyMachine,
+};
+
+pub fn remove_config_line(ctx: Context<RemoveConfigLine>, index: u32) -> Result<()> {
+    let candy_machine = &mut ctx.accounts.candy_machine;
+
+    // config lines can no longer change once finalized
+    if candy_machine.is_feature_enabled(FINALIZED_FEATURE) {
+        return err!(CandyError::ConfigLinesFinalized);
+    }
+
+    // hidden settings candies do not store config lines
+    if candy_machine.data.hidden_settings.is_some() {
+        return err!(CandyError::HiddenSettingsDoNotHaveConfigLines);
+    }
+
+    let is_sequential = candy_machine
+        .data
+        .config_line_settings
+        .as_ref()
+        .ok_or(CandyError::MissingConfigLinesSettings)?
+        .is_sequential;
+
+    if index as u64 >= candy_machine.data.items_available {
+        return err!(CandyError::IndexGreaterThanLength);
+    }
+
+    // the mint indices are only complete after all config lines are added
+    if !candy_machine.is_feature_enabled(MERKLE_CONFIG_LINES_FEATURE) {
+        let account_info = candy_machine.to_account_info();
+        let config_count = get_config_count(&account_info.data.borrow())? as u64;
+
+        if config_count != candy_machine.data.items_available {
+            return err!(CandyError::NotFullyLoaded);
+        }
+    }
+
+    // the loaded bit-mask must keep track of the items taken, so removed config lines
+    // are not minted
+    if !candy_machine.is_items_tracked() {
+        return err!(CandyError::ItemsNotTracked);
+    }
+
+    // the available items must not change while requests are waiting to be revealed
+    if candy_machine.pending_requests() > 0 {
+        return err!(CandyError::PendingMintRequests);
+    }
+
+    let candy_machine_info = candy_machine.to_account_info();
+    let (items_removed, items_skipped) = {
+        let account_data = candy_machine_info.data.borrow();
+
+        if candy_machine.get_items_remaining(&account_data)? == 0 {
+            return err!(CandyError::CandyMachineEmpty);
+        }
+
+        if !is_item_available(candy_machine, &account_data, index)? {
+            return err!(CandyError::ItemNotAvailable);
+        }
+
+        (
+            candy_machine.get_items_removed(&account_data)?,
+            candy_machine.get_items_skipped(&account_data)?,
+        )
+    };
+
+    // a removed item is no longer available to mint, reducing the number of items left
+    // to mint without counting as redeemed
+    let items_removed = items_removed
+        .checked_add(1)
+        .ok_or(CandyError::NumericalOverflowError)?;
+    let position = candy_machine.get_items_removed_position()?;
+    let skipped_position = candy_machine.get_items_skipped_position()?;
+
+    if candy_machine_info.data_len() < skipped_position + ITEMS_SKIPPED_LENGTH {
+        msg!("Allocating space to store the number of removed config lines");
+
+        resize_or_reallocate_account_raw(
+            &candy_machine_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            skipped_position + ITEMS_SKIPPED_LENGTH,
+        )?;
+    }
+
+    let mut account_data = candy_machine_info.data.borrow_mut();
+    account_data[position..position + ITEMS_REMOVED_LENGTH]
+        .copy_from_slice(&items_removed.to_le_bytes());
+
+    // sequential candies skip removed config lines when minting, while random candies
+    // skip them when they are selected, since they are still on the available mint
+    // indices
+    set_item_taken(candy_machine, &mut account_data, index)?;
+
+    if !is_sequential {
+        let items_skipped = items_skipped
+            .checked_add(1)
+            .ok_or(CandyError::NumericalOverflowError)?;
+        set_items_skipped(candy_machine, &mut account_data, items_skipped)?;
+    }
+
+    msg!("Config line removed: index={}", index);
+
+    Ok(())
+}
+
+/// Removes a config line that was not minted.
+#[derive(Accounts)]
+pub struct RemoveConfigLine<'info> {
+    /// Candy Machine account.
+    #[account(mut, has_one = authority)]
+    candy_machine: Account<'info, CandyMachine>,
+
+    /// Candy Machine authority.
+    authority: Signer<'info>,
+
+    /// Payer of the transaction.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+}
@@ -0,0 +1,146 @@
+use anchor_lang::{prelude::*, solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE};
+use mpl_utils::resize_or_reallocate_account_raw;
+
+use crate::{
+    constants::{FINALIZED_FEATURE, MERKLE_CONFIG_LINES_FEATURE},
+    CandyError, CandyMachine, UriEncoding,
+};
+
+pub fn resize_config_lines(
+    ctx: Context<ResizeConfigLines>,
+    name_length: u32,
+    uri_length: u32,
+) -> Result<()> {
+    let candy_machine = &mut ctx.accounts.candy_machine;
+
+    // config lines can no longer change once finalized
+    if candy_machine.is_feature_enabled(FINALIZED_FEATURE) {
+        return err!(CandyError::ConfigLinesFinalized);
+    }
+
+    // config lines of a page are stored at fixed positions
+    if candy_machine.has_config_line_pages() {
+        return err!(CandyError::ConfigLinePagesNotSupported);
+    }
+
+    // config lines are supplied at mint time when using a merkle root
+    if candy_machine.is_feature_enabled(MERKLE_CONFIG_LINES_FEATURE) {
+        return err!(CandyError::MerkleConfigLinesEnabled);
+    }
+
+    if candy_machine.has_started(&candy_machine.to_account_info().data.borrow())? {
+        return err!(CandyError::NoChangingConfigLinesDuringMint);
+    }
+
+    // hidden settings candies do not use config lines
+    if candy_machine.data.hidden_settings.is_some() {
+        return err!(CandyError::HiddenSettingsDoNotHaveConfigLines);
+    }
+
+    let settings = candy_machine
+        .data
+        .config_line_settings
+        .as_ref()
+        .ok_or(CandyError::MissingConfigLinesSettings)?;
+
+    if name_length < settings.name_length || uri_length < settings.uri_length {
+        return err!(CandyError::CannotDecreaseLength);
+    }
+
+    // encoded URIs are stored as raw bytes, so they cannot be padded
+    if uri_length != settings.uri_length && candy_machine.uri_encoding() != UriEncoding::Utf8 {
+        return err!(CandyError::CannotResizeEncodedUris);
+    }
+
+    let old_name_length = settings.name_length as usize;
+    let old_uri_length = settings.uri_length as usize;
+    let old_space = candy_machine.get_space()?;
+
+    let settings = candy_machine
+        .data
+        .config_line_settings
+        .as_mut()
+        .ok_or(CandyError::MissingConfigLinesSettings)?;
+    settings.name_length = name_length;
+    settings.uri_length = uri_length;
+
+    // makes sure the new lengths are within the maximum allowed values
+    candy_machine.data.validate()?;
+
+    let new_space = candy_machine.get_space()?;
+    let candy_machine_info = candy_machine.to_account_info();
+    let old_length = candy_machine_info.data_len();
+    // the rule set, config lines root and rarity tiers (if any) are stored after the
+    // config lines section
+    let new_length = new_space + (old_length - old_space);
+
+    // the account can only grow by MAX_PERMITTED_DATA_INCREASE bytes per instruction
+    if new_length - old_length > MAX_PERMITTED_DATA_INCREASE {
+        return err!(CandyError::AccountGrowthTooLarge);
+    }
+
+    msg!(
+        "Resizing account from {} to {} bytes",
+        old_length,
+        new_length
+    );
+
+    resize_or_reallocate_account_raw(
+        &candy_machine_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        new_length,
+    )?;
+
+    let mut account_data = candy_machine_info.data.borrow_mut();
+    let items_available = candy_machine.data.items_available as usize;
+    let hidden_section = candy_machine.get_hidden_section()?;
+    let old_line_size = old_name_length + old_uri_length;
+    let new_line_size = (name_length + uri_length) as usize;
+
+    // (1) the loaded bit-mask, mint indices and the remaining bytes are moved together
+
+    let old_lines_end = hidden_section + 4 + items_available * old_line_size;
+    let new_lines_end = hidden_section + 4 + items_available * new_line_size;
+    account_data.copy_within(old_lines_end..old_length, new_lines_end);
+
+    // (2) config lines are moved starting from the last one (and the uri before the
+    // name), so a line is never overwritten before it is moved
+
+    for index in (0..items_available).rev() {
+        let old_position = hidden_section + 4 + index * old_line_size;
+        let new_position = hidden_section + 4 + index * new_line_size;
+        let old_uri_position = old_position + old_name_length;
+        let new_uri_position = new_position + name_length as usize;
+
+        account_data.copy_within(
+            old_uri_position..old_uri_position + old_uri_length,
+            new_uri_position,
+        );
+        account_data.copy_within(old_position..old_uri_position, new_position);
+
+        // clears the padding of the name and uri
+        account_data[new_position + old_name_length..new_uri_position].fill(0);
+        account_data[new_uri_position + old_uri_length..new_position + new_line_size].fill(0);
+    }
+
+    Ok(())
+}
+
+/// Increases the name and uri lengths of the config lines.
+#[derive(Accounts)]
+pub struct ResizeConfigLines<'info> {
+    /// Candy Machine account.
+    #[account(mut, has_one = authority)]
+    candy_machine: Account<'info, CandyMachine>,
+
+    /// Candy Machine authority.
+    authority: Signer<'info>,
+
+    /// Payer of the transaction.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+}
@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+use mpl_token_metadata::{accounts::Metadata, instructions::UpdateV1CpiBuilder, types::Data};
+use solana_program::sysvar;
+
+use crate::{
+    constants::{AUTHORITY_SEED, NULL_STRING, ONCHAIN_REVEAL_FEATURE},
+    utils::*,
+    CandyError, CandyMachine, ConfigLineProof,
+};
+
+pub fn reveal_item(
+    ctx: Context<RevealItem>,
+    index: u32,
+    config_line: ConfigLineProof,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+    let candy_machine = &accounts.candy_machine;
+
+    let hidden_settings = candy_machine
+        .data
+        .hidden_settings
+        .as_ref()
+        .ok_or(CandyError::MissingHiddenSettings)?;
+
+    // the hidden settings hash is only interpreted as the merkle root of the revealed
+    // items when the on-chain reveal is enabled
+    if !candy_machine.is_feature_enabled(ONCHAIN_REVEAL_FEATURE) {
+        return err!(CandyError::OnchainRevealNotEnabled);
+    }
+
+    let items_minted = candy_machine
+        .items_redeemed
+        .checked_sub(candy_machine.pending_requests() as u64)
+        .ok_or(CandyError::NumericalOverflowError)?;
+
+    if index as u64 >= items_minted {
+        return err!(CandyError::ItemNotAvailable);
+    }
+
+    // the item must have been minted by the candy machine (the authority PDA is a
+    // verified creator) and not revealed yet (the authority PDA is still the update
+    // authority)
+
+    let metadata = Metadata::try_from(&accounts.nft_metadata.to_account_info())?;
+
+    if !cmp_pubkeys(&metadata.mint, accounts.nft_mint.key) {
+        return err!(CandyError::MintMismatch);
+    }
+
+    let is_candy_machine_item = match &metadata.creators {
+        Some(creators) if !creators.is_empty() => {
+            cmp_pubkeys(&creators[0].address, accounts.authority_pda.key) && creators[0].verified
+        }
+        _ => false,
+    };
+
+    if !is_candy_machine_item
+        || !cmp_pubkeys(&metadata.update_authority, accounts.authority_pda.key)
+    {
+        return err!(CandyError::InvalidRevealItem);
+    }
+
+    // the hidden name and uri include the index of the item
+    if metadata.name.trim_end_matches(NULL_STRING)
+        != replace_patterns(hidden_settings.name.clone(), index as usize)
+        || metadata.uri.trim_end_matches(NULL_STRING)
+            != replace_patterns(hidden_settings.uri.clone(), index as usize)
+    {
+        return err!(CandyError::InvalidRevealItem);
+    }
+
+    // the hidden settings hash is the merkle root of the revealed items
+    let leaf = hash_config_line(index, &config_line.name, &config_line.uri);
+
+    if !verify_merkle_proof(&config_line.proof, &hidden_settings.hash, &leaf) {
+        return err!(CandyError::InvalidConfigLineProof);
+    }
+
+    // items of candy machines without a collection are transferred to the candy machine
+    // authority
+    let update_authority = if candy_machine.has_collection() {
+        let collection_metadata_info = accounts
+            .collection_metadata
+            .as_ref()
+            .ok_or(CandyError::MissingCollectionMetadata)?;
+
+        if !cmp_pubkeys(collection_metadata_info.owner, &mpl_token_metadata::ID) {
+            return err!(CandyError::IncorrectOwner);
+        }
+
+        let collection_metadata = Metadata::try_from(&collection_metadata_info.to_account_info())?;
+
+        if !cmp_pubkeys(&collection_metadata.mint, &candy_machine.collection_mint) {
+            return err!(CandyError::CollectionKeyMismatch);
+        }
+
+        collection_metadata.update_authority
+    } else {
+        candy_machine.authority
+    };
+
+    let candy_machine_key = candy_machine.key();
+    let authority_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        candy_machine_key.as_ref(),
+        &[ctx.bumps["authority_pda"]],
+    ];
+
+    // updates the metadata and transfers the update authority to the collection
+    // update authority
+
+    UpdateV1CpiBuilder::new(&accounts.token_metadata_program)
+        .authority(&accounts.authority_pda)
+        .metadata(&accounts.nft_metadata)
+        .edition(Some(&accounts.nft_master_edition))
+        .mint(&accounts.nft_mint)
+        .payer(&accounts.payer)
+        .system_program(&accounts.system_program)
+        .sysvar_instructions(&accounts.sysvar_instructions)
+        .data(Data {
+            name: config_line.name,
+            symbol: metadata.symbol.trim_end_matches(NULL_STRING).to_string(),
+            uri: config_line.uri,
+            seller_fee_basis_points: metadata.seller_fee_basis_points,
+            creators: metadata.creators,
+        })
+        .new_update_authority(update_authority)
+        .invoke_signed(&[&authority_seeds])?;
+
+    msg!("Item {} revealed", index);
+
+    Ok(())
+}
+
+/// Reveals a hidden settings item.
+#[derive(Accounts)]
+pub struct RevealItem<'info> {
+    /// Candy Machine account.
+    candy_machine: Box<Account<'info, CandyMachine>>,
+
+    /// Authority PDA, the update authority of items not revealed yet.
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(seeds = [AUTHORITY_SEED.as_bytes(), candy_machine.key().as_ref()], bump)]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Payer of the transaction.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// Mint account of the NFT.
+    ///
+    /// CHECK: account checked in CPI
+    nft_mint: UncheckedAccount<'info>,
+
+    /// Metadata account of the NFT.
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut, owner = mpl_token_metadata::ID)]
+    nft_metadata: UncheckedAccount<'info>,
+
+    /// Master edition account of the NFT.
+    ///
+    /// CHECK: account checked in CPI
+    nft_master_edition: UncheckedAccount<'info>,
+
+    /// Metadata account of the collection. Not used when the candy machine does not have
+    /// a collection.
+    ///
+    /// CHECK: account checked in handler
+    collection_metadata: Option<UncheckedAccount<'info>>,
+
+    /// Token Metadata program.
+    ///
+    /// CHECK: account checked in CPI
+    #[account(address = mpl_token_metadata::ID)]
+    token_metadata_program: UncheckedAccount<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+
+    /// Instructions sysvar account.
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(address = sysvar::instructions::id())]
+    sysvar_instructions: UncheckedAccount<'info>,
+}
@@ -2,17 +2,26 @@ use anchor_lang::{prelude::*, solana_program::sysvar};
 use mpl_token_metadata::accounts::Metadata;
 
 use crate::{
-    approve_metadata_delegate, cmp_pubkeys,
+    approve_core_delegate, approve_metadata_delegate, approve_token_group_authority, cmp_pubkeys,
     constants::{AUTHORITY_SEED, MPL_TOKEN_AUTH_RULES_PROGRAM},
-    revoke_collection_authority_helper, revoke_metadata_delegate, AccountVersion,
-    ApproveMetadataDelegateHelperAccounts, CandyError, CandyMachine,
-    RevokeCollectionAuthorityHelperAccounts, RevokeMetadataDelegateHelperAccounts,
+    events::SetCollectionEvent,
+    get_program_account, get_token_2022_update_authority, is_core_collection,
+    is_token_2022_collection, revoke_collection_authority_helper, revoke_core_delegate,
+    revoke_metadata_delegate, revoke_token_group_authority, set_group_authority, AccountVersion,
+    ApproveCoreDelegateHelperAccounts, ApproveMetadataDelegateHelperAccounts,
+    ApproveTokenGroupAuthorityHelperAccounts, CandyError, CandyMachine,
+    RevokeCollectionAuthorityHelperAccounts, RevokeCoreDelegateHelperAccounts,
+    RevokeMetadataDelegateHelperAccounts, RevokeTokenGroupAuthorityHelperAccounts,
 };
 
-pub fn set_collection_v2(ctx: Context<SetCollectionV2>) -> Result<()> {
+pub fn set_collection_v2<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetCollectionV2<'info>>,
+) -> Result<()> {
     let accounts = ctx.accounts;
     let candy_machine = &mut accounts.candy_machine;
 
+    let had_collection = candy_machine.has_collection();
+
     // check whether the new collection mint is the same as the current collection; when they
     // are the same, we are just using this instruction to update the collection delegate so
     // we don't enforce the "mint in progress" constraint
@@ -29,7 +38,67 @@ pub fn set_collection_v2(ctx: Context<SetCollectionV2>) -> Result<()> {
         candy_machine.collection_mint = accounts.new_collection_mint.key();
     }
 
-    if matches!(candy_machine.version, AccountVersion::V2) {
+    emit!(SetCollectionEvent {
+        candy_machine: candy_machine.key(),
+        old_collection_mint: accounts.collection_mint.key(),
+        new_collection_mint: accounts.new_collection_mint.key(),
+    });
+
+    if !had_collection {
+        // a collection can only be attached to candy machines without a collection before
+        // any mint, so there is no delegate to revoke; the token standard must be able to
+        // be minted into the new collection
+        if is_core_collection(&accounts.new_collection_mint)
+            || is_token_2022_collection(&accounts.new_collection_mint)
+        {
+            return err!(CandyError::InvalidTokenStandard);
+        }
+    } else if is_core_collection(&accounts.collection_mint) {
+        // revoking the existing Core update delegate
+
+        let revoke_accounts = RevokeCoreDelegateHelperAccounts {
+            payer: accounts.payer.to_account_info(),
+            authority_pda: accounts.authority_pda.to_account_info(),
+            collection: accounts.collection_mint.to_account_info(),
+            mpl_core_program: get_program_account(ctx.remaining_accounts, &mpl_core::ID)
+                .ok_or(CandyError::MissingMplCoreProgram)?,
+            system_program: accounts.system_program.to_account_info(),
+        };
+
+        revoke_core_delegate(
+            revoke_accounts,
+            candy_machine.key(),
+            *ctx.bumps.get("authority_pda").unwrap(),
+        )?;
+    } else if is_token_2022_collection(&accounts.collection_mint) {
+        // returning the group update authority of the Token-2022 collection
+
+        let revoke_accounts = RevokeTokenGroupAuthorityHelperAccounts {
+            authority_pda: accounts.authority_pda.to_account_info(),
+            collection: accounts.collection_mint.to_account_info(),
+            spl_token_2022_program: get_program_account(
+                ctx.remaining_accounts,
+                &spl_token_2022::ID,
+            )
+            .ok_or(CandyError::MissingSplToken2022Program)?,
+        };
+
+        // restores the group update authority recorded when it was transferred to the
+        // authority PDA
+        let update_authority = match candy_machine
+            .get_group_authority(&candy_machine.to_account_info().data.borrow())?
+        {
+            Some(update_authority) => update_authority,
+            None => get_token_2022_update_authority(&accounts.collection_mint)?,
+        };
+
+        revoke_token_group_authority(
+            revoke_accounts,
+            update_authority,
+            candy_machine.key(),
+            *ctx.bumps.get("authority_pda").unwrap(),
+        )?;
+    } else if !matches!(candy_machine.version, AccountVersion::V1) {
         // revoking the existing metadata delegate
 
         let revoke_accounts = RevokeMetadataDelegateHelperAccounts {
@@ -76,6 +145,47 @@ pub fn set_collection_v2(ctx: Context<SetCollectionV2>) -> Result<()> {
         candy_machine.version = AccountVersion::V2;
     }
 
+    if is_core_collection(&accounts.new_collection_mint) {
+        // approve a new Core update delegate
+
+        let delegate_accounts = ApproveCoreDelegateHelperAccounts {
+            payer: accounts.payer.to_account_info(),
+            authority_pda: accounts.authority_pda.to_account_info(),
+            collection: accounts.new_collection_mint.to_account_info(),
+            collection_update_authority: accounts.new_collection_update_authority.to_account_info(),
+            mpl_core_program: get_program_account(ctx.remaining_accounts, &mpl_core::ID)
+                .ok_or(CandyError::MissingMplCoreProgram)?,
+            system_program: accounts.system_program.to_account_info(),
+        };
+
+        return approve_core_delegate(delegate_accounts);
+    }
+
+    if is_token_2022_collection(&accounts.new_collection_mint) {
+        // transfer the group update authority of the new Token-2022 collection
+
+        let group_accounts = ApproveTokenGroupAuthorityHelperAccounts {
+            authority_pda: accounts.authority_pda.to_account_info(),
+            collection: accounts.new_collection_mint.to_account_info(),
+            collection_update_authority: accounts.new_collection_update_authority.to_account_info(),
+            spl_token_2022_program: get_program_account(
+                ctx.remaining_accounts,
+                &spl_token_2022::ID,
+            )
+            .ok_or(CandyError::MissingSplToken2022Program)?,
+        };
+
+        approve_token_group_authority(group_accounts)?;
+
+        return set_group_authority(
+            candy_machine,
+            &candy_machine.to_account_info(),
+            &accounts.payer.to_account_info(),
+            &accounts.system_program.to_account_info(),
+            accounts.new_collection_update_authority.key,
+        );
+    }
+
     // approve a new metadata delegate
 
     let delegate_accounts = ApproveMetadataDelegateHelperAccounts {
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::COMMIT_REVEAL_FEATURE, CandyError, CandyMachine};
+
+pub fn set_commit_reveal(ctx: Context<SetCommitReveal>, enabled: bool) -> Result<()> {
+    let candy_machine = &mut ctx.accounts.candy_machine;
+
+    // requests can be revealed by anyone, but locking soulbound items and approving a
+    // mint delegate require the signature of the owner
+    if enabled
+        && (candy_machine.is_soulbound()
+            || candy_machine
+                .get_mint_delegate(&candy_machine.to_account_info().data.borrow())?
+                .is_some())
+    {
+        return err!(CandyError::CommitRevealConflict);
+    }
+
+    candy_machine.set_feature(COMMIT_REVEAL_FEATURE, enabled);
+
+    Ok(())
+}
+
+/// Enables or disables the commit-reveal mint.
+#[derive(Accounts)]
+pub struct SetCommitReveal<'info> {
+    /// Candy Machine account.
+    #[account(mut, has_one = authority)]
+    candy_machine: Account<'info, CandyMachine>,
+
+    /// Candy Machine authority.
+    authority: Signer<'info>,
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use mpl_utils::resize_or_reallocate_account_raw;
+
+use crate::{
+    constants::{CONFIG_LINES_ROOT_LENGTH, FINALIZED_FEATURE, MERKLE_CONFIG_LINES_FEATURE},
+    get_config_count, CandyError, CandyMachine,
+};
+
+pub fn set_config_lines_root(ctx: Context<SetConfigLinesRoot>, root: [u8; 32]) -> Result<()> {
+    let candy_machine = &mut ctx.accounts.candy_machine;
+
+    // config lines can no longer change once finalized
+    if candy_machine.is_feature_enabled(FINALIZED_FEATURE) {
+        return err!(CandyError::ConfigLinesFinalized);
+    }
+
+    if candy_machine.items_redeemed > 0 {
+        return err!(CandyError::NoChangingConfigLinesRootDuringMint);
+    }
+
+    // hidden settings candies do not use config lines
+    if candy_machine.data.hidden_settings.is_some() {
+        return err!(CandyError::HiddenSettingsDoNotHaveConfigLines);
+    }
+
+    if candy_machine.data.config_line_settings.is_none() {
+        return err!(CandyError::MissingConfigLinesSettings);
+    }
+
+    // config lines are not stored when using a merkle root
+    if candy_machine.has_config_line_pages() {
+        return err!(CandyError::ConfigLinePagesNotSupported);
+    }
+
+    let candy_machine_info = candy_machine.to_account_info();
+    // the mint indices are not loaded when using a merkle root, so there should
+    // not be any config line on the account
+    if get_config_count(&candy_machine_info.data.borrow())? > 0 {
+        return err!(CandyError::ConfigLinesAlreadyLoaded);
+    }
+
+    if !candy_machine.is_feature_enabled(MERKLE_CONFIG_LINES_FEATURE) {
+        // config lines are not stored on the account when using a merkle root, so the
+        // space reserved for them is released
+        let old_space = candy_machine.get_space()?;
+        candy_machine.set_feature(MERKLE_CONFIG_LINES_FEATURE, true);
+        let new_space = candy_machine.get_space()?;
+
+        let old_length = candy_machine_info.data_len();
+        // the rule set, config lines root and rarity tiers (if any) are stored after the
+        // config lines section
+        let new_length = new_space + old_length.saturating_sub(old_space);
+
+        msg!(
+            "Resizing account from {} to {} bytes",
+            old_length,
+            new_length
+        );
+
+        // the loaded bit-mask, mint indices and the remaining bytes are moved together
+        let hidden_section = candy_machine.get_hidden_section()?;
+        let line_size = candy_machine.data.get_config_line_size();
+        let old_lines_end =
+            hidden_section + 4 + (candy_machine.data.items_available as usize) * line_size;
+        candy_machine_info
+            .data
+            .borrow_mut()
+            .copy_within(old_lines_end..old_length, hidden_section + 4);
+
+        candy_machine_info.realloc(new_length, false)?;
+
+        // the rent of the released space is returned to the payer
+        let minimum_balance = Rent::get()?.minimum_balance(new_length);
+        let surplus = candy_machine_info
+            .lamports()
+            .saturating_sub(minimum_balance);
+
+        if surplus > 0 {
+            let payer = ctx.accounts.payer.to_account_info();
+            **candy_machine_info.lamports.borrow_mut() -= surplus;
+            **payer.lamports.borrow_mut() = payer
+                .lamports()
+                .checked_add(surplus)
+                .ok_or(CandyError::NumericalOverflowError)?;
+        }
+    }
+
+    let position = candy_machine.get_config_lines_root_position()?;
+    let required_length = position + CONFIG_LINES_ROOT_LENGTH;
+
+    if candy_machine_info.data_len() < required_length {
+        msg!("Allocating space to store the config lines root");
+
+        resize_or_reallocate_account_raw(
+            &candy_machine_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            required_length,
+        )?;
+    }
+
+    candy_machine_info.data.borrow_mut()[position..required_length].copy_from_slice(&root);
+
+    // config lines are never loaded when using a merkle root, so the bit-mask keeps
+    // track of the items taken from the start
+    candy_machine.set_items_tracked(true);
+
+    Ok(())
+}
+
+/// Sets the merkle root of the config lines.
+#[derive(Accounts)]
+pub struct SetConfigLinesRoot<'info> {
+    /// Candy Machine account.
+    #[account(mut, has_one = authority)]
+    candy_machine: Account<'info, CandyMachine>,
+
+    /// Candy Machine authority.
+    authority: Signer<'info>,
+
+    /// Payer of the transaction.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+}
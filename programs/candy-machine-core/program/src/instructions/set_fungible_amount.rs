@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use mpl_utils::resize_or_reallocate_account_raw;
+
+use crate::{constants::FUNGIBLE_AMOUNT_LENGTH, CandyError, CandyMachine};
+
+pub fn set_fungible_amount(ctx: Context<SetFungibleAmount>, amount: u64) -> Result<()> {
+    let candy_machine = &ctx.accounts.candy_machine;
+
+    if amount == 0 {
+        return err!(CandyError::InvalidFungibleAmount);
+    }
+
+    let position = candy_machine.get_fungible_amount_position()?;
+    let candy_machine_info = candy_machine.to_account_info();
+
+    if candy_machine_info.data_len() < position + FUNGIBLE_AMOUNT_LENGTH {
+        msg!("Allocating space to store the fungible amount");
+
+        resize_or_reallocate_account_raw(
+            &candy_machine_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            position + FUNGIBLE_AMOUNT_LENGTH,
+        )?;
+    }
+
+    candy_machine_info.data.borrow_mut()[position..position + FUNGIBLE_AMOUNT_LENGTH]
+        .copy_from_slice(&amount.to_le_bytes());
+
+    Ok(())
+}
+
+/// Sets the number of tokens minted per item of fungible token standards.
+#[derive(Accounts)]
+pub struct SetFungibleAmount<'info> {
+    /// Candy Machine account.
+    #[account(mut, has_one = authority)]
+    candy_machine: Account<'info, CandyMachine>,
+
+    /// Candy Machine authority.
+    authority: Signer<'info>,
+
+    /// Payer of the transaction.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+}
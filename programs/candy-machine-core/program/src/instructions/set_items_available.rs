@@ -0,0 +1,164 @@
+use anchor_lang::{prelude::*, solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE};
+use mpl_utils::resize_or_reallocate_account_raw;
+
+use crate::{constants::FINALIZED_FEATURE, CandyError, CandyMachine};
+
+pub fn set_items_available(ctx: Context<SetItemsAvailable>, items_available: u64) -> Result<()> {
+    let candy_machine = &mut ctx.accounts.candy_machine;
+
+    // config lines can no longer change once finalized
+    if candy_machine.is_feature_enabled(FINALIZED_FEATURE) {
+        return err!(CandyError::ConfigLinesFinalized);
+    }
+
+    if candy_machine.has_started(&candy_machine.to_account_info().data.borrow())? {
+        return err!(CandyError::NoChangingConfigLinesDuringMint);
+    }
+
+    // hidden settings candies can change the number of items using the update instruction
+    if candy_machine.data.hidden_settings.is_some() {
+        return err!(CandyError::HiddenSettingsDoNotHaveConfigLines);
+    }
+
+    // pages are allocated for the number of items available when they are added
+    if candy_machine.has_config_line_pages() {
+        return err!(CandyError::ConfigLinePagesNotSupported);
+    }
+
+    // rarity tiers must cover all items, so they need to be cleared first
+    let tiers_position = candy_machine.get_rarity_tiers_position()?;
+    let candy_machine_info = candy_machine.to_account_info();
+
+    if candy_machine_info
+        .data
+        .borrow()
+        .get(tiers_position)
+        .is_some_and(|tiers| *tiers > 0)
+    {
+        return err!(CandyError::InvalidRarityTiers);
+    }
+
+    let old_items = candy_machine.data.items_available as usize;
+    let new_items = items_available as usize;
+    let old_space = candy_machine.get_space()?;
+    let index_size = candy_machine.get_mint_index_size();
+
+    candy_machine.data.items_available = items_available;
+    // makes sure the name and uri are within the maximum allowed values
+    candy_machine.data.validate()?;
+
+    // the mint indices are moved as they are, so their size cannot change
+    if candy_machine.get_mint_index_size() != index_size {
+        return err!(CandyError::CannotChangeMintIndexSize);
+    }
+
+    let new_space = candy_machine.get_space()?;
+    let hidden_section = candy_machine.get_hidden_section()?;
+    let old_length = candy_machine_info.data_len();
+    // the rule set, config lines root and rarity tiers (if any) are stored after the
+    // mint indices
+    let remaining = old_length - old_space;
+    let new_length = new_space + remaining;
+
+    // the account can only grow by MAX_PERMITTED_DATA_INCREASE bytes per instruction
+    if new_length > old_length + MAX_PERMITTED_DATA_INCREASE {
+        return err!(CandyError::AccountGrowthTooLarge);
+    }
+
+    let line_size = candy_machine.get_stored_config_line_size();
+    let old_mask_length = old_items / 8 + 1;
+    let new_mask_length = new_items / 8 + 1;
+
+    // positions of the loaded bit-mask, mint indices and remaining bytes
+    let old_mask_start = hidden_section + 4 + old_items * line_size;
+    let old_indices_start = old_mask_start + old_mask_length;
+    let old_remaining_start = old_indices_start + old_items * index_size;
+
+    let new_mask_start = hidden_section + 4 + new_items * line_size;
+    let new_indices_start = new_mask_start + new_mask_length;
+    let new_remaining_start = new_indices_start + new_items * index_size;
+
+    let moves = [
+        (
+            old_mask_start,
+            new_mask_start,
+            old_mask_length.min(new_mask_length),
+        ),
+        (
+            old_indices_start,
+            new_indices_start,
+            old_items.min(new_items) * index_size,
+        ),
+        (old_remaining_start, new_remaining_start, remaining),
+    ];
+
+    msg!(
+        "Resizing account from {} to {} bytes",
+        old_length,
+        new_length
+    );
+
+    if new_items > old_items {
+        resize_or_reallocate_account_raw(
+            &candy_machine_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            new_length,
+        )?;
+
+        let mut account_data = candy_machine_info.data.borrow_mut();
+
+        // sections are moved starting from the last one, so a section is never
+        // overwritten before it is moved
+        for (source, destination, length) in moves.iter().rev() {
+            account_data.copy_within(*source..*source + *length, *destination);
+        }
+
+        // clears the space of the new items
+        account_data[old_mask_start..new_mask_start].fill(0);
+        account_data[new_mask_start + old_mask_length..new_indices_start].fill(0);
+        account_data[new_indices_start + old_items * index_size..new_remaining_start].fill(0);
+    } else {
+        {
+            let mut account_data = candy_machine_info.data.borrow_mut();
+
+            // the removed config lines must not be loaded
+            if (new_items..old_items)
+                .any(|index| account_data[old_mask_start + index / 8] & (1 << (7 - index % 8)) > 0)
+            {
+                return err!(CandyError::CannotRemoveLoadedConfigLines);
+            }
+
+            for (source, destination, length) in moves.iter() {
+                account_data.copy_within(*source..*source + *length, *destination);
+            }
+        }
+
+        resize_or_reallocate_account_raw(
+            &candy_machine_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            new_length,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Changes the number of items available of a candy machine using config lines.
+#[derive(Accounts)]
+pub struct SetItemsAvailable<'info> {
+    /// Candy Machine account.
+    #[account(mut, has_one = authority)]
+    candy_machine: Account<'info, CandyMachine>,
+
+    /// Candy Machine authority.
+    authority: Signer<'info>,
+
+    /// Payer of the transaction.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use mpl_utils::resize_or_reallocate_account_raw;
+use solana_program::pubkey::PUBKEY_BYTES;
+
+use crate::{CandyError, CandyMachine};
+
+pub fn set_master_edition(
+    ctx: Context<SetMasterEdition>,
+    master_edition_mint: Option<Pubkey>,
+) -> Result<()> {
+    let candy_machine = &ctx.accounts.candy_machine;
+    let candy_machine_info = candy_machine.to_account_info();
+
+    // prints already minted would not match the new master edition
+    if candy_machine.has_started(&candy_machine_info.data.borrow())? {
+        return err!(CandyError::NoChangingMasterEditionDuringMint);
+    }
+
+    let position = candy_machine.get_master_edition_mint_position()?;
+
+    if candy_machine_info.data_len() < position + PUBKEY_BYTES {
+        if master_edition_mint.is_none() {
+            // there is nothing to clear
+            return Ok(());
+        }
+
+        msg!("Allocating space to store the master edition mint");
+
+        resize_or_reallocate_account_raw(
+            &candy_machine_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            position + PUBKEY_BYTES,
+        )?;
+    }
+
+    // the default pubkey represents a cleared master edition mint
+    candy_machine_info.data.borrow_mut()[position..position + PUBKEY_BYTES]
+        .copy_from_slice(master_edition_mint.unwrap_or_default().as_ref());
+
+    Ok(())
+}
+
+/// Sets the mint of the master edition printed by the candy machine.
+#[derive(Accounts)]
+pub struct SetMasterEdition<'info> {
+    /// Candy Machine account.
+    #[account(mut, has_one = authority)]
+    candy_machine: Account<'info, CandyMachine>,
+
+    /// Candy Machine authority.
+    authority: Signer<'info>,
+
+    /// Payer of the transaction.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use mpl_token_metadata::types::TokenStandard;
+use mpl_utils::resize_or_reallocate_account_raw;
+use solana_program::pubkey::PUBKEY_BYTES;
+
+use crate::{
+    constants::{COMMIT_REVEAL_FEATURE, MINT_DELEGATE_LENGTH},
+    AccountVersion, CandyError, CandyMachine, MintDelegate,
+};
+
+pub fn set_mint_delegate(
+    ctx: Context<SetMintDelegate>,
+    mint_delegate: Option<MintDelegate>,
+) -> Result<()> {
+    let candy_machine = &ctx.accounts.candy_machine;
+    let position = candy_machine.get_mint_delegate_position()?;
+    let candy_machine_info = candy_machine.to_account_info();
+
+    let mint_delegate = match mint_delegate {
+        Some(mint_delegate) => mint_delegate,
+        None => {
+            // clears the delegate (if any)
+            if candy_machine_info.data_len() >= position + MINT_DELEGATE_LENGTH {
+                candy_machine_info.data.borrow_mut()[position..position + MINT_DELEGATE_LENGTH]
+                    .fill(0);
+            }
+
+            return Ok(());
+        }
+    };
+
+    // token delegates are approved on the token record of Programmable NFTs
+    if candy_machine.token_standard != TokenStandard::ProgrammableNonFungible as u8
+        || matches!(candy_machine.version, AccountVersion::V1)
+    {
+        return err!(CandyError::MintDelegateNotSupported);
+    }
+
+    // a token can only have one delegate, which is the authority PDA for soulbound items
+    if candy_machine.is_soulbound() {
+        return err!(CandyError::SoulboundMintDelegateConflict);
+    }
+
+    // revealing a mint request does not require the signature of the owner
+    if candy_machine.is_feature_enabled(COMMIT_REVEAL_FEATURE) {
+        return err!(CandyError::CommitRevealConflict);
+    }
+
+    if candy_machine_info.data_len() < position + MINT_DELEGATE_LENGTH {
+        msg!("Allocating space to store the mint delegate");
+
+        resize_or_reallocate_account_raw(
+            &candy_machine_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            position + MINT_DELEGATE_LENGTH,
+        )?;
+    }
+
+    let mut account_data = candy_machine_info.data.borrow_mut();
+    account_data[position] = mint_delegate.role as u8 + 1;
+    account_data[position + 1..position + 1 + PUBKEY_BYTES]
+        .copy_from_slice(mint_delegate.delegate.as_ref());
+    account_data[position + 1 + PUBKEY_BYTES..position + MINT_DELEGATE_LENGTH]
+        .copy_from_slice(mint_delegate.locked_address.as_ref());
+
+    Ok(())
+}
+
+/// Sets the delegate approved on the token record of minted Programmable NFTs.
+#[derive(Accounts)]
+pub struct SetMintDelegate<'info> {
+    /// Candy Machine account.
+    #[account(mut, has_one = authority)]
+    candy_machine: Account<'info, CandyMachine>,
+
+    /// Candy Machine authority.
+    authority: Signer<'info>,
+
+    /// Payer of the transaction.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::MINT_RECEIPT_FEATURE, CandyMachine};
+
+pub fn set_mint_receipt(ctx: Context<SetMintReceipt>, enabled: bool) -> Result<()> {
+    let candy_machine = &mut ctx.accounts.candy_machine;
+
+    candy_machine.set_feature(MINT_RECEIPT_FEATURE, enabled);
+
+    Ok(())
+}
+
+/// Enables or disables mint receipts.
+#[derive(Accounts)]
+pub struct SetMintReceipt<'info> {
+    /// Candy Machine account.
+    #[account(mut, has_one = authority)]
+    candy_machine: Account<'info, CandyMachine>,
+
+    /// Candy Machine authority.
+    authority: Signer<'info>,
+}
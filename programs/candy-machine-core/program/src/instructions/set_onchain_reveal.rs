@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use mpl_token_metadata::types::TokenStandard;
+
+use crate::{
+    constants::ONCHAIN_REVEAL_FEATURE, utils::assert_hidden_settings_index, CandyError,
+    CandyMachine,
+};
+
+pub fn set_onchain_reveal(ctx: Context<SetOnchainReveal>, enabled: bool) -> Result<()> {
+    let candy_machine = &mut ctx.accounts.candy_machine;
+
+    // items minted before the change would not be revealed consistently
+    if candy_machine.items_redeemed > 0 {
+        return err!(CandyError::NoChangingOnchainRevealDuringMint);
+    }
+
+    if enabled {
+        let hidden_settings = candy_machine
+            .data
+            .hidden_settings
+            .as_ref()
+            .ok_or(CandyError::MissingHiddenSettings)?;
+        assert_hidden_settings_index(hidden_settings)?;
+
+        // the reveal updates the metadata of the items through Token Metadata
+        if candy_machine.token_standard != TokenStandard::NonFungible as u8
+            && candy_machine.token_standard != TokenStandard::ProgrammableNonFungible as u8
+        {
+            return err!(CandyError::InvalidTokenStandard);
+        }
+    }
+
+    candy_machine.set_feature(ONCHAIN_REVEAL_FEATURE, enabled);
+
+    Ok(())
+}
+
+/// Enables or disables the on-chain reveal of hidden settings items.
+#[derive(Accounts)]
+pub struct SetOnchainReveal<'info> {
+    /// Candy Machine account.
+    #[account(mut, has_one = authority)]
+    candy_machine: Account<'info, CandyMachine>,
+
+    /// Candy Machine authority.
+    authority: Signer<'info>,
+}
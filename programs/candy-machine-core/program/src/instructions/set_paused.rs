@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::CandyMachine;
+
+pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    let candy_machine = &mut ctx.accounts.candy_machine;
+
+    candy_machine.set_paused(paused);
+
+    msg!("Candy machine paused: {}", paused);
+
+    Ok(())
+}
+
+/// Pauses or resumes minting.
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    /// Candy Machine account.
+    #[account(mut, has_one = authority)]
+    candy_machine: Account<'info, CandyMachine>,
+
+    /// Candy Machine authority.
+    authority: Signer<'info>,
+}
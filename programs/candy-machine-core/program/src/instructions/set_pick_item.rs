@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use mpl_utils::resize_or_reallocate_account_raw;
+
+use crate::{
+    constants::{ITEMS_SKIPPED_LENGTH, PICK_ITEM_FEATURE},
+    CandyError, CandyMachine,
+};
+
+pub fn set_pick_item(ctx: Context<SetPickItem>, enabled: bool) -> Result<()> {
+    let candy_machine = &mut ctx.accounts.candy_machine;
+
+    // requesting items would bypass the draw weights of the rarity tiers
+    if enabled && candy_machine.has_rarity_tiers(&candy_machine.to_account_info().data.borrow())? {
+        return err!(CandyError::PickItemRarityTiersConflict);
+    }
+
+    candy_machine.set_feature(PICK_ITEM_FEATURE, enabled);
+
+    // requested items are skipped by the random selection
+    let candy_machine_info = candy_machine.to_account_info();
+    let position = candy_machine.get_items_skipped_position()?;
+
+    if enabled && candy_machine_info.data_len() < position + ITEMS_SKIPPED_LENGTH {
+        msg!("Allocating space to store the number of skipped items");
+
+        resize_or_reallocate_account_raw(
+            &candy_machine_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            position + ITEMS_SKIPPED_LENGTH,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Enables or disables minting a specific item.
+#[derive(Accounts)]
+pub struct SetPickItem<'info> {
+    /// Candy Machine account.
+    #[account(mut, has_one = authority)]
+    candy_machine: Account<'info, CandyMachine>,
+
+    /// Candy Machine authority.
+    authority: Signer<'info>,
+
+    /// Payer of the transaction.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use mpl_utils::resize_or_reallocate_account_raw;
+
+use crate::{
+    constants::{MAX_RARITY_TIERS, PICK_ITEM_FEATURE, RARITY_TIER_LENGTH},
+    CandyError, CandyMachine, RarityTier,
+};
+
+pub fn set_rarity_tiers(ctx: Context<SetRarityTiers>, tiers: Vec<RarityTier>) -> Result<()> {
+    let candy_machine = &ctx.accounts.candy_machine;
+
+    if candy_machine.has_started(&candy_machine.to_account_info().data.borrow())? {
+        return err!(CandyError::NoChangingRarityTiersDuringMint);
+    }
+
+    // rarity tiers are only used for the random selection of config lines
+    match &candy_machine.data.config_line_settings {
+        Some(settings) if !settings.is_sequential => (),
+        _ => return err!(CandyError::InvalidRarityTiers),
+    }
+
+    // requesting items would bypass the draw weights of the tiers
+    if !tiers.is_empty() && candy_machine.is_feature_enabled(PICK_ITEM_FEATURE) {
+        return err!(CandyError::PickItemRarityTiersConflict);
+    }
+
+    if tiers.len() > MAX_RARITY_TIERS || tiers.iter().any(|tier| tier.weight == 0) {
+        return err!(CandyError::InvalidRarityTiers);
+    }
+
+    // tiers must cover all config lines (or none when clearing the tiers)
+    let total_size = tiers.iter().map(|tier| tier.size as u64).sum::<u64>();
+
+    if !tiers.is_empty() && total_size != candy_machine.data.items_available {
+        return err!(CandyError::InvalidRarityTiers);
+    }
+
+    let position = candy_machine.get_rarity_tiers_position()?;
+    let required_length = position + 1 + tiers.len() * RARITY_TIER_LENGTH;
+    let candy_machine_info = candy_machine.to_account_info();
+
+    if candy_machine_info.data_len() < required_length {
+        msg!("Allocating space to store the rarity tiers");
+
+        resize_or_reallocate_account_raw(
+            &candy_machine_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            required_length,
+        )?;
+    } else if candy_machine_info.data_len() <= position {
+        // there are no tiers to clear
+        return Ok(());
+    }
+
+    let mut account_data = candy_machine_info.data.borrow_mut();
+    account_data[position] = tiers.len() as u8;
+
+    // all items of a tier are available before the mint starts
+    for (index, tier) in tiers.iter().enumerate() {
+        let offset = position + 1 + index * RARITY_TIER_LENGTH;
+        account_data[offset..offset + 4].copy_from_slice(&tier.weight.to_le_bytes());
+        account_data[offset + 4..offset + 8].copy_from_slice(&tier.size.to_le_bytes());
+        account_data[offset + 8..offset + 12].copy_from_slice(&tier.size.to_le_bytes());
+    }
+
+    Ok(())
+}
+
+/// Sets the rarity tiers of the config lines.
+#[derive(Accounts)]
+pub struct SetRarityTiers<'info> {
+    /// Candy Machine account.
+    #[account(mut, has_one = authority)]
+    candy_machine: Account<'info, CandyMachine>,
+
+    /// Candy Machine authority.
+    authority: Signer<'info>,
+
+    /// Payer of the transaction.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use mpl_token_metadata::types::TokenStandard;
+
+use crate::{constants::COMMIT_REVEAL_FEATURE, AccountVersion, CandyError, CandyMachine};
+
+pub fn set_soulbound(ctx: Context<SetSoulbound>, enabled: bool) -> Result<()> {
+    let candy_machine = &mut ctx.accounts.candy_machine;
+
+    if candy_machine.items_redeemed > 0 {
+        return err!(CandyError::NoChangingSoulboundDuringMint);
+    }
+
+    // items are locked after they are minted using a token delegate
+    if enabled
+        && (matches!(candy_machine.version, AccountVersion::V1)
+            || !is_soulbound_token_standard(candy_machine.token_standard))
+    {
+        return err!(CandyError::SoulboundNotSupported);
+    }
+
+    // a token can only have one delegate
+    if enabled
+        && candy_machine
+            .get_mint_delegate(&candy_machine.to_account_info().data.borrow())?
+            .is_some()
+    {
+        return err!(CandyError::SoulboundMintDelegateConflict);
+    }
+
+    // revealing a mint request does not require the signature of the owner
+    if enabled && candy_machine.is_feature_enabled(COMMIT_REVEAL_FEATURE) {
+        return err!(CandyError::CommitRevealConflict);
+    }
+
+    candy_machine.set_soulbound(enabled);
+
+    Ok(())
+}
+
+/// Returns whether items of the token standard can be minted as soulbound items.
+pub fn is_soulbound_token_standard(token_standard: u8) -> bool {
+    token_standard == TokenStandard::NonFungible as u8
+        || token_standard == TokenStandard::ProgrammableNonFungible as u8
+}
+
+/// Enables or disables minting soulbound items.
+#[derive(Accounts)]
+pub struct SetSoulbound<'info> {
+    /// Candy Machine account.
+    #[account(mut, has_one = authority)]
+    candy_machine: Account<'info, CandyMachine>,
+
+    /// Candy Machine authority.
+    authority: Signer<'info>,
+}
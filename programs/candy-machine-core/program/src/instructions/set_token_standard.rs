@@ -4,7 +4,13 @@ use mpl_utils::resize_or_reallocate_account_raw;
 
 use crate::{
     approve_metadata_delegate, assert_token_standard, cmp_pubkeys,
-    constants::{AUTHORITY_SEED, MPL_TOKEN_AUTH_RULES_PROGRAM, RULE_SET_LENGTH, SET, UNSET},
+    constants::{
+        AUTHORITY_SEED, MPL_TOKEN_AUTH_RULES_PROGRAM, RULE_SET_LENGTH, SET, TOKEN_STANDARD_CORE,
+        TOKEN_STANDARD_TOKEN_2022, UNSET,
+    },
+    events::SetTokenStandardEvent,
+    instructions::is_soulbound_token_standard,
+    is_collection_required, is_core_collection, is_token_2022_collection,
     revoke_collection_authority_helper, AccountVersion, ApproveMetadataDelegateHelperAccounts,
     CandyError, CandyMachine, RevokeCollectionAuthorityHelperAccounts,
 };
@@ -13,63 +19,97 @@ pub fn set_token_standard(ctx: Context<SetTokenStandard>, token_standard: u8) ->
     let accounts = ctx.accounts;
     let candy_machine = &mut accounts.candy_machine;
 
-    let collection_metadata_info = &accounts.collection_metadata;
-    let collection_metadata: Metadata =
-        Metadata::try_from(&collection_metadata_info.to_account_info())?;
-    // check that the update authority matches the collection update authority
-    if !cmp_pubkeys(&collection_metadata.mint, accounts.collection_mint.key) {
-        return err!(CandyError::MintMismatch);
+    assert_token_standard(token_standard)?;
+
+    // soulbound items are locked using a token delegate
+    if candy_machine.is_soulbound() && !is_soulbound_token_standard(token_standard) {
+        return err!(CandyError::SoulboundNotSupported);
     }
 
-    assert_token_standard(token_standard)?;
+    // mint delegates are approved on the token record of Programmable NFTs
+    if token_standard != TokenStandard::ProgrammableNonFungible as u8
+        && candy_machine
+            .get_mint_delegate(&candy_machine.to_account_info().data.borrow())?
+            .is_some()
+    {
+        return err!(CandyError::MintDelegateNotSupported);
+    }
 
-    if matches!(candy_machine.version, AccountVersion::V1) {
-        // revoking the existing collection authority
-        let collection_authority_record = accounts
-            .collection_authority_record
-            .as_ref()
-            .ok_or(CandyError::MissingCollectionAuthorityRecord)?;
-
-        let revoke_accounts = RevokeCollectionAuthorityHelperAccounts {
-            authority_pda: accounts.authority_pda.to_account_info(),
-            collection_authority_record: collection_authority_record.to_account_info(),
-            collection_metadata: accounts.collection_metadata.to_account_info(),
-            collection_mint: accounts.collection_mint.to_account_info(),
-            token_metadata_program: accounts.token_metadata_program.to_account_info(),
-        };
-
-        revoke_collection_authority_helper(
-            revoke_accounts,
-            candy_machine.key(),
-            *ctx.bumps.get("authority_pda").unwrap(),
-            collection_metadata.token_standard,
-        )?;
-
-        // approve a new metadata delegate
-
-        let delegate_accounts = ApproveMetadataDelegateHelperAccounts {
-            token_metadata_program: accounts.token_metadata_program.to_account_info(),
-            authority_pda: accounts.authority_pda.to_account_info(),
-            collection_metadata: accounts.collection_metadata.to_account_info(),
-            collection_mint: accounts.collection_mint.to_account_info(),
-            collection_update_authority: accounts.collection_update_authority.to_account_info(),
-            delegate_record: accounts.collection_delegate_record.to_account_info(),
-            payer: accounts.payer.to_account_info(),
-            system_program: accounts.system_program.to_account_info(),
-            sysvar_instructions: accounts.sysvar_instructions.to_account_info(),
-            authorization_rules_program: accounts
-                .authorization_rules_program
-                .to_owned()
-                .map(|authorization_rules_program| authorization_rules_program.to_account_info()),
-            authorization_rules: accounts
-                .authorization_rules
-                .to_owned()
-                .map(|authorization_rules| authorization_rules.to_account_info()),
-        };
-
-        approve_metadata_delegate(delegate_accounts)?;
-        // bump the version of the account since we are setting a metadata delegate
-        candy_machine.version = AccountVersion::V2;
+    if !candy_machine.has_collection() {
+        // candy machines without a collection can only mint Token Metadata assets
+        if is_collection_required(token_standard) {
+            return err!(CandyError::CollectionRequired);
+        }
+    } else if is_core_collection(&accounts.collection_mint) {
+        // Core collections can only be used to mint Core assets
+        if token_standard != TOKEN_STANDARD_CORE {
+            return err!(CandyError::InvalidTokenStandard);
+        }
+    } else if is_token_2022_collection(&accounts.collection_mint) {
+        // Token-2022 collections can only be used to mint Token-2022 NFTs
+        if token_standard != TOKEN_STANDARD_TOKEN_2022 {
+            return err!(CandyError::InvalidTokenStandard);
+        }
+    } else if token_standard == TOKEN_STANDARD_CORE || token_standard == TOKEN_STANDARD_TOKEN_2022 {
+        // Core assets and Token-2022 NFTs can only be minted into a collection of the
+        // same type
+        return err!(CandyError::InvalidTokenStandard);
+    } else {
+        let collection_metadata_info = &accounts.collection_metadata;
+        let collection_metadata: Metadata =
+            Metadata::try_from(&collection_metadata_info.to_account_info())?;
+        // check that the update authority matches the collection update authority
+        if !cmp_pubkeys(&collection_metadata.mint, accounts.collection_mint.key) {
+            return err!(CandyError::MintMismatch);
+        }
+
+        if matches!(candy_machine.version, AccountVersion::V1) {
+            // revoking the existing collection authority
+            let collection_authority_record = accounts
+                .collection_authority_record
+                .as_ref()
+                .ok_or(CandyError::MissingCollectionAuthorityRecord)?;
+
+            let revoke_accounts = RevokeCollectionAuthorityHelperAccounts {
+                authority_pda: accounts.authority_pda.to_account_info(),
+                collection_authority_record: collection_authority_record.to_account_info(),
+                collection_metadata: accounts.collection_metadata.to_account_info(),
+                collection_mint: accounts.collection_mint.to_account_info(),
+                token_metadata_program: accounts.token_metadata_program.to_account_info(),
+            };
+
+            revoke_collection_authority_helper(
+                revoke_accounts,
+                candy_machine.key(),
+                *ctx.bumps.get("authority_pda").unwrap(),
+                collection_metadata.token_standard,
+            )?;
+
+            // approve a new metadata delegate
+
+            let delegate_accounts = ApproveMetadataDelegateHelperAccounts {
+                token_metadata_program: accounts.token_metadata_program.to_account_info(),
+                authority_pda: accounts.authority_pda.to_account_info(),
+                collection_metadata: accounts.collection_metadata.to_account_info(),
+                collection_mint: accounts.collection_mint.to_account_info(),
+                collection_update_authority: accounts.collection_update_authority.to_account_info(),
+                delegate_record: accounts.collection_delegate_record.to_account_info(),
+                payer: accounts.payer.to_account_info(),
+                system_program: accounts.system_program.to_account_info(),
+                sysvar_instructions: accounts.sysvar_instructions.to_account_info(),
+                authorization_rules_program: accounts.authorization_rules_program.to_owned().map(
+                    |authorization_rules_program| authorization_rules_program.to_account_info(),
+                ),
+                authorization_rules: accounts
+                    .authorization_rules
+                    .to_owned()
+                    .map(|authorization_rules| authorization_rules.to_account_info()),
+            };
+
+            approve_metadata_delegate(delegate_accounts)?;
+            // bump the version of the account since we are setting a metadata delegate
+            candy_machine.version = AccountVersion::V2;
+        }
     }
 
     msg!(
@@ -78,9 +118,15 @@ pub fn set_token_standard(ctx: Context<SetTokenStandard>, token_standard: u8) ->
         token_standard
     );
 
+    emit!(SetTokenStandardEvent {
+        candy_machine: candy_machine.key(),
+        old_token_standard: candy_machine.token_standard,
+        new_token_standard: token_standard,
+    });
+
     candy_machine.token_standard = token_standard;
 
-    let required_length = candy_machine.data.get_space_for_candy()?;
+    let required_length = candy_machine.get_space()?;
     let candy_machine_info = candy_machine.to_account_info();
 
     if token_standard == TokenStandard::ProgrammableNonFungible as u8 {
@@ -116,11 +162,14 @@ pub fn set_token_standard(ctx: Context<SetTokenStandard>, token_standard: u8) ->
             msg!("Rule set cleared");
         }
     } else if required_length < candy_machine_info.data_len() {
-        let end_index = candy_machine_info.data_len();
+        // the rarity tiers (if any) are stored after the rule set
+        let end_index = candy_machine_info
+            .data_len()
+            .min(required_length + 1 + RULE_SET_LENGTH);
         let mut account_data = candy_machine_info.data.borrow_mut();
         account_data[required_length..end_index].fill(0);
 
-        msg!("Remaining account bytes cleared");
+        msg!("Rule set bytes cleared");
     }
 
     Ok(())
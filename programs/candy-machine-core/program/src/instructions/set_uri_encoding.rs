@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{FINALIZED_FEATURE, MERKLE_CONFIG_LINES_FEATURE},
+    get_config_count, CandyError, CandyMachine, UriEncoding,
+};
+
+pub fn set_uri_encoding(ctx: Context<SetUriEncoding>, encoding: UriEncoding) -> Result<()> {
+    let candy_machine = &mut ctx.accounts.candy_machine;
+
+    // config lines can no longer change once finalized
+    if candy_machine.is_feature_enabled(FINALIZED_FEATURE) {
+        return err!(CandyError::ConfigLinesFinalized);
+    }
+
+    if candy_machine.items_redeemed > 0 {
+        return err!(CandyError::NoChangingConfigLinesDuringMint);
+    }
+
+    // hidden settings candies do not store config lines
+    if candy_machine.data.hidden_settings.is_some() {
+        return err!(CandyError::HiddenSettingsDoNotHaveConfigLines);
+    }
+
+    if candy_machine.data.config_line_settings.is_none() {
+        return err!(CandyError::MissingConfigLinesSettings);
+    }
+
+    // config lines are supplied at mint time when using a merkle root
+    if candy_machine.is_feature_enabled(MERKLE_CONFIG_LINES_FEATURE) {
+        return err!(CandyError::MerkleConfigLinesEnabled);
+    }
+
+    // the encoding determines how the URIs are stored, so it cannot change after
+    // config lines are added
+    if get_config_count(&candy_machine.to_account_info().data.borrow())? > 0 {
+        return err!(CandyError::ConfigLinesAlreadyLoaded);
+    }
+
+    candy_machine.set_uri_encoding(encoding);
+
+    Ok(())
+}
+
+/// Sets the encoding of the config line URIs.
+#[derive(Accounts)]
+pub struct SetUriEncoding<'info> {
+    /// Candy Machine account.
+    #[account(mut, has_one = authority)]
+    candy_machine: Account<'info, CandyMachine>,
+
+    /// Candy Machine authority.
+    authority: Signer<'info>,
+}
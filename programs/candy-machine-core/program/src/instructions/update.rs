@@ -1,7 +1,12 @@
 use anchor_lang::prelude::*;
 use mpl_token_metadata::MAX_SYMBOL_LENGTH;
 
-use crate::{utils::fixed_length_string, CandyError, CandyMachine, CandyMachineData};
+use crate::{
+    constants::{FINALIZED_FEATURE, ONCHAIN_REVEAL_FEATURE},
+    events::UpdateEvent,
+    utils::{assert_hidden_settings_index, fixed_length_string},
+    AccountVersion, CandyError, CandyMachine, CandyMachineData,
+};
 
 pub fn update(ctx: Context<Update>, data: CandyMachineData) -> Result<()> {
     let candy_machine = &mut ctx.accounts.candy_machine;
@@ -33,8 +38,16 @@ pub fn update(ctx: Context<Update>, data: CandyMachineData) -> Result<()> {
                 return err!(CandyError::CannotIncreaseLength);
             }
 
+            // config lines of a page are stored at fixed positions
+            if candy_machine.has_config_line_pages()
+                && (config_lines.name_length != new_config_lines.name_length
+                    || config_lines.uri_length != new_config_lines.uri_length)
+            {
+                return err!(CandyError::ConfigLinePagesNotSupported);
+            }
+
             if config_lines.is_sequential != new_config_lines.is_sequential
-                && candy_machine.items_redeemed > 0
+                && candy_machine.has_started(&candy_machine.to_account_info().data.borrow())?
             {
                 return err!(CandyError::CannotChangeSequentialIndexGeneration);
             }
@@ -43,9 +56,80 @@ pub fn update(ctx: Context<Update>, data: CandyMachineData) -> Result<()> {
         return err!(CandyError::CannotSwitchFromHiddenSettings);
     }
 
+    // the config lines settings are part of the commitment of finalized config lines
+    if candy_machine.is_feature_enabled(FINALIZED_FEATURE) {
+        let unchanged = match (
+            &candy_machine.data.config_line_settings,
+            &data.config_line_settings,
+        ) {
+            (Some(current), Some(new)) => {
+                current.prefix_name == new.prefix_name
+                    && current.name_length == new.name_length
+                    && current.prefix_uri == new.prefix_uri
+                    && current.uri_length == new.uri_length
+                    && current.is_sequential == new.is_sequential
+            }
+            _ => false,
+        };
+
+        if !unchanged || data.items_available != candy_machine.data.items_available {
+            return err!(CandyError::ConfigLinesFinalized);
+        }
+    }
+
+    // the hidden settings are the commitment of the on-chain reveal
+    if candy_machine.is_feature_enabled(ONCHAIN_REVEAL_FEATURE) {
+        let hidden_settings = data
+            .hidden_settings
+            .as_ref()
+            .ok_or(CandyError::MissingHiddenSettings)?;
+        assert_hidden_settings_index(hidden_settings)?;
+
+        if candy_machine.items_redeemed > 0 {
+            let current = candy_machine
+                .data
+                .hidden_settings
+                .as_ref()
+                .ok_or(CandyError::MissingHiddenSettings)?;
+
+            if current.name != hidden_settings.name
+                || current.uri != hidden_settings.uri
+                || current.hash != hidden_settings.hash
+            {
+                return err!(CandyError::CannotChangeHiddenSettings);
+            }
+        }
+    }
+
+    let hidden_section = candy_machine.get_hidden_section()?;
+
     candy_machine.data = data;
     candy_machine.data.symbol = symbol;
 
+    // the hidden section of V3 accounts starts right after the candy machine data, so it
+    // is moved when the data gets smaller
+    if let AccountVersion::V3 = candy_machine.version {
+        let new_hidden_section = candy_machine.get_hidden_section()?;
+
+        if new_hidden_section > hidden_section {
+            return err!(CandyError::CandyMachineDataTooLarge);
+        } else if new_hidden_section < hidden_section {
+            let candy_machine_info = candy_machine.to_account_info();
+            let length = candy_machine_info.data_len();
+
+            candy_machine_info
+                .data
+                .borrow_mut()
+                .copy_within(hidden_section..length, new_hidden_section);
+            candy_machine_info.realloc(length - (hidden_section - new_hidden_section), false)?;
+        }
+    }
+
+    emit!(UpdateEvent {
+        candy_machine: candy_machine.key(),
+        data: candy_machine.data.clone(),
+    });
+
     Ok(())
 }
 
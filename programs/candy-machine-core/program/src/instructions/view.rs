@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use solana_program::program::MAX_RETURN_DATA;
+
+use crate::{
+    constants::MERKLE_CONFIG_LINES_FEATURE,
+    instructions::mint_v2::read_config_line,
+    utils::{get_config_count, replace_patterns},
+    CandyError, CandyMachine, ConfigLine,
+};
+
+/// Maximum number of indices that fit in the return data.
+const MAX_UNLOADED_INDICES: usize = (MAX_RETURN_DATA - 4) / 4;
+
+pub fn get_items_remaining(ctx: Context<View>) -> Result<u64> {
+    let candy_machine = &ctx.accounts.candy_machine;
+
+    candy_machine.get_items_remaining(&candy_machine.to_account_info().data.borrow())
+}
+
+pub fn get_loaded_count(ctx: Context<View>) -> Result<u32> {
+    let candy_machine = &ctx.accounts.candy_machine;
+
+    if candy_machine.data.hidden_settings.is_some() {
+        return Ok(0);
+    }
+
+    let account_info = candy_machine.to_account_info();
+    let account_data = account_info.data.borrow();
+
+    Ok(get_config_count(&account_data)? as u32)
+}
+
+pub fn get_unloaded_indices(ctx: Context<View>, start: u32) -> Result<Vec<u32>> {
+    let candy_machine = &ctx.accounts.candy_machine;
+
+    // there are no config lines to load
+    if candy_machine.data.hidden_settings.is_some()
+        || candy_machine.is_feature_enabled(MERKLE_CONFIG_LINES_FEATURE)
+    {
+        return Ok(Vec::new());
+    }
+
+    let account_info = candy_machine.to_account_info();
+    let account_data = account_info.data.borrow();
+
+    // all config lines are loaded (once minting starts, the bit-mask keeps track of the
+    // items taken instead)
+    if get_config_count(&account_data)? as u64 == candy_machine.data.items_available {
+        return Ok(Vec::new());
+    }
+
+    let bit_mask_start = get_bit_mask_start(candy_machine)?;
+
+    Ok((start..candy_machine.data.items_available as u32)
+        .filter(|index| !is_loaded(&account_data, bit_mask_start, *index as usize))
+        .take(MAX_UNLOADED_INDICES)
+        .collect())
+}
+
+pub fn preview_config_line(ctx: Context<View>, index: u32) -> Result<ConfigLine> {
+    let candy_machine = &ctx.accounts.candy_machine;
+
+    if index as u64 >= candy_machine.data.items_available {
+        return err!(CandyError::IndexGreaterThanLength);
+    }
+
+    if let Some(hs) = &candy_machine.data.hidden_settings {
+        return Ok(ConfigLine {
+            name: replace_patterns(hs.name.clone(), index as usize),
+            uri: replace_patterns(hs.uri.clone(), index as usize),
+        });
+    }
+
+    if candy_machine.is_feature_enabled(MERKLE_CONFIG_LINES_FEATURE) {
+        return err!(CandyError::MerkleConfigLinesEnabled);
+    }
+
+    let account_info = candy_machine.to_account_info();
+    let account_data = account_info.data.borrow();
+
+    // all config lines are loaded when the count matches the number of items (once
+    // minting starts, the bit-mask keeps track of the items taken instead)
+    if get_config_count(&account_data)? as u64 != candy_machine.data.items_available
+        && !is_loaded(
+            &account_data,
+            get_bit_mask_start(candy_machine)?,
+            index as usize,
+        )
+    {
+        return err!(CandyError::ConfigLineNotLoaded);
+    }
+
+    read_config_line(
+        candy_machine,
+        &account_data,
+        ctx.remaining_accounts,
+        index as usize,
+    )
+}
+
+/// Returns the position of the loaded config lines bit-mask on the account data.
+fn get_bit_mask_start(candy_machine: &CandyMachine) -> Result<usize> {
+    Ok(candy_machine.get_hidden_section()?
+        + 4
+        + (candy_machine.data.items_available as usize)
+            * candy_machine.get_stored_config_line_size())
+}
+
+/// Returns whether the config line at the specified index was added.
+fn is_loaded(data: &[u8], bit_mask_start: usize, index: usize) -> bool {
+    let mask = 1u8 << (7 - index % 8);
+    data[bit_mask_start + index / 8] & mask == mask
+}
+
+/// Reads the state of the candy machine. The result is set as the return data of
+/// the instruction.
+#[derive(Accounts)]
+pub struct View<'info> {
+    /// Candy Machine account.
+    candy_machine: Account<'info, CandyMachine>,
+}
@@ -1,8 +1,19 @@
 use anchor_lang::prelude::*;
 
-use crate::CandyMachine;
+use crate::{events::WithdrawEvent, CandyError, CandyMachine};
+
+pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
+    // pending mint requests must be revealed or closed first
+    if ctx.accounts.candy_machine.pending_requests() > 0 {
+        return err!(CandyError::PendingMintRequests);
+    }
+
+    emit!(WithdrawEvent {
+        candy_machine: ctx.accounts.candy_machine.key(),
+        authority: ctx.accounts.authority.key(),
+        lamports: ctx.accounts.candy_machine.to_account_info().lamports(),
+    });
 
-pub fn withdraw(_ctx: Context<Withdraw>) -> Result<()> {
     Ok(())
 }
 
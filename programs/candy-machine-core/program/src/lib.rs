@@ -9,6 +9,7 @@ pub use utils::*;
 
 pub mod constants;
 pub mod errors;
+pub mod events;
 mod instructions;
 mod state;
 mod utils;
@@ -19,8 +20,31 @@ declare_id!("CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR");
 pub mod candy_machine_core {
     use super::*;
 
+    /// Add a page account to store config lines, allowing a candy machine to hold more
+    /// config lines than fit in a single account.
+    ///
+    /// Each page stores as many config lines as fit in the maximum account size, and the
+    /// page account must have space for the config lines of the page. When the first page
+    /// is added, the config lines section is removed from the candy machine account, so
+    /// no config lines can be loaded at this point. Config lines and mint indices of all
+    /// pages are still tracked on the candy machine account.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Candy Machine account
+    ///   1. `[signer]` Candy Machine authority
+    ///   2. `[writable]` Config line page account (must be pre-allocated but zero content)
+    ///   3. `[signer, writable]` Payer
+    ///   4. `[]` System program
+    pub fn add_config_line_page(ctx: Context<AddConfigLinePage>) -> Result<()> {
+        instructions::add_config_line_page(ctx)
+    }
+
     /// Add the configuration (name + uri) of each NFT to the account data.
     ///
+    /// When config lines are stored on page accounts, the config lines must belong to a
+    /// single page and the page account is provided as a remaining account.
+    ///
     /// # Accounts
     ///
     ///   0. `[writable]` Candy Machine account
@@ -33,6 +57,92 @@ pub mod candy_machine_core {
         instructions::add_config_lines(ctx, index, config_lines)
     }
 
+    /// Close an expired mint request, releasing the mint reserved by the request.
+    ///
+    /// Requests are closed in the same order they are revealed, so only the next request
+    /// to be revealed can be closed. Anyone can close an expired request, so it does not
+    /// block the requests created after it.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Candy Machine account
+    ///   1. `[writable]` Mint request
+    ///   2. `[writable]` Mint request payer
+    ///   3. `[]` SlotHashes sysvar cluster data
+    pub fn close_mint_request(ctx: Context<CloseMintRequest>) -> Result<()> {
+        instructions::close_mint_request(ctx)
+    }
+
+    /// Close a mint receipt, returning the rent to the payer of the mint.
+    ///
+    /// Receipts can only be closed after all items of the candy machine are minted.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[]` Candy Machine account
+    ///   1. `[signer]` Candy Machine authority
+    ///   2. `[writable]` Mint receipt
+    ///   3. `[writable]` Mint receipt payer
+    pub fn close_mint_receipt(ctx: Context<CloseMintReceipt>) -> Result<()> {
+        instructions::close_mint_receipt(ctx)
+    }
+
+    /// Finalize the config lines, storing a commitment of the loaded config lines on
+    /// the candy machine account.
+    ///
+    /// After finalizing, config lines and their settings can no longer change. The
+    /// commitment can be recomputed from the account data using
+    /// `get_config_lines_commitment`. When config lines are stored on page accounts, the
+    /// commitment is computed over the config lines of the pages, in page order.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Candy Machine account
+    ///   1. `[signer]` Candy Machine authority
+    ///   2. `[signer, writable]` Payer
+    ///   3. `[]` System program
+    ///
+    /// Followed by the config line page accounts (when config lines are stored on page
+    /// accounts).
+    pub fn finalize<'info>(ctx: Context<'_, '_, '_, 'info, Finalize<'info>>) -> Result<()> {
+        instructions::finalize(ctx)
+    }
+
+    /// Return the number of items that can still be minted.
+    ///
+    /// The result is set as the return data of the instruction.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[]` Candy Machine account
+    pub fn get_items_remaining(ctx: Context<View>) -> Result<u64> {
+        instructions::get_items_remaining(ctx)
+    }
+
+    /// Return the number of config lines loaded.
+    ///
+    /// The result is set as the return data of the instruction.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[]` Candy Machine account
+    pub fn get_loaded_count(ctx: Context<View>) -> Result<u32> {
+        instructions::get_loaded_count(ctx)
+    }
+
+    /// Return the indices of the config lines not loaded, starting from the specified index.
+    ///
+    /// The result is set as the return data of the instruction. Since the size of the return
+    /// data is limited, the list might be truncated; the remaining indices can be retrieved
+    /// starting from the index after the last returned one.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[]` Candy Machine account
+    pub fn get_unloaded_indices(ctx: Context<View>, start: u32) -> Result<Vec<u32>> {
+        instructions::get_unloaded_indices(ctx, start)
+    }
+
     /// Initialize the candy machine account with the specified data.
     ///
     /// # Accounts
@@ -70,14 +180,75 @@ pub mod candy_machine_core {
     ///   11. `[]` Instructions sysvar account
     ///   12. `[optional]` Token Authorization Rules program
     ///   13. `[optional]` Token authorization rules account
-    pub fn initialize_v2(
-        ctx: Context<InitializeV2>,
+    ///
+    /// For MPL Core collections, the MPL Core program must be passed as a remaining account.
+    /// For Token-2022 collections, the SPL Token-2022 program must be passed as a remaining
+    /// account; the group update authority is transferred to the authority PDA and recorded
+    /// so it can be restored when the collection is replaced.
+    pub fn initialize_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitializeV2<'info>>,
         data: CandyMachineData,
         token_standard: u8,
     ) -> Result<()> {
         instructions::initialize_v2(ctx, data, token_standard)
     }
 
+    /// Initialize the candy machine account with the specified data and token standard,
+    /// using the compact (V3) account layout.
+    ///
+    /// The account space is sized to the candy machine data, so updates to the data
+    /// cannot increase its serialized size. Mint indices are stored as `u16` values
+    /// when the number of items fits in a `u16`.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Candy Machine account (must be pre-allocated but zero content)
+    ///   1. `[writable]` Authority PDA (seeds `["candy_machine", candy machine id]`)
+    ///   2. `[]` Candy Machine authority
+    ///   3. `[signer]` Payer
+    ///   4. `[]` Collection metadata
+    ///   5. `[]` Collection mint
+    ///   6. `[]` Collection master edition
+    ///   7. `[signer]` Collection update authority
+    ///   8. `[writable]` Collection metadata delegate record
+    ///   9. `[]` Token Metadata program
+    ///   10. `[]` System program
+    ///   11. `[]` Instructions sysvar account
+    ///   12. `[optional]` Token Authorization Rules program
+    ///   13. `[optional]` Token authorization rules account
+    ///   14. `[optional]` MPL Core program
+    ///   15. `[optional]` SPL Token-2022 program
+    pub fn initialize_v3(
+        ctx: Context<InitializeV3>,
+        data: CandyMachineData,
+        token_standard: u8,
+    ) -> Result<()> {
+        instructions::initialize_v3(ctx, data, token_standard)
+    }
+
+    /// Initialize the candy machine account without a collection, using the compact (V3)
+    /// account layout.
+    ///
+    /// Minted items are not verified into a collection and their update authority is
+    /// the candy machine authority. Core assets, compressed and Token-2022 NFTs require
+    /// a collection. A collection can be attached using `set_collection_v2` before any
+    /// item is minted.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Candy Machine account (must be pre-allocated but zero content)
+    ///   1. `[]` Candy Machine authority
+    ///   2. `[signer]` Payer
+    ///   3. `[optional]` Authorization rule set
+    ///   4. `[]` System program
+    pub fn initialize_without_collection(
+        ctx: Context<InitializeWithoutCollection>,
+        data: CandyMachineData,
+        token_standard: u8,
+    ) -> Result<()> {
+        instructions::initialize_without_collection(ctx, data, token_standard)
+    }
+
     /// Mint an NFT.
     ///
     /// Only the candy machine mint authority is allowed to mint.
@@ -105,10 +276,67 @@ pub mod candy_machine_core {
         instructions::mint(ctx)
     }
 
+    /// Mint a batch of NFTs to a list of owners.
+    ///
+    /// Only the candy machine authority is allowed to mint a batch, which bypasses the
+    /// mint authority. Items are selected in the same way as the `mint_v2` instruction,
+    /// so the batch is not available when commit-reveal or merkle config lines are
+    /// enabled. Since owners do not sign the batch, it is also not available for
+    /// soulbound items or when a mint delegate is set. The Candy Machine authority is
+    /// also the mint authority of the NFTs.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Candy Machine account
+    ///   1. `[writable]` Authority PDA (seeds `["candy_machine", candy machine id]`)
+    ///   2. `[signer]` Candy Machine authority
+    ///   3. `[signer, writable]` Payer
+    ///   4. `[]` Collection delegate or authority record
+    ///   5. `[]` Collection mint
+    ///   6. `[writable]` Collection metadata
+    ///   7. `[]` Collection master edition
+    ///   8. `[]` Collection update authority
+    ///   9. `[]` Token Metadata program
+    ///   10. `[]` SPL Token program
+    ///   11. `[optional]` SPL Associated Token program
+    ///   12. `[]` System program
+    ///   13. `[]` Instructions sysvar account
+    ///   14. `[]` SlotHashes sysvar cluster data.
+    ///   15. `[optional]` MPL Core program
+    ///   16. `[optional, writable]` Merkle tree
+    ///   17. `[optional, writable]` Tree config
+    ///   18. `[optional]` Bubblegum collection signer
+    ///   19. `[optional]` Bubblegum program
+    ///   20. `[optional]` SPL Noop program
+    ///   21. `[optional]` SPL Account Compression program
+    ///   22. `[optional]` SPL Token-2022 program
+    ///
+    /// Followed by the accounts of each item (optional accounts are set to the program id
+    /// when not present):
+    ///
+    ///   0. `[]` NFT owner
+    ///   1. `[signer, writable]` Mint account of the NFT
+    ///   2. `[writable]` Metadata account of the NFT
+    ///   3. `[writable]` Master edition account of the NFT
+    ///   4. `[optional, writable]` Destination token account
+    ///   5. `[optional, writable]` Token record
+    ///   6. `[optional, writable]` Mint receipt (seeds `["mint_receipt", candy machine id, NFT mint]`)
+    pub fn mint_batch<'info>(ctx: Context<'_, '_, '_, 'info, MintBatch<'info>>) -> Result<()> {
+        instructions::mint_batch(ctx)
+    }
+
     /// Mint an NFT.
     ///
-    /// Only the candy machine mint authority is allowed to mint. This handler mints both
-    /// NFTs and Programmable NFTs.
+    /// Only the candy machine mint authority is allowed to mint. This handler mints
+    /// NFTs, Programmable NFTs, MPL Core assets, compressed NFTs, Token-2022 NFTs, print
+    /// editions and fungible tokens. For fungible tokens, the NFT accounts are the mint,
+    /// metadata and token accounts of the token type of the config line, so the item
+    /// must be known when the transaction is created: config lines must be sequential
+    /// (or hidden settings used), unless a specific item is requested with `mint_v3`.
+    /// Config lines with the same name and uri share the mint of their token type, which
+    /// is not verified into the collection.
+    /// The collection accounts are not used when the candy machine does not have a
+    /// collection.
     ///
     /// # Accounts
     ///
@@ -133,10 +361,259 @@ pub mod candy_machine_core {
     ///   18. `[]` System program
     ///   19. `[optional]` Instructions sysvar account
     ///   20. `[]` SlotHashes sysvar cluster data.
+    ///   21. `[optional]` Token Authorization Rules program
+    ///   22. `[optional]` Token authorization rules account
+    ///
+    /// MPL Core assets, compressed NFTs and Token-2022 NFTs can only be minted with
+    /// `mint_v3`, which takes the MPL Core, Bubblegum and SPL Token-2022 accounts, as
+    /// well as commit-reveal mints and mints with receipts, which take the mint request
+    /// and mint receipt accounts.
+    ///
+    /// When config lines are stored on page accounts, the page accounts are provided as
+    /// remaining accounts. When printing editions, the remaining accounts are the master
+    /// metadata, master edition, master token account (owned by the authority PDA) and
+    /// edition marker accounts of the master edition mint set on the candy machine. When
+    /// a mint delegate is set, the delegate account is also provided as a remaining
+    /// account.
     pub fn mint_v2<'info>(ctx: Context<'_, '_, '_, 'info, MintV2<'info>>) -> Result<()> {
         instructions::mint_v2(ctx)
     }
 
+    /// Mint an NFT with mint arguments.
+    ///
+    /// Only the candy machine mint authority is allowed to mint. The arguments are all
+    /// optional:
+    ///
+    /// - `index`: mints the NFT of a specific config line. The pick item mode must be
+    ///   enabled and the requested item must not have been minted; it is removed from the
+    ///   items available for random selection.
+    /// - `config_line`: the config line of the selected item with its merkle proof, when
+    ///   config lines are verified using a merkle root. The item must be known when the
+    ///   transaction is created: this is the case for sequential mints and when a specific
+    ///   `index` is requested. Random mints use the commit-reveal mint, supplying the
+    ///   config line when the request is revealed.
+    /// - `group`: the group label recorded on the mint receipt, when mint receipts are
+    ///   enabled.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Candy Machine account (must be pre-allocated but zero content)
+    ///   1. `[writable]` Authority PDA (seeds `["candy_machine", candy machine id]`)
+    ///   2. `[signer]` Candy Machine mint authority
+    ///   3. `[signer]` Payer
+    ///   4. `[writable]` Mint account of the NFT
+    ///   5. `[]` Mint authority of the NFT
+    ///   6. `[writable]` Metadata account of the NFT
+    ///   7. `[writable]` Master edition account of the NFT
+    ///   8. `[optional, writable]` Destination token account
+    ///   9. `[optional, writable]` Token record
+    ///   10. `[]` Collection delegate or authority record
+    ///   11. `[]` Collection mint
+    ///   12. `[writable]` Collection metadata
+    ///   13. `[]` Collection master edition
+    ///   14. `[]` Collection update authority
+    ///   15. `[]` Token Metadata program
+    ///   16. `[]` SPL Token program
+    ///   17. `[optional]` SPL Associated Token program
+    ///   18. `[]` System program
+    ///   19. `[optional]` Instructions sysvar account
+    ///   20. `[]` SlotHashes sysvar cluster data.
+    ///   21. `[optional]` Token Authorization Rules program
+    ///   22. `[optional]` Token authorization rules account
+    ///   23. `[optional]` MPL Core program
+    ///   24. `[optional, writable]` Merkle tree
+    ///   25. `[optional, writable]` Tree config
+    ///   26. `[optional]` Bubblegum collection signer
+    ///   27. `[optional]` Bubblegum program
+    ///   28. `[optional]` SPL Noop program
+    ///   29. `[optional]` SPL Account Compression program
+    ///   30. `[optional]` SPL Token-2022 program
+    ///   31. `[optional, writable]` Mint request (seeds `["mint_request", candy machine id, NFT owner]`)
+    ///   32. `[optional, writable]` Mint receipt (seeds `["mint_receipt", candy machine id, NFT mint]`)
+    ///
+    /// The remaining accounts are the same as the `mint_v2` instruction.
+    pub fn mint_v3<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintV3<'info>>,
+        args: MintArgs,
+    ) -> Result<()> {
+        instructions::mint_v3(ctx, args)
+    }
+
+    /// Return the name and uri of the config line at the specified index, as they would
+    /// be used by a mint.
+    ///
+    /// The result is set as the return data of the instruction. For hidden settings, the
+    /// index is used as the mint number.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[]` Candy Machine account
+    ///
+    /// When config lines are stored on page accounts, the page account of the config line
+    /// is provided as a remaining account.
+    pub fn preview_config_line(ctx: Context<View>, index: u32) -> Result<ConfigLine> {
+        instructions::preview_config_line(ctx, index)
+    }
+
+    /// Unlock or burn a soulbound item.
+    ///
+    /// Only the candy machine authority is allowed to release soulbound items. The item
+    /// is unlocked by the authority PDA, which is the delegate that locked it; when `burn`
+    /// is `true`, the item is also burned.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[]` Candy Machine account
+    ///   1. `[signer]` Candy Machine authority
+    ///   2. `[]` Authority PDA (seeds `["candy_machine", candy machine id]`)
+    ///   3. `[signer, writable]` Payer
+    ///   4. `[]` Owner of the item
+    ///   5. `[writable]` Token account of the item
+    ///   6. `[writable]` Mint account of the item
+    ///   7. `[writable]` Metadata account of the item
+    ///   8. `[writable]` Master edition account of the item
+    ///   9. `[optional, writable]` Token record of the item
+    ///   10. `[optional, writable]` Collection metadata
+    ///   11. `[]` Token Metadata program
+    ///   12. `[]` SPL Token program
+    ///   13. `[]` System program
+    ///   14. `[]` Instructions sysvar account
+    ///   15. `[optional]` Token Authorization Rules program
+    ///   16. `[optional]` Token authorization rules account
+    pub fn release_soulbound(ctx: Context<ReleaseSoulbound>, burn: bool) -> Result<()> {
+        instructions::release_soulbound(ctx, burn)
+    }
+
+    /// Remove a config line that was not minted from the items available to mint.
+    ///
+    /// The number of removed config lines is stored on the account, so the number of
+    /// items left to mint is reduced by one without counting the removed item as
+    /// redeemed. Sequential candy machines skip the removed config line when minting,
+    /// while random candy machines skip it when it is selected. The candy machine must
+    /// keep track of the items taken, which starts when its config lines are fully
+    /// loaded (or its merkle root is set) before minting.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Candy Machine account
+    ///   1. `[signer]` Candy Machine authority
+    ///   2. `[signer, writable]` Payer
+    ///   3. `[]` System program
+    pub fn remove_config_line(ctx: Context<RemoveConfigLine>, index: u32) -> Result<()> {
+        instructions::remove_config_line(ctx, index)
+    }
+
+    /// Increase the name and uri lengths of the config lines.
+    ///
+    /// The account is reallocated and the config lines already added are moved to the
+    /// new layout. This is only allowed before any item is minted and is not supported
+    /// when config lines are verified using a merkle root. The account can grow by at
+    /// most 10 KB (`MAX_PERMITTED_DATA_INCREASE`) per instruction, so the lengths of
+    /// large candy machines need to be increased in several steps.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Candy Machine account
+    ///   1. `[signer]` Candy Machine authority
+    ///   2. `[signer, writable]` Payer
+    ///   3. `[]` System program
+    pub fn resize_config_lines(
+        ctx: Context<ResizeConfigLines>,
+        name_length: u32,
+        uri_length: u32,
+    ) -> Result<()> {
+        instructions::resize_config_lines(ctx, name_length, uri_length)
+    }
+
+    /// Reveal a hidden settings item, updating its metadata with the revealed name and uri.
+    ///
+    /// The on-chain reveal must be enabled, in which case the hash of the hidden settings
+    /// is the merkle root of the revealed items, where each leaf is the hash of the
+    /// (index, name, uri) of an item. This instruction can be
+    /// called by any account: the revealed name and uri are verified against the hash,
+    /// and the index is verified against the hidden name and uri of the item. After the
+    /// reveal, the update authority of the item is set to the collection update authority.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[]` Candy Machine account
+    ///   1. `[]` Authority PDA (seeds `["candy_machine", candy machine id]`)
+    ///   2. `[signer, writable]` Payer
+    ///   3. `[]` Mint account of the NFT
+    ///   4. `[writable]` Metadata account of the NFT
+    ///   5. `[]` Master edition account of the NFT
+    ///   6. `[optional]` Collection metadata (required when the candy machine has a collection)
+    ///   7. `[]` Token Metadata program
+    ///   8. `[]` System program
+    ///   9. `[]` Instructions sysvar account
+    pub fn reveal_item(
+        ctx: Context<RevealItem>,
+        index: u32,
+        config_line: ConfigLineProof,
+    ) -> Result<()> {
+        instructions::reveal_item(ctx, index, config_line)
+    }
+
+    /// Reveal a mint request, minting the item selected by the hash of the first slot
+    /// after the request.
+    ///
+    /// This instruction can be called by any account, but the NFT is always minted to the
+    /// owner of the request. Requests are revealed in the order they were created and no
+    /// other item can be minted or removed while requests are pending, so the item
+    /// selected for a request cannot be influenced once the slot hash is known. When config lines
+    /// are verified using a merkle root, the config line of the selected item must be
+    /// supplied with its proof.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Candy Machine account
+    ///   1. `[writable]` Authority PDA (seeds `["candy_machine", candy machine id]`)
+    ///   2. `[writable]` Mint request (seeds `["mint_request", candy machine id, NFT owner]`)
+    ///   3. `[writable]` Mint request payer
+    ///   4. `[signer]` Payer
+    ///   5. `[]` NFT owner
+    ///   6. `[writable]` Mint account of the NFT
+    ///   7. `[signer]` Mint authority of the NFT
+    ///   8. `[writable]` Metadata account of the NFT
+    ///   9. `[writable]` Master edition account of the NFT
+    ///   10. `[optional, writable]` Destination token account
+    ///   11. `[optional, writable]` Token record
+    ///   12. `[]` Collection delegate or authority record
+    ///   13. `[]` Collection mint
+    ///   14. `[writable]` Collection metadata
+    ///   15. `[]` Collection master edition
+    ///   16. `[]` Collection update authority
+    ///   17. `[]` Token Metadata program
+    ///   18. `[]` SPL Token program
+    ///   19. `[optional]` SPL Associated Token program
+    ///   20. `[]` System program
+    ///   21. `[]` Instructions sysvar account
+    ///   22. `[]` SlotHashes sysvar cluster data.
+    ///   23. `[optional]` Token Authorization Rules program
+    ///   24. `[optional]` Token authorization rules account
+    ///   25. `[optional]` MPL Core program
+    ///   26. `[optional, writable]` Merkle tree
+    ///   27. `[optional, writable]` Tree config
+    ///   28. `[optional]` Bubblegum collection signer
+    ///   29. `[optional]` Bubblegum program
+    ///   30. `[optional]` SPL Noop program
+    ///   31. `[optional]` SPL Account Compression program
+    ///   32. `[optional]` SPL Token-2022 program
+    ///   33. `[optional, writable]` Mint receipt (seeds `["mint_receipt", candy machine id, NFT mint]`)
+    ///
+    /// When config lines are stored on page accounts, the page accounts are provided as
+    /// remaining accounts. When printing editions, the remaining accounts are the master
+    /// metadata, master edition, master token account (owned by the authority PDA) and
+    /// edition marker accounts of the master edition mint set on the candy machine. When
+    /// a mint delegate is set, the delegate account is also provided as a remaining
+    /// account.
+    pub fn reveal_mint<'info>(
+        ctx: Context<'_, '_, '_, 'info, RevealMint<'info>>,
+        config_line: Option<ConfigLineProof>,
+    ) -> Result<()> {
+        instructions::reveal_mint(ctx, config_line)
+    }
+
     /// Set a new authority of the candy machine.
     ///
     /// # Accounts
@@ -192,10 +669,73 @@ pub mod candy_machine_core {
     ///   16. `[]` Instructions sysvar account
     ///   17. `[optional]` Token Authorization Rules program
     ///   18. `[optional]` Token authorization rules account
-    pub fn set_collection_v2(ctx: Context<SetCollectionV2>) -> Result<()> {
+    ///
+    /// For MPL Core and Token-2022 collections, the MPL Core program or the SPL Token-2022
+    /// program must be passed as a remaining account. The group update authority of a
+    /// Token-2022 collection is returned to the authority recorded when it was transferred.
+    pub fn set_collection_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetCollectionV2<'info>>,
+    ) -> Result<()> {
         instructions::set_collection_v2(ctx)
     }
 
+    /// Enable or disable the commit-reveal mint.
+    ///
+    /// When enabled, a mint only reserves a mint and creates a mint request; the item is
+    /// selected and minted when the request is revealed. Since anyone can reveal a request,
+    /// the commit-reveal mint cannot be used with soulbound items or a mint delegate,
+    /// which require the signature of the NFT owner.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Candy Machine account
+    ///   1. `[signer]` Candy Machine authority
+    pub fn set_commit_reveal(ctx: Context<SetCommitReveal>, enabled: bool) -> Result<()> {
+        instructions::set_commit_reveal(ctx, enabled)
+    }
+
+    /// Set the merkle root of the config lines.
+    ///
+    /// Config lines are not stored on the account; they are supplied at mint time and
+    /// verified against the merkle root, where each leaf is the hash of the (index, name,
+    /// uri) of a config line. The root can only be set before any config line is loaded
+    /// and before the mint starts. When the root is first set, the space reserved for
+    /// config lines is released and its rent returned to the payer.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Candy Machine account
+    ///   1. `[signer]` Candy Machine authority
+    ///   2. `[signer, writable]` Payer
+    ///   3. `[]` System program
+    pub fn set_config_lines_root(ctx: Context<SetConfigLinesRoot>, root: [u8; 32]) -> Result<()> {
+        instructions::set_config_lines_root(ctx, root)
+    }
+
+    /// Set the number of items available of a candy machine using config lines.
+    ///
+    /// The account is reallocated and the config lines, loaded bit-mask and mint indices
+    /// are moved to the new layout. This is only allowed before any item is minted. When
+    /// reducing the number of items, the removed config lines must not have been added;
+    /// rarity tiers (if any) must be cleared before changing the number of items. Candy
+    /// machines storing config lines on page accounts cannot change the number of items,
+    /// since pages are allocated for the items available when they are added. The account
+    /// can grow by at most 10 KB (`MAX_PERMITTED_DATA_INCREASE`) per instruction, so the
+    /// number of items of large candy machines needs to be increased in several steps.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Candy Machine account
+    ///   1. `[signer]` Candy Machine authority
+    ///   2. `[signer, writable]` Payer
+    ///   3. `[]` System program
+    pub fn set_items_available(
+        ctx: Context<SetItemsAvailable>,
+        items_available: u64,
+    ) -> Result<()> {
+        instructions::set_items_available(ctx, items_available)
+    }
+
     /// Set a new mint authority of the candy machine.
     ///
     /// # Accounts
@@ -207,6 +747,155 @@ pub mod candy_machine_core {
         instructions::set_mint_authority(ctx)
     }
 
+    /// Set or clear the delegate approved on minted Programmable NFTs.
+    ///
+    /// When set, each Programmable NFT minted has the delegate approved on its token
+    /// record with the specified role (staking, locked transfer or utility). The NFT
+    /// owner must sign the mint transaction and the delegate account is provided as a
+    /// remaining account. A mint delegate cannot be used with soulbound items or the
+    /// commit-reveal mint.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Candy Machine account
+    ///   1. `[signer]` Candy Machine authority
+    ///   2. `[signer, writable]` Payer
+    ///   3. `[]` System program
+    pub fn set_mint_delegate(
+        ctx: Context<SetMintDelegate>,
+        mint_delegate: Option<MintDelegate>,
+    ) -> Result<()> {
+        instructions::set_mint_delegate(ctx, mint_delegate)
+    }
+
+    /// Set the number of tokens minted per item of fungible token standards.
+    ///
+    /// When not set, a single token is minted per item. The supply of a token type is the
+    /// number of its config lines times this amount.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Candy Machine account
+    ///   1. `[signer]` Candy Machine authority
+    ///   2. `[signer, writable]` Payer
+    ///   3. `[]` System program
+    pub fn set_fungible_amount(ctx: Context<SetFungibleAmount>, amount: u64) -> Result<()> {
+        instructions::set_fungible_amount(ctx, amount)
+    }
+
+    /// Set or clear the mint of the master edition printed by the candy machine.
+    ///
+    /// Candy machines printing editions (`PRINT_EDITION` token standard) must set the
+    /// master edition mint: the master edition accounts provided at mint time are
+    /// required to match it. The master edition cannot be changed after the mint starts.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Candy Machine account
+    ///   1. `[signer]` Candy Machine authority
+    ///   2. `[signer, writable]` Payer
+    ///   3. `[]` System program
+    pub fn set_master_edition(
+        ctx: Context<SetMasterEdition>,
+        master_edition_mint: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_master_edition(ctx, master_edition_mint)
+    }
+
+    /// Enable or disable the on-chain reveal of hidden settings items.
+    ///
+    /// When enabled, minted items keep the authority PDA as their update authority until
+    /// they are revealed using the `reveal_item` instruction, and the hash of the hidden
+    /// settings is interpreted as the merkle root of the revealed items. The hidden
+    /// settings cannot be changed after the mint starts.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Candy Machine account
+    ///   1. `[signer]` Candy Machine authority
+    pub fn set_onchain_reveal(ctx: Context<SetOnchainReveal>, enabled: bool) -> Result<()> {
+        instructions::set_onchain_reveal(ctx, enabled)
+    }
+
+    /// Enable or disable mint receipts.
+    ///
+    /// When enabled, each mint creates a receipt account (seeds `["mint_receipt", candy
+    /// machine id, NFT mint]`) recording the index minted, the mint number, the payer,
+    /// the slot and timestamp of the mint and the candy guard group label (if any).
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Candy Machine account
+    ///   1. `[signer]` Candy Machine authority
+    pub fn set_mint_receipt(ctx: Context<SetMintReceipt>, enabled: bool) -> Result<()> {
+        instructions::set_mint_receipt(ctx, enabled)
+    }
+
+    /// Pause or resume minting.
+    ///
+    /// While paused, the candy machine rejects any new mint. Pending mint requests can
+    /// still be revealed.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Candy Machine account
+    ///   1. `[signer]` Candy Machine authority
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        instructions::set_paused(ctx, paused)
+    }
+
+    /// Enable or disable the pick item mode.
+    ///
+    /// When enabled, the mint authority can mint a specific item using the `index`
+    /// argument of the `mint_v3` instruction. The mint authority must be a candy guard,
+    /// which restricts the items requested with the item range guard. The pick item mode
+    /// cannot be used with rarity tiers.
+    ///
+    /// Requested items are skipped by the random selection, so the space to store the
+    /// number of skipped items is allocated when the mode is enabled.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Candy Machine account
+    ///   1. `[signer]` Candy Machine authority
+    ///   2. `[signer, writable]` Payer
+    ///   3. `[]` System program
+    pub fn set_pick_item(ctx: Context<SetPickItem>, enabled: bool) -> Result<()> {
+        instructions::set_pick_item(ctx, enabled)
+    }
+
+    /// Set the rarity tiers of the candy machine.
+    ///
+    /// Each tier is a contiguous range of config lines with a selection weight. The
+    /// random selection of a config line is weighted by the tier of the line. An empty
+    /// list of tiers clears the weights. Rarity tiers cannot be used with the pick item
+    /// mode.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Candy Machine account
+    ///   1. `[signer]` Candy Machine authority
+    ///   2. `[signer, writable]` Payer
+    ///   3. `[]` System program
+    pub fn set_rarity_tiers(ctx: Context<SetRarityTiers>, tiers: Vec<RarityTier>) -> Result<()> {
+        instructions::set_rarity_tiers(ctx, tiers)
+    }
+
+    /// Enable or disable minting soulbound items.
+    ///
+    /// When enabled, each NFT or Programmable NFT minted is locked in the owner's token
+    /// account, with the authority PDA as its delegate, so it cannot be transferred. The
+    /// NFT owner must sign the mint transaction, so soulbound items cannot be used with
+    /// the commit-reveal mint. The setting can only change before any item is minted.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Candy Machine account
+    ///   1. `[signer]` Candy Machine authority
+    pub fn set_soulbound(ctx: Context<SetSoulbound>, enabled: bool) -> Result<()> {
+        instructions::set_soulbound(ctx, enabled)
+    }
+
     /// Set the token standard of the minted NFTs.
     ///
     /// # Accounts
@@ -229,8 +918,26 @@ pub mod candy_machine_core {
         instructions::set_token_standard(ctx, token_standard)
     }
 
+    /// Set the encoding of the config line URIs.
+    ///
+    /// Except for UTF-8, URIs are stored as raw bytes and encoded when the config line
+    /// is retrieved. The URI length of the config line settings is then the number of
+    /// bytes of the decoded URI. The encoding can only be set before config lines are
+    /// added.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Candy Machine account
+    ///   1. `[signer]` Candy Machine authority
+    pub fn set_uri_encoding(ctx: Context<SetUriEncoding>, encoding: UriEncoding) -> Result<()> {
+        instructions::set_uri_encoding(ctx, encoding)
+    }
+
     /// Update the candy machine configuration.
     ///
+    /// The hidden section of V3 accounts starts right after the candy machine data, so
+    /// their data cannot get larger; when it gets smaller, the hidden section is moved.
+    ///
     /// # Accounts
     ///
     ///   0. `[writable]` Candy Machine account
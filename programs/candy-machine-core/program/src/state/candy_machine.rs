@@ -2,9 +2,21 @@ use anchor_lang::prelude::*;
 use arrayref::array_ref;
 use mpl_token_metadata::{accounts::Metadata, types::ProgrammableConfig};
 
-use crate::constants::{RULE_SET_LENGTH, SET};
+use solana_program::{pubkey::PUBKEY_BYTES, system_instruction::MAX_PERMITTED_DATA_LENGTH};
 
-use super::candy_machine_data::CandyMachineData;
+use crate::{
+    constants::{
+        CONFIG_LINES_ROOT_LENGTH, CONFIG_LINE_PAGES_FLAG, FEATURE_FLAGS_INDEX,
+        FUNGIBLE_AMOUNT_LENGTH, HIDDEN_SECTION, ITEMS_REMOVED_LENGTH, ITEMS_SKIPPED_LENGTH,
+        ITEMS_TRACKED_FLAG, MAX_CONFIG_LINE_PAGES, MAX_RARITY_TIERS, MERKLE_CONFIG_LINES_FEATURE,
+        MINT_DELEGATE_LENGTH, NEXT_REVEAL_INDEX, PAUSED_FLAG, PENDING_REQUESTS_INDEX,
+        RARITY_TIER_LENGTH, RULE_SET_LENGTH, SET, SOULBOUND_FLAG, STATE_FLAGS_INDEX,
+        URI_ENCODING_MASK, URI_ENCODING_SHIFT,
+    },
+    errors::CandyError,
+};
+
+use super::{candy_machine_data::CandyMachineData, config_line_page::ConfigLinePage};
 
 /// Candy machine state and config data.
 #[account]
@@ -14,7 +26,8 @@ pub struct CandyMachine {
     pub version: AccountVersion,
     /// Token standard to mint NFTs.
     pub token_standard: u8,
-    /// Features flags.
+    /// Features flags, state flags, the number of pending mint requests and the sequence
+    /// of the next mint request to be revealed.
     pub features: [u8; 6],
     /// Authority address.
     pub authority: Pubkey,
@@ -26,26 +39,444 @@ pub struct CandyMachine {
     pub items_redeemed: u64,
     /// Candy machine configuration data.
     pub data: CandyMachineData,
-    // hidden data section to avoid deserialisation:
+    // hidden data section to avoid deserialisation (starting at `HIDDEN_SECTION` or,
+    // for V3 accounts, right after the candy machine data):
     //
     // - (u32) how many actual lines of data there are currently (eventually
     //   equals items available)
-    // - (ConfigLine * items_available) lines and lines of name + uri data
+    // - (ConfigLine * items_available) lines and lines of name + uri data (not
+    //   present when config lines are stored on page accounts)
     // - (item_available / 8) + 1 bit mask to keep track of which ConfigLines
-    //   have been added
-    // - (u32 * items_available) mint indices
+    //   have been added; once minting starts, it keeps track of the items taken
+    //   (minted or removed), which are unset (set when using a merkle root)
+    // - (u32 * items_available) mint indices (u16 for V3 accounts when the number of
+    //   items fits in a u16)
     // - for pNFT:
     //   (u8) indicates whether to use a custom rule set
     //   (Pubkey) custom rule set
+    // - for merkle config lines or finalized config lines (the rule set space is
+    //   always present):
+    //   (32 bytes) merkle root or commitment of the config lines
+    // - for rarity tiers (the rule set and root spaces are always present):
+    //   (u8) number of tiers
+    //   (u32 weight, u32 size, u32 remaining) * number of tiers
+    // - for config line pages (the rule set, root and maximum rarity tiers spaces are
+    //   always present):
+    //   (u8) number of pages
+    //   (Pubkey) page account * number of pages
+    // - for mint delegates (the rule set, root, maximum rarity tiers and maximum config
+    //   line pages spaces are always present):
+    //   (u8) delegate role (0 when there is no delegate)
+    //   (Pubkey) delegate
+    //   (Pubkey) locked address
+    // - for removed config lines (the rule set, root, maximum rarity tiers, maximum
+    //   config line pages and mint delegate spaces are always present):
+    //   (u64) number of config lines removed
+    // - for print editions (the rule set, root, maximum rarity tiers, maximum config line
+    //   pages, mint delegate and removed config lines spaces are always present):
+    //   (Pubkey) mint of the master edition
+    // - for fungible token standards (the rule set, root, maximum rarity tiers, maximum
+    //   config line pages, mint delegate, removed config lines and master edition spaces
+    //   are always present):
+    //   (u64) number of tokens minted per item
+    // - for Token-2022 collections (the rule set, root, maximum rarity tiers, maximum
+    //   config line pages, mint delegate, removed config lines, master edition and
+    //   fungible amount spaces are always present):
+    //   (Pubkey) group update authority before it was transferred to the authority PDA
+    // - for picked items and removed config lines (the rule set, root, maximum rarity
+    //   tiers, maximum config line pages, mint delegate, removed config lines, master
+    //   edition, fungible amount and group authority spaces are always present):
+    //   (u32) number of items skipped by the random selection
 }
 
 impl CandyMachine {
+    /// Returns whether the specified feature flag is enabled.
+    pub fn is_feature_enabled(&self, feature: u8) -> bool {
+        self.features[FEATURE_FLAGS_INDEX] & feature == feature
+    }
+
+    /// Enables or disables the specified feature flag.
+    pub fn set_feature(&mut self, feature: u8, enabled: bool) {
+        if enabled {
+            self.features[FEATURE_FLAGS_INDEX] |= feature;
+        } else {
+            self.features[FEATURE_FLAGS_INDEX] &= !feature;
+        }
+    }
+
+    /// Returns whether minting is paused.
+    pub fn is_paused(&self) -> bool {
+        self.features[STATE_FLAGS_INDEX] & PAUSED_FLAG == PAUSED_FLAG
+    }
+
+    /// Pauses or resumes minting.
+    pub fn set_paused(&mut self, paused: bool) {
+        if paused {
+            self.features[STATE_FLAGS_INDEX] |= PAUSED_FLAG;
+        } else {
+            self.features[STATE_FLAGS_INDEX] &= !PAUSED_FLAG;
+        }
+    }
+
+    /// Returns whether the candy machine has a collection, which is indicated by a
+    /// collection mint different than the default address.
+    pub fn has_collection(&self) -> bool {
+        self.collection_mint != Pubkey::default()
+    }
+
+    /// Returns whether config lines are stored on page accounts.
+    pub fn has_config_line_pages(&self) -> bool {
+        self.features[STATE_FLAGS_INDEX] & CONFIG_LINE_PAGES_FLAG == CONFIG_LINE_PAGES_FLAG
+    }
+
+    /// Sets whether config lines are stored on page accounts.
+    pub fn set_config_line_pages(&mut self, enabled: bool) {
+        if enabled {
+            self.features[STATE_FLAGS_INDEX] |= CONFIG_LINE_PAGES_FLAG;
+        } else {
+            self.features[STATE_FLAGS_INDEX] &= !CONFIG_LINE_PAGES_FLAG;
+        }
+    }
+
+    /// Returns whether minted items are soulbound (locked to the owner).
+    pub fn is_soulbound(&self) -> bool {
+        self.features[STATE_FLAGS_INDEX] & SOULBOUND_FLAG == SOULBOUND_FLAG
+    }
+
+    /// Sets whether minted items are soulbound (locked to the owner).
+    pub fn set_soulbound(&mut self, soulbound: bool) {
+        if soulbound {
+            self.features[STATE_FLAGS_INDEX] |= SOULBOUND_FLAG;
+        } else {
+            self.features[STATE_FLAGS_INDEX] &= !SOULBOUND_FLAG;
+        }
+    }
+
+    /// Returns whether the loaded bit-mask tracks the items taken (minted or removed).
+    pub fn is_items_tracked(&self) -> bool {
+        self.features[STATE_FLAGS_INDEX] & ITEMS_TRACKED_FLAG == ITEMS_TRACKED_FLAG
+    }
+
+    /// Sets whether the loaded bit-mask tracks the items taken (minted or removed).
+    pub fn set_items_tracked(&mut self, tracked: bool) {
+        if tracked {
+            self.features[STATE_FLAGS_INDEX] |= ITEMS_TRACKED_FLAG;
+        } else {
+            self.features[STATE_FLAGS_INDEX] &= !ITEMS_TRACKED_FLAG;
+        }
+    }
+
+    /// Returns the encoding of the config line URIs stored on the account.
+    pub fn uri_encoding(&self) -> UriEncoding {
+        match (self.features[FEATURE_FLAGS_INDEX] & URI_ENCODING_MASK) >> URI_ENCODING_SHIFT {
+            1 => UriEncoding::Base64Url,
+            2 => UriEncoding::Base58,
+            3 => UriEncoding::Base32,
+            _ => UriEncoding::Utf8,
+        }
+    }
+
+    /// Sets the encoding of the config line URIs stored on the account.
+    pub fn set_uri_encoding(&mut self, encoding: UriEncoding) {
+        self.features[FEATURE_FLAGS_INDEX] = (self.features[FEATURE_FLAGS_INDEX]
+            & !URI_ENCODING_MASK)
+            | ((encoding as u8) << URI_ENCODING_SHIFT);
+    }
+
+    /// Returns the number of mint requests waiting to be revealed.
+    pub fn pending_requests(&self) -> u16 {
+        u16::from_le_bytes(*array_ref![self.features, PENDING_REQUESTS_INDEX, 2])
+    }
+
+    /// Sets the number of mint requests waiting to be revealed.
+    pub fn set_pending_requests(&mut self, pending_requests: u16) {
+        self.features[PENDING_REQUESTS_INDEX..PENDING_REQUESTS_INDEX + 2]
+            .copy_from_slice(&pending_requests.to_le_bytes());
+    }
+
+    /// Returns the sequence of the next mint request to be revealed (or closed).
+    ///
+    /// Mint requests are revealed in the order they were created, so the sequence of a
+    /// new request is the sequence of the next request plus the number of pending
+    /// requests. The sequence wraps around, which is unambiguous since there are
+    /// fewer pending requests than possible sequences.
+    pub fn next_reveal_sequence(&self) -> u16 {
+        u16::from_le_bytes(*array_ref![self.features, NEXT_REVEAL_INDEX, 2])
+    }
+
+    /// Advances the sequence of the next mint request to be revealed, after a request
+    /// is revealed or closed.
+    pub fn advance_reveal_sequence(&mut self) -> Result<()> {
+        let pending_requests = self
+            .pending_requests()
+            .checked_sub(1)
+            .ok_or(CandyError::NumericalOverflowError)?;
+        self.set_pending_requests(pending_requests);
+
+        let sequence = self.next_reveal_sequence().wrapping_add(1);
+        self.features[NEXT_REVEAL_INDEX..NEXT_REVEAL_INDEX + 2]
+            .copy_from_slice(&sequence.to_le_bytes());
+
+        Ok(())
+    }
+
+    /// Returns the position of the hidden section on the account data.
+    pub fn get_hidden_section(&self) -> Result<usize> {
+        match self.version {
+            AccountVersion::V3 => self.data.get_hidden_section_v3(),
+            _ => Ok(HIDDEN_SECTION),
+        }
+    }
+
+    /// Returns the size of each value of the mint indices array.
+    pub fn get_mint_index_size(&self) -> usize {
+        match self.version {
+            AccountVersion::V3 => self.data.get_compact_index_size(),
+            _ => 4,
+        }
+    }
+
+    /// Returns the size of each config line stored on the account, which is zero when
+    /// config lines are stored on page accounts or verified using a merkle root.
+    pub fn get_stored_config_line_size(&self) -> usize {
+        if self.has_config_line_pages() || self.is_feature_enabled(MERKLE_CONFIG_LINES_FEATURE) {
+            0
+        } else {
+            self.data.get_config_line_size()
+        }
+    }
+
+    /// Returns the number of config lines stored on each page account.
+    pub fn get_config_lines_per_page(&self) -> usize {
+        (MAX_PERMITTED_DATA_LENGTH as usize - ConfigLinePage::HEADER)
+            / self.data.get_config_line_size().max(1)
+    }
+
+    /// Returns the space required by the account, excluding the sections stored after
+    /// the mint indices (rule set, config lines root, rarity tiers and config line pages).
+    pub fn get_space(&self) -> Result<usize> {
+        self.data.get_space_for_layout(
+            self.get_hidden_section()?,
+            self.get_stored_config_line_size(),
+            self.get_mint_index_size(),
+        )
+    }
+
+    /// Returns the position of the config lines merkle root (or commitment of finalized
+    /// config lines) on the account data.
+    pub fn get_config_lines_root_position(&self) -> Result<usize> {
+        Ok(self.get_space()? + 1 + RULE_SET_LENGTH)
+    }
+
+    /// Returns the position of the rarity tiers section on the account data.
+    pub fn get_rarity_tiers_position(&self) -> Result<usize> {
+        Ok(self.get_config_lines_root_position()? + CONFIG_LINES_ROOT_LENGTH)
+    }
+
+    /// Returns whether rarity tiers are set.
+    pub fn has_rarity_tiers(&self, account_data: &[u8]) -> Result<bool> {
+        let position = self.get_rarity_tiers_position()?;
+        Ok(account_data.len() > position && account_data[position] > 0)
+    }
+
+    /// Returns the position of the config line pages section on the account data.
+    pub fn get_config_line_pages_position(&self) -> Result<usize> {
+        Ok(self.get_rarity_tiers_position()? + 1 + MAX_RARITY_TIERS * RARITY_TIER_LENGTH)
+    }
+
+    /// Returns the addresses of the config line page accounts, in page order.
+    pub fn get_config_line_pages(&self, account_data: &[u8]) -> Result<Vec<Pubkey>> {
+        let position = self.get_config_line_pages_position()?;
+
+        if account_data.len() <= position {
+            return Ok(Vec::new());
+        }
+
+        Ok((0..account_data[position] as usize)
+            .map(|page| {
+                Pubkey::from(*array_ref![
+                    account_data,
+                    position + 1 + page * PUBKEY_BYTES,
+                    PUBKEY_BYTES
+                ])
+            })
+            .collect())
+    }
+
+    /// Returns the position of the mint delegate section on the account data.
+    pub fn get_mint_delegate_position(&self) -> Result<usize> {
+        Ok(self.get_config_line_pages_position()? + 1 + MAX_CONFIG_LINE_PAGES * PUBKEY_BYTES)
+    }
+
+    /// Returns the delegate approved on the token record of minted Programmable NFTs
+    /// (if any).
+    pub fn get_mint_delegate(&self, account_data: &[u8]) -> Result<Option<MintDelegate>> {
+        let position = self.get_mint_delegate_position()?;
+
+        if account_data.len() < position + MINT_DELEGATE_LENGTH {
+            return Ok(None);
+        }
+
+        let role = match account_data[position] {
+            1 => MintDelegateRole::Staking,
+            2 => MintDelegateRole::LockedTransfer,
+            3 => MintDelegateRole::Utility,
+            _ => return Ok(None),
+        };
+
+        Ok(Some(MintDelegate {
+            role,
+            delegate: Pubkey::from(*array_ref![account_data, position + 1, PUBKEY_BYTES]),
+            locked_address: Pubkey::from(*array_ref![
+                account_data,
+                position + 1 + PUBKEY_BYTES,
+                PUBKEY_BYTES
+            ]),
+        }))
+    }
+
+    /// Returns the position of the number of removed config lines on the account data.
+    pub fn get_items_removed_position(&self) -> Result<usize> {
+        Ok(self.get_mint_delegate_position()? + MINT_DELEGATE_LENGTH)
+    }
+
+    /// Returns the number of config lines removed from the items available to mint.
+    pub fn get_items_removed(&self, account_data: &[u8]) -> Result<u64> {
+        let position = self.get_items_removed_position()?;
+
+        if account_data.len() < position + ITEMS_REMOVED_LENGTH {
+            return Ok(0);
+        }
+
+        Ok(u64::from_le_bytes(*array_ref![
+            account_data,
+            position,
+            ITEMS_REMOVED_LENGTH
+        ]))
+    }
+
+    /// Returns the position of the master edition mint on the account data.
+    pub fn get_master_edition_mint_position(&self) -> Result<usize> {
+        Ok(self.get_items_removed_position()? + ITEMS_REMOVED_LENGTH)
+    }
+
+    /// Returns the mint of the master edition printed by the candy machine (if any).
+    pub fn get_master_edition_mint(&self, account_data: &[u8]) -> Result<Option<Pubkey>> {
+        let position = self.get_master_edition_mint_position()?;
+
+        if account_data.len() < position + PUBKEY_BYTES {
+            return Ok(None);
+        }
+
+        let mint = Pubkey::from(*array_ref![account_data, position, PUBKEY_BYTES]);
+
+        Ok(if mint == Pubkey::default() {
+            None
+        } else {
+            Some(mint)
+        })
+    }
+
+    /// Returns the position of the number of tokens minted per item of fungible token
+    /// standards on the account data.
+    pub fn get_fungible_amount_position(&self) -> Result<usize> {
+        Ok(self.get_master_edition_mint_position()? + PUBKEY_BYTES)
+    }
+
+    /// Returns the number of tokens minted per item of fungible token standards, which
+    /// is one when not set.
+    pub fn get_fungible_amount(&self, account_data: &[u8]) -> Result<u64> {
+        let position = self.get_fungible_amount_position()?;
+
+        if account_data.len() < position + FUNGIBLE_AMOUNT_LENGTH {
+            return Ok(1);
+        }
+
+        Ok(u64::from_le_bytes(*array_ref![account_data, position, FUNGIBLE_AMOUNT_LENGTH]).max(1))
+    }
+
+    /// Returns the position of the previous group update authority of a Token-2022
+    /// collection on the account data.
+    pub fn get_group_authority_position(&self) -> Result<usize> {
+        Ok(self.get_fungible_amount_position()? + FUNGIBLE_AMOUNT_LENGTH)
+    }
+
+    /// Returns the group update authority of the Token-2022 collection before it was
+    /// transferred to the authority PDA (if recorded).
+    pub fn get_group_authority(&self, account_data: &[u8]) -> Result<Option<Pubkey>> {
+        let position = self.get_group_authority_position()?;
+
+        if account_data.len() < position + PUBKEY_BYTES {
+            return Ok(None);
+        }
+
+        let authority = Pubkey::from(*array_ref![account_data, position, PUBKEY_BYTES]);
+
+        Ok(if authority == Pubkey::default() {
+            None
+        } else {
+            Some(authority)
+        })
+    }
+
+    /// Returns the position of the number of items skipped by the random selection on
+    /// the account data.
+    pub fn get_items_skipped_position(&self) -> Result<usize> {
+        Ok(self.get_group_authority_position()? + PUBKEY_BYTES)
+    }
+
+    /// Returns the number of items skipped by the random selection, i.e., the picked
+    /// items and removed config lines that are still on the available mint indices.
+    pub fn get_items_skipped(&self, account_data: &[u8]) -> Result<u32> {
+        let position = self.get_items_skipped_position()?;
+
+        if account_data.len() < position + ITEMS_SKIPPED_LENGTH {
+            return Ok(0);
+        }
+
+        Ok(u32::from_le_bytes(*array_ref![
+            account_data,
+            position,
+            ITEMS_SKIPPED_LENGTH
+        ]))
+    }
+
+    /// Returns the number of items that can still be minted (or reserved by a mint
+    /// request), excluding removed config lines.
+    pub fn get_items_remaining(&self, account_data: &[u8]) -> Result<u64> {
+        Ok(self
+            .data
+            .items_available
+            .saturating_sub(self.items_redeemed)
+            .saturating_sub(self.get_items_removed(account_data)?))
+    }
+
+    /// Returns the number of items taken out of the available mint indices, i.e., the
+    /// items minted and the removed config lines.
+    ///
+    /// Items reserved by pending mint requests are still in the available indices, so
+    /// they are not included.
+    pub fn get_items_taken(&self, account_data: &[u8]) -> Result<u64> {
+        let items_removed = self.get_items_removed(account_data)?;
+
+        self.items_redeemed
+            .checked_sub(self.pending_requests() as u64)
+            .and_then(|items_minted| items_minted.checked_add(items_removed))
+            .ok_or_else(|| CandyError::NumericalOverflowError.into())
+    }
+
+    /// Returns whether the items available to mint have started to change, i.e., an item
+    /// was minted (or reserved) or a config line was removed.
+    pub fn has_started(&self, account_data: &[u8]) -> Result<bool> {
+        Ok(self.items_redeemed > 0 || self.get_items_removed(account_data)? > 0)
+    }
+
     pub fn get_rule_set(
         &self,
         account_data: &[u8],
-        collection_metadata: &Metadata,
+        collection_metadata: Option<&Metadata>,
     ) -> Result<Option<Pubkey>> {
-        let required_length = self.data.get_space_for_candy()?;
+        let required_length = self.get_space()?;
 
         if account_data.len() <= required_length {
             return Ok(None);
@@ -60,7 +491,7 @@ impl CandyMachine {
                 RULE_SET_LENGTH
             ])))
         } else if let Some(ProgrammableConfig::V1 { rule_set }) =
-            collection_metadata.programmable_config
+            collection_metadata.and_then(|metadata| metadata.programmable_config.clone())
         {
             Ok(rule_set)
         } else {
@@ -69,6 +500,88 @@ impl CandyMachine {
     }
 }
 
+/// Rarity tier of a contiguous range of config lines.
+///
+/// Tiers are sequential: the first tier includes the first `size` config lines, the
+/// second tier the following `size` config lines and so on.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RarityTier {
+    /// Draw weight of each config line of the tier.
+    pub weight: u32,
+    /// Number of config lines of the tier.
+    pub size: u32,
+}
+
+/// Role of the token delegate approved when a Programmable NFT is minted.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MintDelegateRole {
+    /// Staking delegate, which can lock and unlock the token.
+    Staking,
+    /// Locked transfer delegate, which can lock the token and transfer it to the
+    /// locked address.
+    LockedTransfer,
+    /// Utility delegate, which can lock, unlock and burn the token.
+    Utility,
+}
+
+/// Token delegate approved on the token record of minted Programmable NFTs.
+///
+/// The role is stored as its value plus one, so a zero byte indicates that there is
+/// no delegate.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MintDelegate {
+    /// Role of the delegate.
+    pub role: MintDelegateRole,
+    /// Address of the delegate (e.g., a program PDA).
+    pub delegate: Pubkey,
+    /// Address the token can be transferred to by a `LockedTransfer` delegate (ignored
+    /// by the other roles).
+    pub locked_address: Pubkey,
+}
+
+/// Encoding of the config line URIs stored on the account.
+///
+/// Except for `Utf8`, the URI of a config line is stored as raw bytes (the URI length
+/// being the number of bytes) and is encoded when the config line is retrieved. The
+/// URI of a config line is provided in its encoded form when adding config lines.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UriEncoding {
+    /// NUL-padded UTF-8 string.
+    #[default]
+    Utf8,
+    /// URL-safe base64 without padding (e.g., Arweave transaction ids).
+    Base64Url,
+    /// Base58 (e.g., IPFS CIDv0).
+    Base58,
+    /// Lowercase base32 without padding (e.g., IPFS CIDv1 after the multibase prefix).
+    Base32,
+}
+
+/// Config line supplied at mint time, together with the merkle proof of the line.
+///
+/// The leaf of the merkle tree is the hash of the (index, name, uri) of the line.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ConfigLineProof {
+    /// Name of the asset.
+    pub name: String,
+    /// URI to JSON metadata.
+    pub uri: String,
+    /// Merkle proof of the config line.
+    pub proof: Vec<[u8; 32]>,
+}
+
+/// Arguments of a mint.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct MintArgs {
+    /// Index of the item to mint (requires the pick item mode).
+    pub index: Option<u32>,
+    /// Config line of the item to mint with its merkle proof (required when config lines
+    /// are verified using a merkle root).
+    pub config_line: Option<ConfigLineProof>,
+    /// Group label recorded on the mint receipt (if any).
+    pub group: Option<String>,
+}
+
 /// Config line struct for storing asset (NFT) data pre-mint.
 #[derive(AnchorSerialize, AnchorDeserialize, Debug)]
 pub struct ConfigLine {
@@ -84,4 +597,9 @@ pub enum AccountVersion {
     #[default]
     V1,
     V2,
+    /// Compact layout: the hidden section starts right after the candy machine data
+    /// (instead of reserving space for the maximum length of each field) and mint
+    /// indices are stored as `u16` values when the number of items fits in a `u16`.
+    /// Uses a metadata delegate, as `V2`.
+    V3,
 }
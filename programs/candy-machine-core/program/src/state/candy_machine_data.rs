@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
-use mpl_token_metadata::{MAX_CREATOR_LIMIT, MAX_NAME_LENGTH, MAX_URI_LENGTH};
+use mpl_token_metadata::{MAX_CREATOR_LIMIT, MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH};
 
-use crate::{constants::HIDDEN_SECTION, errors::CandyError, utils::replace_patterns};
+use crate::{
+    constants::{HEADER_V3, HIDDEN_SECTION},
+    errors::CandyError,
+    utils::replace_patterns,
+};
 
 /// Candy machine configuration data.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug)]
@@ -42,7 +46,8 @@ pub struct HiddenSettings {
     pub name: String,
     /// Shared URI
     pub uri: String,
-    /// Hash of the hidden settings file
+    /// Hash of the hidden settings file (merkle root of the revealed items when using
+    /// the on-chain reveal)
     pub hash: [u8; 32],
 }
 
@@ -63,18 +68,55 @@ pub struct ConfigLineSettings {
 
 impl CandyMachineData {
     pub fn get_space_for_candy(&self) -> Result<usize> {
+        self.get_space_for_layout(HIDDEN_SECTION, self.get_config_line_size(), 4)
+    }
+
+    /// Returns the space required by a V3 candy machine account.
+    pub fn get_space_for_candy_v3(&self) -> Result<usize> {
+        self.get_space_for_layout(
+            self.get_hidden_section_v3()?,
+            self.get_config_line_size(),
+            self.get_compact_index_size(),
+        )
+    }
+
+    /// Returns the position of the hidden section of a V3 candy machine account, which
+    /// starts right after the candy machine data.
+    ///
+    /// The symbol is always stored with its maximum length.
+    pub fn get_hidden_section_v3(&self) -> Result<usize> {
+        Ok(HEADER_V3 + self.try_to_vec()?.len() - self.symbol.len() + MAX_SYMBOL_LENGTH)
+    }
+
+    /// Returns the size of each value of the mint indices array of a V3 candy machine
+    /// account. Values are stored with an offset of one when using merkle config lines,
+    /// so `u16` values are only used when the number of items fits in a `u16`.
+    pub fn get_compact_index_size(&self) -> usize {
+        if self.items_available <= u16::MAX as u64 {
+            2
+        } else {
+            4
+        }
+    }
+
+    pub(crate) fn get_space_for_layout(
+        &self,
+        hidden_section: usize,
+        config_line_size: usize,
+        index_size: usize,
+    ) -> Result<usize> {
         Ok(if self.hidden_settings.is_some() {
-            HIDDEN_SECTION
+            hidden_section
         } else {
-            HIDDEN_SECTION
+            hidden_section
                 + 4
-                + (self.items_available as usize) * self.get_config_line_size()
+                + (self.items_available as usize) * config_line_size
                 + (self
                     .items_available
                     .checked_div(8)
                     .ok_or(CandyError::NumericalOverflowError)?
                     + 1) as usize
-                + (self.items_available as usize) * 4
+                + (self.items_available as usize) * index_size
         })
     }
 
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+/// Page account storing a contiguous range of config lines of a candy machine.
+///
+/// Pages allow a candy machine to hold more config lines than fit in a single account:
+/// the first page stores the first `lines_per_page` config lines, the second page the
+/// following `lines_per_page` config lines and so on.
+#[account]
+#[derive(Default, Debug)]
+pub struct ConfigLinePage {
+    /// Candy machine of the page.
+    pub candy_machine: Pubkey,
+    // hidden data section to avoid deserialisation:
+    //
+    // - (ConfigLine * lines_per_page) lines of name + uri data
+}
+
+impl ConfigLinePage {
+    /// Size of the page header, where the config lines start.
+    pub const HEADER: usize = 8 // discriminator
+        + 32; // candy machine
+}
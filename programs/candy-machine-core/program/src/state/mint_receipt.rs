@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_RECEIPT_GROUP_LENGTH;
+
+/// Receipt of a mint, linking the minted item to the candy machine.
+#[account]
+#[derive(Default, Debug)]
+pub struct MintReceipt {
+    /// Candy machine of the mint.
+    pub candy_machine: Pubkey,
+    /// Mint (or asset) account of the item.
+    pub mint: Pubkey,
+    /// Payer of the mint, which also paid the receipt account rent.
+    pub payer: Pubkey,
+    /// Index of the config line minted.
+    pub index: u32,
+    /// Number of items redeemed after the mint.
+    pub items_redeemed: u64,
+    /// Slot of the mint.
+    pub slot: u64,
+    /// Timestamp of the mint.
+    pub timestamp: i64,
+    /// Label of the candy guard group used to mint (if any).
+    pub group: Option<String>,
+    /// Bump of the PDA.
+    pub bump: u8,
+}
+
+impl MintReceipt {
+    /// Size of the account.
+    pub const LEN: usize = 8 // discriminator
+        + 32                 // candy machine
+        + 32                 // mint
+        + 32                 // payer
+        + 4                  // index
+        + 8                  // items redeemed
+        + 8                  // slot
+        + 8                  // timestamp
+        + 1 + 4 + MAX_RECEIPT_GROUP_LENGTH // group
+        + 1; // bump
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+/// Mint request of a candy machine using commit-reveal.
+///
+/// The request reserves a mint for the owner; the item is selected when the request is
+/// revealed using the hash of the first slot after the request slot. Requests are
+/// revealed in the order they were created, so the items available when a request is
+/// revealed only depend on the requests created before it. Anyone can reveal a request
+/// and, once expired, anyone can close it so it does not block the following requests.
+#[account]
+#[derive(Default, Debug)]
+pub struct MintRequest {
+    /// Candy machine of the request.
+    pub candy_machine: Pubkey,
+    /// Owner of the NFT to be minted.
+    pub owner: Pubkey,
+    /// Payer of the request account rent.
+    pub payer: Pubkey,
+    /// Slot of the request.
+    pub slot: u64,
+    /// Sequence of the request, which determines the order of the reveals.
+    pub sequence: u16,
+    /// Bump of the PDA.
+    pub bump: u8,
+}
+
+impl MintRequest {
+    /// Size of the account.
+    pub const LEN: usize = 8 // discriminator
+        + 32                 // candy machine
+        + 32                 // owner
+        + 32                 // payer
+        + 8                  // slot
+        + 2                  // sequence
+        + 1; // bump
+}
@@ -1,5 +1,11 @@
 pub use candy_machine::*;
 pub use candy_machine_data::*;
+pub use config_line_page::*;
+pub use mint_receipt::*;
+pub use mint_request::*;
 
 pub mod candy_machine;
 pub mod candy_machine_data;
+pub mod config_line_page;
+pub mod mint_receipt;
+pub mod mint_request;
@@ -1,5 +1,14 @@
+use std::slice::ChunksExact;
+
 use anchor_lang::prelude::*;
 use arrayref::array_ref;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use bytemuck::bytes_of;
+use mpl_core::{
+    instructions::{AddCollectionPluginV1CpiBuilder, UpdateCollectionPluginV1CpiBuilder},
+    types::{Plugin, UpdateDelegate},
+    Collection,
+};
 use mpl_token_metadata::{
     accounts::Metadata,
     instructions::{
@@ -8,20 +17,38 @@ use mpl_token_metadata::{
     },
     types::TokenStandard,
 };
+use mpl_utils::resize_or_reallocate_account_raw;
 use solana_program::{
     account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction},
+    keccak,
+    program::{invoke, invoke_signed},
     program_memory::sol_memcmp,
     program_pack::{IsInitialized, Pack},
     pubkey::{Pubkey, PUBKEY_BYTES},
 };
+use spl_discriminator::SplDiscriminate;
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
+use spl_token_2022::{
+    extension::{BaseStateWithExtensions, StateWithExtensions},
+    state::Mint,
+};
+use spl_token_metadata_interface::state::TokenMetadata;
 
 use crate::{
     constants::{
-        AUTHORITY_SEED, HIDDEN_SECTION, NULL_STRING, REPLACEMENT_INDEX, REPLACEMENT_INDEX_INCREMENT,
+        ACCOUNT_VERSION_V3, AUTHORITY_SEED, BATCH_ITEM_ACCOUNTS, CONFIG_LINES_ROOT_LENGTH,
+        GROUP_MEMBER_POINTER_EXTENSION, HEADER_V3, HIDDEN_SECTION, MERKLE_CONFIG_LINES_FEATURE,
+        NULL_STRING, REPLACEMENT_INDEX, REPLACEMENT_INDEX_INCREMENT, SLOT_HASH_ENTRY_LENGTH,
+        TOKEN_STANDARD_COMPRESSED, TOKEN_STANDARD_CORE, TOKEN_STANDARD_PRINT_EDITION,
+        TOKEN_STANDARD_TOKEN_2022,
     },
-    CandyError,
+    CandyError, CandyMachine, CandyMachineData, HiddenSettings, UriEncoding,
 };
 
+/// Alphabet of the (lowercase) base32 encoding.
+const BASE32_ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
 /// Anchor wrapper for Token program.
 #[derive(Debug, Clone)]
 pub struct Token;
@@ -124,6 +151,54 @@ pub struct RevokeMetadataDelegateHelperAccounts<'info> {
     pub token_metadata_program: AccountInfo<'info>,
 }
 
+pub struct ApproveCoreDelegateHelperAccounts<'info> {
+    /// CHECK: account checked in CPI
+    pub payer: AccountInfo<'info>,
+    /// CHECK: account checked in CPI
+    pub authority_pda: AccountInfo<'info>,
+    /// CHECK: account checked in CPI
+    pub collection: AccountInfo<'info>,
+    /// CHECK: account checked in CPI
+    pub collection_update_authority: AccountInfo<'info>,
+    /// CHECK: account checked in CPI
+    pub mpl_core_program: AccountInfo<'info>,
+    /// CHECK: account checked in CPI
+    pub system_program: AccountInfo<'info>,
+}
+
+pub struct RevokeCoreDelegateHelperAccounts<'info> {
+    /// CHECK: account checked in CPI
+    pub payer: AccountInfo<'info>,
+    /// CHECK: account checked in CPI
+    pub authority_pda: AccountInfo<'info>,
+    /// CHECK: account checked in CPI
+    pub collection: AccountInfo<'info>,
+    /// CHECK: account checked in CPI
+    pub mpl_core_program: AccountInfo<'info>,
+    /// CHECK: account checked in CPI
+    pub system_program: AccountInfo<'info>,
+}
+
+pub struct ApproveTokenGroupAuthorityHelperAccounts<'info> {
+    /// CHECK: account checked in CPI
+    pub authority_pda: AccountInfo<'info>,
+    /// CHECK: account checked in CPI
+    pub collection: AccountInfo<'info>,
+    /// CHECK: account checked in CPI
+    pub collection_update_authority: AccountInfo<'info>,
+    /// CHECK: account checked in CPI
+    pub spl_token_2022_program: AccountInfo<'info>,
+}
+
+pub struct RevokeTokenGroupAuthorityHelperAccounts<'info> {
+    /// CHECK: account checked in CPI
+    pub authority_pda: AccountInfo<'info>,
+    /// CHECK: account checked in CPI
+    pub collection: AccountInfo<'info>,
+    /// CHECK: account checked in CPI
+    pub spl_token_2022_program: AccountInfo<'info>,
+}
+
 pub fn assert_initialized<T: Pack + IsInitialized>(account_info: &AccountInfo) -> Result<T> {
     let account: T = T::unpack_unchecked(&account_info.data.borrow())?;
     if !account.is_initialized() {
@@ -135,13 +210,42 @@ pub fn assert_initialized<T: Pack + IsInitialized>(account_info: &AccountInfo) -
 
 /// Return the current number of lines written to the account.
 pub fn get_config_count(data: &[u8]) -> Result<usize> {
-    Ok(u32::from_le_bytes(*array_ref![data, HIDDEN_SECTION, 4]) as usize)
+    Ok(u32::from_le_bytes(*array_ref![data, get_hidden_section(data)?, 4]) as usize)
+}
+
+/// Return the position of the hidden section of the account.
+///
+/// The version of the account is the first field after the discriminator; the hidden
+/// section of V3 accounts starts right after the candy machine data.
+pub fn get_hidden_section(data: &[u8]) -> Result<usize> {
+    if data[8] == ACCOUNT_VERSION_V3 {
+        let mut candy_machine_data = &data[HEADER_V3..];
+        CandyMachineData::deserialize(&mut candy_machine_data)?;
+
+        Ok(data.len() - candy_machine_data.len())
+    } else {
+        Ok(HIDDEN_SECTION)
+    }
 }
 
 pub fn cmp_pubkeys(a: &Pubkey, b: &Pubkey) -> bool {
     sol_memcmp(a.as_ref(), b.as_ref(), PUBKEY_BYTES) == 0
 }
 
+/// Return the program account with the specified id from the remaining accounts.
+///
+/// Instructions that predate a program dependency read the program from the
+/// remaining accounts so their account list stays compatible with existing clients.
+pub fn get_program_account<'info>(
+    remaining_accounts: &[AccountInfo<'info>],
+    program_id: &Pubkey,
+) -> Option<AccountInfo<'info>> {
+    remaining_accounts
+        .iter()
+        .find(|account| cmp_pubkeys(account.key, program_id))
+        .cloned()
+}
+
 /// Return a padded string up to the specified length. If the specified
 /// string `value` is longer than the allowed `length`, return an error.
 pub fn fixed_length_string(value: String, length: usize) -> Result<String> {
@@ -169,6 +273,20 @@ pub fn replace_patterns(value: String, index: usize) -> String {
     mutable
 }
 
+/// Checks that the hidden settings include an index replacement pattern, so each item
+/// has a different name or uri before it is revealed.
+pub fn assert_hidden_settings_index(hidden_settings: &HiddenSettings) -> Result<()> {
+    let has_index = |value: &str| {
+        value.contains(REPLACEMENT_INDEX) || value.contains(REPLACEMENT_INDEX_INCREMENT)
+    };
+
+    if has_index(&hidden_settings.name) || has_index(&hidden_settings.uri) {
+        Ok(())
+    } else {
+        err!(CandyError::HiddenSettingsMissingIndex)
+    }
+}
+
 pub fn approve_collection_authority_helper(
     accounts: ApproveCollectionAuthorityHelperAccounts,
 ) -> Result<()> {
@@ -282,9 +400,521 @@ pub fn revoke_metadata_delegate(
         .map_err(|error| error.into())
 }
 
+/// Approves the authority PDA as an additional update delegate of an MPL Core collection.
+pub fn approve_core_delegate(accounts: ApproveCoreDelegateHelperAccounts) -> Result<()> {
+    if !is_core_collection(&accounts.collection) {
+        return err!(CandyError::IncorrectOwner);
+    }
+
+    let collection = Collection::try_from(&accounts.collection)?;
+
+    if !cmp_pubkeys(
+        &collection.base.update_authority,
+        &accounts.collection_update_authority.key(),
+    ) {
+        return err!(CandyError::IncorrectCollectionAuthority);
+    }
+
+    let authority_pda = accounts.authority_pda.key();
+
+    let mut additional_delegates = match collection.plugin_list.update_delegate {
+        Some(plugin) => plugin.update_delegate.additional_delegates,
+        None => {
+            return AddCollectionPluginV1CpiBuilder::new(&accounts.mpl_core_program)
+                .collection(&accounts.collection)
+                .payer(&accounts.payer)
+                .authority(Some(&accounts.collection_update_authority))
+                .system_program(&accounts.system_program)
+                .plugin(Plugin::UpdateDelegate(UpdateDelegate {
+                    additional_delegates: vec![authority_pda],
+                }))
+                .invoke()
+                .map_err(|error| error.into());
+        }
+    };
+
+    if additional_delegates
+        .iter()
+        .any(|delegate| cmp_pubkeys(delegate, &authority_pda))
+    {
+        return Ok(());
+    }
+
+    additional_delegates.push(authority_pda);
+
+    UpdateCollectionPluginV1CpiBuilder::new(&accounts.mpl_core_program)
+        .collection(&accounts.collection)
+        .payer(&accounts.payer)
+        .authority(Some(&accounts.collection_update_authority))
+        .system_program(&accounts.system_program)
+        .plugin(Plugin::UpdateDelegate(UpdateDelegate {
+            additional_delegates,
+        }))
+        .invoke()
+        .map_err(|error| error.into())
+}
+
+/// Revokes the authority PDA as an additional update delegate of an MPL Core collection.
+///
+/// Only the authority PDA is removed from the update delegate plugin, so delegates of
+/// other candy machines on the same collection are not affected.
+pub fn revoke_core_delegate(
+    accounts: RevokeCoreDelegateHelperAccounts,
+    candy_machine: Pubkey,
+    signer_bump: u8,
+) -> Result<()> {
+    let collection = Collection::try_from(&accounts.collection)?;
+    let authority_pda = accounts.authority_pda.key();
+
+    let additional_delegates = match collection.plugin_list.update_delegate {
+        Some(plugin) => plugin.update_delegate.additional_delegates,
+        None => return Ok(()),
+    };
+
+    if !additional_delegates
+        .iter()
+        .any(|delegate| cmp_pubkeys(delegate, &authority_pda))
+    {
+        return Ok(());
+    }
+
+    UpdateCollectionPluginV1CpiBuilder::new(&accounts.mpl_core_program)
+        .collection(&accounts.collection)
+        .payer(&accounts.payer)
+        .authority(Some(&accounts.authority_pda))
+        .system_program(&accounts.system_program)
+        .plugin(Plugin::UpdateDelegate(UpdateDelegate {
+            additional_delegates: additional_delegates
+                .into_iter()
+                .filter(|delegate| !cmp_pubkeys(delegate, &authority_pda))
+                .collect(),
+        }))
+        .invoke_signed(&[&[
+            AUTHORITY_SEED.as_bytes(),
+            candy_machine.as_ref(),
+            &[signer_bump],
+        ]])
+        .map_err(|error| error.into())
+}
+
+/// Return whether the collection account is an MPL Core collection.
+pub fn is_core_collection(collection: &AccountInfo) -> bool {
+    cmp_pubkeys(collection.owner, &mpl_core::ID)
+}
+
+/// Transfers the update authority of a Token-2022 collection group to the authority PDA.
+///
+/// The authority PDA needs to be the group update authority to add minted NFTs as
+/// members of the collection.
+pub fn approve_token_group_authority(
+    accounts: ApproveTokenGroupAuthorityHelperAccounts,
+) -> Result<()> {
+    if !is_token_2022_collection(&accounts.collection) {
+        return err!(CandyError::IncorrectOwner);
+    }
+
+    if !cmp_pubkeys(
+        &get_token_2022_update_authority(&accounts.collection)?,
+        &accounts.collection_update_authority.key(),
+    ) {
+        return err!(CandyError::IncorrectCollectionAuthority);
+    }
+
+    invoke(
+        &update_token_group_authority(
+            accounts.spl_token_2022_program.key,
+            accounts.collection.key,
+            accounts.collection_update_authority.key,
+            Some(accounts.authority_pda.key()),
+        )?,
+        &[
+            accounts.collection,
+            accounts.collection_update_authority,
+            accounts.spl_token_2022_program,
+        ],
+    )
+    .map_err(|error| error.into())
+}
+
+/// Records the group update authority of a Token-2022 collection before it is
+/// transferred to the authority PDA, so it can be restored when the collection is
+/// replaced.
+pub fn set_group_authority<'info>(
+    candy_machine: &CandyMachine,
+    candy_machine_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    group_authority: &Pubkey,
+) -> Result<()> {
+    let position = candy_machine.get_group_authority_position()?;
+
+    if candy_machine_info.data_len() < position + PUBKEY_BYTES {
+        msg!("Allocating space to store the group authority");
+
+        resize_or_reallocate_account_raw(
+            candy_machine_info,
+            payer,
+            system_program,
+            position + PUBKEY_BYTES,
+        )?;
+    }
+
+    candy_machine_info.data.borrow_mut()[position..position + PUBKEY_BYTES]
+        .copy_from_slice(group_authority.as_ref());
+
+    Ok(())
+}
+
+/// Returns the update authority of a Token-2022 collection group to the specified
+/// update authority, which is the group update authority recorded when the authority
+/// was transferred to the authority PDA.
+pub fn revoke_token_group_authority(
+    accounts: RevokeTokenGroupAuthorityHelperAccounts,
+    update_authority: Pubkey,
+    candy_machine: Pubkey,
+    signer_bump: u8,
+) -> Result<()> {
+    invoke_signed(
+        &update_token_group_authority(
+            accounts.spl_token_2022_program.key,
+            accounts.collection.key,
+            accounts.authority_pda.key,
+            Some(update_authority),
+        )?,
+        &[
+            accounts.collection,
+            accounts.authority_pda,
+            accounts.spl_token_2022_program,
+        ],
+        &[&[
+            AUTHORITY_SEED.as_bytes(),
+            candy_machine.as_ref(),
+            &[signer_bump],
+        ]],
+    )
+    .map_err(|error| error.into())
+}
+
+/// Return whether the collection account is a Token-2022 mint.
+pub fn is_token_2022_collection(collection: &AccountInfo) -> bool {
+    cmp_pubkeys(collection.owner, &spl_token_2022::ID)
+}
+
+/// Return the update authority stored in the token metadata extension of a Token-2022 mint.
+pub fn get_token_2022_update_authority(mint: &AccountInfo) -> Result<Pubkey> {
+    let data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&data)?;
+    let metadata = mint.get_variable_len_extension::<TokenMetadata>()?;
+
+    Option::<Pubkey>::from(metadata.update_authority)
+        .ok_or_else(|| CandyError::IncorrectCollectionAuthority.into())
+}
+
+// The group extensions are not available on the version of the Token-2022 crate
+// compatible with the current Solana version (and the token group interface crate
+// requires a newer Solana version), so the instructions are created using the same
+// discriminators and data types as the `spl-token-group-interface` builders.
+
+/// Discriminator of the token group interface `InitializeMember` instruction.
+#[derive(SplDiscriminate)]
+#[discriminator_hash_input("spl_token_group_interface:initialize_member")]
+pub struct InitializeMember;
+
+/// Discriminator of the token group interface `UpdateGroupAuthority` instruction.
+#[derive(SplDiscriminate)]
+#[discriminator_hash_input("spl_token_group_interface:update_authority")]
+pub struct UpdateGroupAuthority;
+
+/// Creates a Token-2022 `GroupMemberPointer` initialize instruction.
+pub fn initialize_group_member_pointer(
+    mint: &Pubkey,
+    authority: &Pubkey,
+    member_address: &Pubkey,
+) -> Result<Instruction> {
+    let authority = OptionalNonZeroPubkey::try_from(Some(*authority))?;
+    let member_address = OptionalNonZeroPubkey::try_from(Some(*member_address))?;
+    // extension instruction tag followed by the `Initialize` instruction tag
+    let mut data = vec![GROUP_MEMBER_POINTER_EXTENSION, 0];
+    data.extend_from_slice(bytes_of(&authority));
+    data.extend_from_slice(bytes_of(&member_address));
+
+    Ok(Instruction {
+        program_id: spl_token_2022::ID,
+        accounts: vec![AccountMeta::new(*mint, false)],
+        data,
+    })
+}
+
+/// Creates a token group interface `InitializeMember` instruction.
+pub fn initialize_token_group_member(
+    program_id: &Pubkey,
+    member: &Pubkey,
+    member_mint: &Pubkey,
+    member_mint_authority: &Pubkey,
+    group: &Pubkey,
+    group_update_authority: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*member, false),
+            AccountMeta::new_readonly(*member_mint, false),
+            AccountMeta::new_readonly(*member_mint_authority, true),
+            AccountMeta::new(*group, false),
+            AccountMeta::new_readonly(*group_update_authority, true),
+        ],
+        data: InitializeMember::SPL_DISCRIMINATOR_SLICE.to_vec(),
+    }
+}
+
+/// Creates a token group interface `UpdateGroupAuthority` instruction.
+pub fn update_token_group_authority(
+    program_id: &Pubkey,
+    group: &Pubkey,
+    current_authority: &Pubkey,
+    new_authority: Option<Pubkey>,
+) -> Result<Instruction> {
+    let new_authority = OptionalNonZeroPubkey::try_from(new_authority)?;
+    let mut data = UpdateGroupAuthority::SPL_DISCRIMINATOR_SLICE.to_vec();
+    data.extend_from_slice(bytes_of(&new_authority));
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*group, false),
+            AccountMeta::new_readonly(*current_authority, true),
+        ],
+        data,
+    })
+}
+
+/// Returns the seed to select the item minted to the specified mint account.
+///
+/// The mint address is mixed into the seed so that items minted in the same slot (e.g.
+/// the items of a batch) are selected using different seeds.
+pub fn get_item_seed(seed: u64, mint: &Pubkey) -> u64 {
+    let hash = keccak::hashv(&[&seed.to_le_bytes(), mint.as_ref()]).0;
+    u64::from_le_bytes(*array_ref![hash, 0, 8])
+}
+
+/// Splits the accounts of a batch mint into the accounts of each item.
+///
+/// The batch must include at least one item and the number of accounts must be a
+/// multiple of the number of accounts of each item.
+pub fn get_batch_items<T>(accounts: &[T]) -> Result<ChunksExact<'_, T>> {
+    let batch = accounts.chunks_exact(BATCH_ITEM_ACCOUNTS);
+
+    if batch.len() == 0 || !batch.remainder().is_empty() {
+        return err!(CandyError::InvalidBatchAccounts);
+    }
+
+    Ok(batch)
+}
+
+/// Returns the hash of the first slot after the specified slot from the SlotHashes
+/// sysvar data.
+///
+/// The sysvar only keeps the most recent entries (sorted from the most recent to the
+/// oldest slot), so the hash is only available for a limited number of slots.
+pub fn get_slot_hash_after(slot_hashes: &[u8], slot: u64) -> Result<[u8; 32]> {
+    let count = u64::from_le_bytes(*array_ref![slot_hashes, 0, 8]) as usize;
+    let mut slot_hash = None;
+
+    for index in 0..count {
+        let offset = 8 + index * SLOT_HASH_ENTRY_LENGTH;
+        let entry_slot = u64::from_le_bytes(*array_ref![slot_hashes, offset, 8]);
+
+        if entry_slot <= slot {
+            // the previous (more recent) entry is the first slot after the specified slot
+            return slot_hash.ok_or_else(|| CandyError::MintRequestNotReady.into());
+        }
+
+        let hash = *array_ref![slot_hashes, offset + 8, 32];
+
+        if entry_slot == slot + 1 {
+            return Ok(hash);
+        }
+
+        slot_hash = Some(hash);
+    }
+
+    if slot_hash.is_some() {
+        // all entries are after the specified slot, so the first slot after it was
+        // already removed from the sysvar
+        err!(CandyError::MintRequestExpired)
+    } else {
+        err!(CandyError::MintRequestNotReady)
+    }
+}
+
+/// Returns the leaf of a config line on the config lines merkle tree.
+///
+/// The leaf is the hash of the index followed by the length-prefixed name and uri.
+pub fn hash_config_line(index: u32, name: &str, uri: &str) -> [u8; 32] {
+    keccak::hashv(&[
+        &index.to_le_bytes(),
+        &(name.len() as u32).to_le_bytes(),
+        name.as_bytes(),
+        &(uri.len() as u32).to_le_bytes(),
+        uri.as_bytes(),
+    ])
+    .0
+}
+
+/// Verifies that a `leaf` is part of the merkle tree defined by `root`. Pairs of nodes
+/// are sorted before hashing.
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: &[u8; 32]) -> bool {
+    let mut computed_hash = *leaf;
+
+    for proof_element in proof {
+        computed_hash = if computed_hash <= *proof_element {
+            keccak::hashv(&[&computed_hash, proof_element]).0
+        } else {
+            keccak::hashv(&[proof_element, &computed_hash]).0
+        };
+    }
+
+    computed_hash == *root
+}
+
+/// Returns the commitment of the config lines of a candy machine.
+///
+/// The commitment is the hash of the length-prefixed name and uri prefixes, the name
+/// and uri lengths and the config lines section of the account data. When config lines
+/// are stored on page accounts, the config lines of each page (in page order) are used
+/// instead, so the commitment is the same as if they were stored on the account. When
+/// using merkle config lines, the commitment is the merkle root of the config lines.
+pub fn get_config_lines_commitment(
+    candy_machine: &CandyMachine,
+    account_data: &[u8],
+    page_lines: &[&[u8]],
+) -> Result<[u8; 32]> {
+    if candy_machine.data.hidden_settings.is_some() {
+        return err!(CandyError::HiddenSettingsDoNotHaveConfigLines);
+    }
+
+    let settings = candy_machine
+        .data
+        .config_line_settings
+        .as_ref()
+        .ok_or(CandyError::MissingConfigLinesSettings)?;
+
+    if candy_machine.is_feature_enabled(MERKLE_CONFIG_LINES_FEATURE) {
+        let position = candy_machine.get_config_lines_root_position()?;
+        return Ok(*array_ref![
+            account_data,
+            position,
+            CONFIG_LINES_ROOT_LENGTH
+        ]);
+    }
+
+    let prefix_name_length = (settings.prefix_name.len() as u32).to_le_bytes();
+    let prefix_uri_length = (settings.prefix_uri.len() as u32).to_le_bytes();
+    let name_length = settings.name_length.to_le_bytes();
+    let uri_length = settings.uri_length.to_le_bytes();
+
+    let mut values: Vec<&[u8]> = vec![
+        &prefix_name_length,
+        settings.prefix_name.as_bytes(),
+        &prefix_uri_length,
+        settings.prefix_uri.as_bytes(),
+        &name_length,
+        &uri_length,
+    ];
+
+    if candy_machine.has_config_line_pages() {
+        values.extend_from_slice(page_lines);
+    } else {
+        let lines_start = candy_machine.get_hidden_section()? + 4;
+        let lines_end = lines_start
+            + (candy_machine.data.items_available as usize)
+                * candy_machine.data.get_config_line_size();
+        values.push(&account_data[lines_start..lines_end]);
+    }
+
+    Ok(keccak::hashv(&values).0)
+}
+
+/// Encodes the raw bytes of a config line URI.
+pub fn encode_uri(encoding: UriEncoding, bytes: &[u8]) -> Result<String> {
+    match encoding {
+        UriEncoding::Utf8 => Ok(String::from_utf8(bytes.to_vec())
+            .map_err(|_| CandyError::CouldNotRetrieveConfigLineData)?
+            .trim_end_matches(NULL_STRING)
+            .to_string()),
+        UriEncoding::Base64Url => Ok(URL_SAFE_NO_PAD.encode(bytes)),
+        UriEncoding::Base58 => Ok(bs58::encode(bytes).into_string()),
+        UriEncoding::Base32 => {
+            let mut uri = String::with_capacity(bytes.len() * 8 / 5 + 1);
+            let mut buffer = 0u16;
+            let mut bits = 0;
+
+            for byte in bytes {
+                buffer = (buffer << 8) | *byte as u16;
+                bits += 8;
+
+                while bits >= 5 {
+                    bits -= 5;
+                    uri.push(BASE32_ALPHABET[((buffer >> bits) & 0x1f) as usize] as char);
+                }
+            }
+
+            if bits > 0 {
+                uri.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 0x1f) as usize] as char);
+            }
+
+            Ok(uri)
+        }
+    }
+}
+
+/// Decodes a config line URI into the raw bytes stored on the account.
+pub fn decode_uri(encoding: UriEncoding, uri: &str) -> Result<Vec<u8>> {
+    match encoding {
+        UriEncoding::Utf8 => Ok(uri.as_bytes().to_vec()),
+        UriEncoding::Base64Url => Ok(URL_SAFE_NO_PAD
+            .decode(uri)
+            .map_err(|_| CandyError::InvalidUriEncoding)?),
+        UriEncoding::Base58 => Ok(bs58::decode(uri)
+            .into_vec()
+            .map_err(|_| CandyError::InvalidUriEncoding)?),
+        UriEncoding::Base32 => {
+            let mut bytes = Vec::with_capacity(uri.len() * 5 / 8);
+            let mut buffer = 0u16;
+            let mut bits = 0;
+
+            for character in uri.bytes() {
+                let value = BASE32_ALPHABET
+                    .iter()
+                    .position(|c| *c == character)
+                    .ok_or(CandyError::InvalidUriEncoding)?;
+                buffer = (buffer << 5) | value as u16;
+                bits += 5;
+
+                if bits >= 8 {
+                    bits -= 8;
+                    bytes.push((buffer >> bits) as u8);
+                }
+            }
+
+            // the remaining bits are padding and must be zero
+            if bits >= 5 || buffer & ((1 << bits) - 1) != 0 {
+                return err!(CandyError::InvalidUriEncoding);
+            }
+
+            Ok(bytes)
+        }
+    }
+}
+
 pub fn assert_token_standard(token_standard: u8) -> Result<()> {
     if token_standard == TokenStandard::NonFungible as u8
         || token_standard == TokenStandard::ProgrammableNonFungible as u8
+        || token_standard == TOKEN_STANDARD_CORE
+        || token_standard == TOKEN_STANDARD_COMPRESSED
+        || token_standard == TOKEN_STANDARD_TOKEN_2022
+        || token_standard == TOKEN_STANDARD_PRINT_EDITION
+        || is_fungible_token_standard(token_standard)
     {
         Ok(())
     } else {
@@ -292,9 +922,326 @@ pub fn assert_token_standard(token_standard: u8) -> Result<()> {
     }
 }
 
+/// Returns whether the token standard can only be minted into a collection (Core assets,
+/// compressed and Token-2022 NFTs).
+pub fn is_collection_required(token_standard: u8) -> bool {
+    token_standard == TOKEN_STANDARD_CORE
+        || token_standard == TOKEN_STANDARD_COMPRESSED
+        || token_standard == TOKEN_STANDARD_TOKEN_2022
+}
+
+/// Returns whether the token standard mints fungible tokens (`Fungible` or
+/// `FungibleAsset`), where each config line is a token type.
+pub fn is_fungible_token_standard(token_standard: u8) -> bool {
+    token_standard == TokenStandard::Fungible as u8
+        || token_standard == TokenStandard::FungibleAsset as u8
+}
+
 #[cfg(test)]
 pub mod tests {
+    use anchor_lang::Discriminator;
+    use spl_pod::primitives::PodU64;
+
     use super::*;
+    use crate::{
+        constants::{GROUP_MEMBER_LENGTH, GROUP_MEMBER_POINTER_LENGTH},
+        AccountVersion,
+    };
+
+    fn slot_hashes(slots: &[u64]) -> Vec<u8> {
+        let mut data = (slots.len() as u64).to_le_bytes().to_vec();
+        for slot in slots {
+            data.extend_from_slice(&slot.to_le_bytes());
+            data.extend_from_slice(&[*slot as u8; 32]);
+        }
+        data
+    }
+
+    #[test]
+    fn check_slot_hash_after() {
+        // entries are sorted from the most recent to the oldest slot
+        let data = slot_hashes(&[14, 12, 11, 9]);
+
+        assert_eq!(get_slot_hash_after(&data, 9).unwrap(), [11; 32]);
+        assert_eq!(get_slot_hash_after(&data, 11).unwrap(), [12; 32]);
+        assert_eq!(get_slot_hash_after(&data, 12).unwrap(), [14; 32]);
+        assert_eq!(get_slot_hash_after(&data, 10).unwrap(), [11; 32]);
+        assert!(get_slot_hash_after(&data, 14).is_err());
+        // the first slot after 7 could have been removed
+        assert!(get_slot_hash_after(&data, 7).is_err());
+    }
+
+    #[test]
+    fn check_item_seed() {
+        let first = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+
+        assert_eq!(get_item_seed(7, &first), get_item_seed(7, &first));
+        assert_ne!(get_item_seed(7, &first), get_item_seed(7, &second));
+        assert_ne!(get_item_seed(7, &first), get_item_seed(8, &first));
+    }
+
+    #[test]
+    fn check_batch_items() {
+        let accounts: Vec<u8> = (0..(3 * BATCH_ITEM_ACCOUNTS) as u8).collect();
+        let items: Vec<&[u8]> = get_batch_items(&accounts).unwrap().collect();
+
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[1][0], BATCH_ITEM_ACCOUNTS as u8);
+        assert!(items.iter().all(|item| item.len() == BATCH_ITEM_ACCOUNTS));
+
+        assert!(get_batch_items::<u8>(&[]).is_err());
+        assert!(get_batch_items(&accounts[..BATCH_ITEM_ACCOUNTS + 1]).is_err());
+        assert!(get_batch_items(&accounts[..BATCH_ITEM_ACCOUNTS - 1]).is_err());
+    }
+
+    #[test]
+    fn check_merkle_proof() {
+        let leaves = [
+            hash_config_line(0, "#1", "uri/1"),
+            hash_config_line(1, "#2", "uri/2"),
+            hash_config_line(2, "#3", "uri/3"),
+        ];
+        let pair = |a: [u8; 32], b: [u8; 32]| {
+            if a <= b {
+                keccak::hashv(&[&a, &b]).0
+            } else {
+                keccak::hashv(&[&b, &a]).0
+            }
+        };
+        let node = pair(leaves[0], leaves[1]);
+        let root = pair(node, leaves[2]);
+
+        assert!(verify_merkle_proof(
+            &[leaves[1], leaves[2]],
+            &root,
+            &leaves[0]
+        ));
+        assert!(verify_merkle_proof(
+            &[leaves[0], leaves[2]],
+            &root,
+            &leaves[1]
+        ));
+        assert!(verify_merkle_proof(&[node], &root, &leaves[2]));
+        // the index is part of the leaf
+        let leaf = hash_config_line(1, "#1", "uri/1");
+        assert!(!verify_merkle_proof(&[leaves[1], leaves[2]], &root, &leaf));
+        // name and uri lengths are part of the leaf
+        assert_ne!(
+            hash_config_line(0, "#1u", "ri/1"),
+            hash_config_line(0, "#1", "uri/1")
+        );
+    }
+
+    #[test]
+    fn check_config_lines_commitment() {
+        let mut candy_machine = CandyMachine::default();
+        candy_machine.data.items_available = 2;
+        candy_machine.data.config_line_settings = Some(crate::ConfigLineSettings {
+            prefix_name: "#".to_string(),
+            name_length: 2,
+            prefix_uri: "uri/".to_string(),
+            uri_length: 3,
+            is_sequential: false,
+        });
+        let mut data = vec![0; HIDDEN_SECTION + 4 + 2 * 5];
+        data[HIDDEN_SECTION + 4..].copy_from_slice(b"01abc02def");
+
+        let commitment = get_config_lines_commitment(&candy_machine, &data, &[]).unwrap();
+        // deterministic for the same account data
+        assert_eq!(
+            get_config_lines_commitment(&candy_machine, &data, &[]).unwrap(),
+            commitment
+        );
+        // the config lines are part of the commitment
+        data[HIDDEN_SECTION + 4 + 5] = b'1';
+        assert_ne!(
+            get_config_lines_commitment(&candy_machine, &data, &[]).unwrap(),
+            commitment
+        );
+        data[HIDDEN_SECTION + 4 + 5] = b'0';
+
+        // config lines stored on page accounts have the same commitment
+        let mut paged = candy_machine.clone();
+        paged.set_config_line_pages(true);
+        assert_eq!(
+            get_config_lines_commitment(&paged, &data, &[b"01abc", b"02def"]).unwrap(),
+            commitment
+        );
+        assert_ne!(
+            get_config_lines_commitment(&paged, &data, &[b"01abc", b"02deg"]).unwrap(),
+            commitment
+        );
+
+        // the prefixes are part of the commitment
+        if let Some(settings) = candy_machine.data.config_line_settings.as_mut() {
+            settings.prefix_uri = "uri".to_string();
+        }
+        assert_ne!(
+            get_config_lines_commitment(&candy_machine, &data, &[]).unwrap(),
+            commitment
+        );
+    }
+
+    #[test]
+    fn check_hidden_section() {
+        let mut candy_machine = CandyMachine::default();
+        candy_machine.data.symbol = "CM".to_string();
+        candy_machine.data.items_available = 10;
+
+        let mut data = CandyMachine::discriminator().to_vec();
+        data.append(&mut candy_machine.try_to_vec().unwrap());
+        assert_eq!(get_hidden_section(&data).unwrap(), HIDDEN_SECTION);
+
+        // the symbol is stored with its maximum length
+        let hidden_section = candy_machine.data.get_hidden_section_v3().unwrap();
+        candy_machine.data.symbol = fixed_length_string("CM".to_string(), 10).unwrap();
+        candy_machine.version = AccountVersion::V3;
+
+        let mut data = CandyMachine::discriminator().to_vec();
+        data.append(&mut candy_machine.try_to_vec().unwrap());
+        assert_eq!(data.len(), hidden_section);
+        assert_eq!(candy_machine.get_hidden_section().unwrap(), hidden_section);
+
+        // the hidden section follows the candy machine data
+        data.extend_from_slice(&[0u8; 4]);
+        assert_eq!(get_hidden_section(&data).unwrap(), hidden_section);
+        assert_eq!(candy_machine.get_mint_index_size(), 2);
+    }
+
+    #[test]
+    fn check_uri_encoding() {
+        let bytes = (0..36).map(|i| (i * 7) as u8).collect::<Vec<u8>>();
+
+        for encoding in [
+            UriEncoding::Base64Url,
+            UriEncoding::Base58,
+            UriEncoding::Base32,
+        ] {
+            let uri = encode_uri(encoding, &bytes).unwrap();
+            assert_eq!(decode_uri(encoding, &uri).unwrap(), bytes);
+        }
+
+        assert_eq!(encode_uri(UriEncoding::Base32, b"f").unwrap(), "my");
+        assert_eq!(
+            encode_uri(UriEncoding::Base32, b"fooba").unwrap(),
+            "mzxw6ytb"
+        );
+        assert_eq!(
+            encode_uri(UriEncoding::Base64Url, &[251, 255]).unwrap(),
+            "-_8"
+        );
+        assert!(decode_uri(UriEncoding::Base32, "mz").is_err());
+        assert!(decode_uri(UriEncoding::Base58, "0OIl").is_err());
+    }
+
+    #[test]
+    fn check_config_line_pages() {
+        let mut candy_machine = CandyMachine::default();
+        candy_machine.data.items_available = 100;
+        candy_machine.data.config_line_settings = Some(crate::ConfigLineSettings {
+            prefix_name: String::new(),
+            name_length: 10,
+            prefix_uri: String::new(),
+            uri_length: 40,
+            is_sequential: false,
+        });
+        let space = candy_machine.get_space().unwrap();
+
+        // config lines are not stored on the candy machine account
+        candy_machine.set_paused(true);
+        candy_machine.set_config_line_pages(true);
+        assert!(candy_machine.is_paused());
+        assert_eq!(candy_machine.get_space().unwrap(), space - 100 * 50);
+
+        let pages = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let position = candy_machine.get_config_line_pages_position().unwrap();
+        let mut data = vec![0u8; position + 1 + pages.len() * 32];
+        data[position] = pages.len() as u8;
+        data[position + 1..position + 33].copy_from_slice(pages[0].as_ref());
+        data[position + 33..].copy_from_slice(pages[1].as_ref());
+
+        assert_eq!(candy_machine.get_config_line_pages(&data).unwrap(), pages);
+        assert!(candy_machine
+            .get_config_line_pages(&data[..position])
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn check_token_group_instructions() {
+        // discriminators are the first 8 bytes of the hash of the interface hash input
+        let discriminator = |input: &str| solana_program::hash::hash(input.as_bytes()).to_bytes();
+
+        let [member, member_mint, group, authority, program_id] =
+            [(); 5].map(|_| Pubkey::new_unique());
+
+        let instruction = initialize_token_group_member(
+            &program_id,
+            &member,
+            &member_mint,
+            &authority,
+            &group,
+            &authority,
+        );
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(
+            instruction.data,
+            discriminator("spl_token_group_interface:initialize_member")[..8]
+        );
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(member, false),
+                AccountMeta::new_readonly(member_mint, false),
+                AccountMeta::new_readonly(authority, true),
+                AccountMeta::new(group, false),
+                AccountMeta::new_readonly(authority, true),
+            ]
+        );
+
+        let instruction =
+            update_token_group_authority(&program_id, &group, &authority, Some(member)).unwrap();
+        assert_eq!(
+            instruction.data[..8],
+            discriminator("spl_token_group_interface:update_authority")[..8]
+        );
+        assert_eq!(instruction.data[8..], member.to_bytes());
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(group, false),
+                AccountMeta::new_readonly(authority, true),
+            ]
+        );
+        // an unset authority is represented by a zeroed pubkey
+        let instruction =
+            update_token_group_authority(&program_id, &group, &authority, None).unwrap();
+        assert_eq!(instruction.data[8..], [0; 32]);
+
+        let instruction =
+            initialize_group_member_pointer(&member_mint, &authority, &member).unwrap();
+        assert_eq!(instruction.program_id, spl_token_2022::ID);
+        assert_eq!(instruction.data[..2], [41, 0]);
+        assert_eq!(instruction.data[2..34], authority.to_bytes());
+        assert_eq!(instruction.data[34..], member.to_bytes());
+        assert_eq!(
+            instruction.accounts,
+            vec![AccountMeta::new(member_mint, false)]
+        );
+
+        // extensions are stored as type (2 bytes) + length (2 bytes) + data: the group
+        // member pointer stores the authority and member address, the group member stores
+        // the mint, group and member number
+        assert_eq!(
+            GROUP_MEMBER_POINTER_LENGTH,
+            4 + 2 * std::mem::size_of::<OptionalNonZeroPubkey>()
+        );
+        assert_eq!(
+            GROUP_MEMBER_LENGTH,
+            4 + 2 * std::mem::size_of::<Pubkey>() + std::mem::size_of::<PodU64>()
+        );
+    }
 
     #[test]
     fn check_keys_equal() {